@@ -3,13 +3,15 @@
  *
  * Usage:
  *   npx tsx app/demo.ts init                                  - Initialize config (one-time, starts PAUSED)
- *   npx tsx app/demo.ts create-atas                           - Create fee collector ATAs for USDC/USDT/TNS
+ *   npx tsx app/demo.ts create-atas                           - Create treasury vault ATAs for USDC/USDT/TNS
  *   npx tsx app/demo.ts register <symbol> <mint> <years>      - Register a symbol (pays with SOL)
  *   npx tsx app/demo.ts renew <symbol> <years>                - Renew a symbol (pays with SOL)
  *   npx tsx app/demo.ts update-mint <symbol> <new_mint>       - Update mint for a symbol (pays with SOL)
  *   npx tsx app/demo.ts transfer <symbol> <new_owner>         - Transfer symbol ownership
  *   npx tsx app/demo.ts cancel <symbol>                       - Cancel and close symbol account
+ *   npx tsx app/demo.ts release <symbol>                      - Release your symbol (rent + keeper deposit refunded)
  *   npx tsx app/demo.ts verify <symbol>                       - Verify symbol matches metadata (keeper enforcement)
+ *   npx tsx app/demo.ts migrate <symbol>                      - Grow a symbol's account to the current layout (pays rent)
  *   npx tsx app/demo.ts lookup <symbol>                       - Lookup symbol details
  *   npx tsx app/demo.ts lookup-mint <mint>                    - Reverse lookup by mint
  *   npx tsx app/demo.ts pda <symbol>                          - Derive token PDA
@@ -22,6 +24,11 @@
  *   npx tsx app/demo.ts set-phase <phase>                     - Set protocol phase 1/2/3 (admin only)
  *   npx tsx app/demo.ts admin-update <symbol> [options]       - Force-update a symbol (admin only)
  *   npx tsx app/demo.ts admin-close <symbol>                  - Force-close a symbol (admin only)
+ *   npx tsx app/demo.ts init-keeper-vault                     - Create the keeper vault (admin only)
+ *   npx tsx app/demo.ts init-oracle-config                    - Create the oracle config with default feeds (admin only)
+ *   npx tsx app/demo.ts init-bond-vault                       - Create the bond vault for bond pricing mode (admin only)
+ *   npx tsx app/demo.ts sweep-bond-vault                      - Sweep forfeited bonds to the fee collector (admin only)
+ *   npx tsx app/demo.ts reconcile-keeper-vault [--sweep]      - Reconcile keeper vault balance (admin only)
  */
 
 import "dotenv/config";
@@ -161,6 +168,38 @@ function getConfigPda(): PublicKey {
   return configPda;
 }
 
+function getKeeperVaultPda(): PublicKey {
+  const [keeperVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("keeper_vault")],
+    PROGRAM_ID
+  );
+  return keeperVaultPda;
+}
+
+function getBondVaultPda(): PublicKey {
+  const [bondVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("bond_vault")],
+    PROGRAM_ID
+  );
+  return bondVaultPda;
+}
+
+function getOracleConfigPda(): PublicKey {
+  const [oracleConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle_config")],
+    PROGRAM_ID
+  );
+  return oracleConfigPda;
+}
+
+function getTreasuryPda(): PublicKey {
+  const [treasuryPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    PROGRAM_ID
+  );
+  return treasuryPda;
+}
+
 function getTokenPda(symbol: string): PublicKey {
   // Symbols are case-sensitive (mSOL != MSOL)
   const [tokenPda] = PublicKey.findProgramAddressSync(
@@ -308,6 +347,7 @@ async function registerSymbol(symbol: string, mint: string, years: number) {
       tokenMetadata: tokenMetadata,
       feeCollector: feeCollector,
       priceUpdate: pythData.priceUpdateAccount,
+      platform: null,
       platformFeeAccount: null,
     })
     .instruction();
@@ -359,6 +399,7 @@ async function renewSymbol(symbol: string, years: number) {
       tokenAccount: tokenPda,
       feeCollector: feeCollector,
       priceUpdate: pythData.priceUpdateAccount,
+      platform: null,
       platformFeeAccount: null,
     })
     .instruction();
@@ -412,6 +453,7 @@ async function updateMint(symbol: string, newMint: string) {
       newMintMetadata: getMetadataPda(newMintPubkey),
       feeCollector: config.feeCollector,
       priceUpdate: pythData.priceUpdateAccount,
+      platform: null,
       platformFeeAccount: null,
     })
     .instruction();
@@ -448,11 +490,12 @@ async function transferOwnership(symbol: string, newOwner: string) {
     .accounts({
       owner: provider.wallet.publicKey,
       config: configPda,
+      keeperVault: getKeeperVaultPda(),
       tokenAccount: tokenPda,
     })
     .rpc();
 
-  console.log("\nOwnership transferred!");
+  console.log("\nOwnership transferred! Keeper deposit and any bond returned.");
   console.log(`  Transaction: ${tx}`);
 }
 
@@ -483,6 +526,67 @@ async function cancelSymbol(symbol: string) {
   console.log(`  Transaction: ${tx}`);
 }
 
+async function migrateTokenAccount(symbol: string) {
+  const provider = getProvider();
+  anchor.setProvider(provider);
+  const program = getProgram(provider);
+
+  const tokenPda = getTokenPda(symbol);
+  const before = await provider.connection.getAccountInfo(tokenPda);
+  if (before === null) {
+    console.log(`Symbol ${symbol} is not registered`);
+    return;
+  }
+
+  console.log("Migrating symbol account...");
+  console.log(`  Symbol: ${symbol}`);
+  console.log(`  Token PDA: ${tokenPda}`);
+  console.log(`  Current size: ${before.data.length} bytes`);
+
+  const tx = await program.methods
+    .migrateTokenAccount()
+    .accounts({
+      payer: provider.wallet.publicKey,
+      tokenAccount: tokenPda,
+    })
+    .rpc();
+
+  const after = await provider.connection.getAccountInfo(tokenPda);
+
+  console.log("\nSymbol account migrated!");
+  console.log(`  New size: ${after!.data.length} bytes`);
+  console.log(`  Rent paid: ${(after!.lamports - before.lamports) / 1_000_000_000} SOL`);
+  console.log(`  Transaction: ${tx}`);
+}
+
+async function releaseSymbol(symbol: string) {
+  const provider = getProvider();
+  anchor.setProvider(provider);
+  const program = getProgram(provider);
+
+  const configPda = getConfigPda();
+  const tokenPda = getTokenPda(symbol);
+
+  const tokenAccount = await (program.account as any).token.fetch(tokenPda);
+
+  console.log("Releasing symbol...");
+  console.log(`  Symbol: ${symbol}`);
+  console.log(`  Keeper deposit: ${tokenAccount.keeperDeposit.toNumber() / 1_000_000_000} SOL`);
+
+  const tx = await program.methods
+    .releaseSymbol()
+    .accounts({
+      owner: provider.wallet.publicKey,
+      config: configPda,
+      keeperVault: getKeeperVaultPda(),
+      tokenAccount: tokenPda,
+    })
+    .rpc();
+
+  console.log("\nSymbol released! Rent and keeper deposit returned.");
+  console.log(`  Transaction: ${tx}`);
+}
+
 async function verifyOrClose(symbol: string) {
   const provider = getProvider();
   anchor.setProvider(provider);
@@ -1007,18 +1111,149 @@ async function adminCloseSymbol(symbol: string) {
   console.log(`\nThe symbol ${symbol} is now available for fresh registration.`);
 }
 
-async function createFeeCollectorAtas() {
+async function initKeeperVault() {
+  const provider = getProvider();
+  anchor.setProvider(provider);
+  const program = getProgram(provider);
+
+  const configPda = getConfigPda();
+  const keeperVaultPda = getKeeperVaultPda();
+
+  console.log("Initializing keeper vault...");
+  console.log(`  Keeper Vault PDA: ${keeperVaultPda}`);
+
+  const tx = await program.methods
+    .initializeKeeperVault()
+    .accounts({
+      admin: provider.wallet.publicKey,
+      config: configPda,
+      keeperVault: keeperVaultPda,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  console.log("\nKeeper vault initialized!");
+  console.log(`  Transaction: ${tx}`);
+}
+
+async function initOracleConfig() {
+  const provider = getProvider();
+  anchor.setProvider(provider);
+  const program = getProgram(provider);
+
+  const configPda = getConfigPda();
+  const oracleConfigPda = getOracleConfigPda();
+
+  console.log("Initializing oracle config...");
+  console.log(`  Oracle Config PDA: ${oracleConfigPda}`);
+
+  const tx = await program.methods
+    .initializeOracleConfig()
+    .accounts({
+      admin: provider.wallet.publicKey,
+      config: configPda,
+      oracleConfig: oracleConfigPda,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  console.log("\nOracle config initialized!");
+  console.log(`  Transaction: ${tx}`);
+}
+
+async function initBondVault() {
   const provider = getProvider();
   anchor.setProvider(provider);
   const program = getProgram(provider);
+
   const configPda = getConfigPda();
+  const bondVaultPda = getBondVaultPda();
+
+  console.log("Initializing bond vault...");
+  console.log(`  Bond Vault PDA: ${bondVaultPda}`);
 
-  // Fetch fee collector from config
+  const tx = await program.methods
+    .initializeBondVault()
+    .accounts({
+      admin: provider.wallet.publicKey,
+      config: configPda,
+      bondVault: bondVaultPda,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+
+  console.log("\nBond vault initialized!");
+  console.log(`  Transaction: ${tx}`);
+}
+
+async function sweepBondVault() {
+  const provider = getProvider();
+  anchor.setProvider(provider);
+  const program = getProgram(provider);
+
+  const configPda = getConfigPda();
+  const bondVaultPda = getBondVaultPda();
+  const config = await program.account.config.fetch(configPda);
+
+  console.log("Sweeping bond vault...");
+  console.log(`  Bond Vault PDA: ${bondVaultPda}`);
+  console.log(`  Fee Collector:  ${config.feeCollector}`);
+
+  const tx = await program.methods
+    .sweepBondVault()
+    .accounts({
+      admin: provider.wallet.publicKey,
+      config: configPda,
+      bondVault: bondVaultPda,
+      feeCollector: config.feeCollector,
+    })
+    .rpc();
+
+  console.log("\nBond vault swept!");
+  console.log(`  Transaction: ${tx}`);
+}
+
+async function reconcileKeeperVault(sweepSurplus: boolean) {
+  const provider = getProvider();
+  anchor.setProvider(provider);
+  const program = getProgram(provider);
+
+  const configPda = getConfigPda();
+  const keeperVaultPda = getKeeperVaultPda();
   const config = await (program.account as any).config.fetch(configPda);
-  const feeCollector = config.feeCollector as PublicKey;
 
-  console.log("Creating ATAs for fee collector...");
-  console.log(`  Fee Collector: ${feeCollector}`);
+  console.log("Reconciling keeper vault...");
+  console.log(`  Sweep surplus: ${sweepSurplus}`);
+
+  const tx = await program.methods
+    .reconcileKeeperVault(sweepSurplus)
+    .accounts({
+      admin: provider.wallet.publicKey,
+      config: configPda,
+      keeperVault: keeperVaultPda,
+      feeCollector: config.feeCollector,
+    })
+    .rpc();
+
+  const vault = await (program.account as any).keeperVault.fetch(keeperVaultPda);
+  const balance = await provider.connection.getBalance(keeperVaultPda);
+
+  console.log("\nKeeper vault reconciled!");
+  console.log(`  Balance:              ${balance / 1_000_000_000} SOL`);
+  console.log(`  Outstanding deposits: ${vault.outstandingDeposits.toNumber() / 1_000_000_000} SOL`);
+  console.log(`  Legacy reserve:       ${vault.legacyReserve.toNumber() / 1_000_000_000} SOL`);
+  console.log(`  Transaction: ${tx}`);
+}
+
+async function createTreasuryVaults() {
+  const provider = getProvider();
+  anchor.setProvider(provider);
+
+  // Token fees are paid into the treasury PDA's ATAs
+  const treasury = getTreasuryPda();
+
+  console.log("Creating treasury vault ATAs...");
+  console.log(`  Treasury: ${treasury}`);
   console.log(`  Payer: ${provider.wallet.publicKey}`);
   console.log("");
 
@@ -1032,7 +1267,7 @@ async function createFeeCollectorAtas() {
   const atasToCreate: { name: string; ata: PublicKey }[] = [];
 
   for (const { name, mint, programId } of mints) {
-    const ata = getAssociatedTokenAddressSync(mint, feeCollector, false, programId);
+    const ata = getAssociatedTokenAddressSync(mint, treasury, true, programId);
 
     // Check if ATA already exists
     const accountInfo = await provider.connection.getAccountInfo(ata);
@@ -1045,7 +1280,7 @@ async function createFeeCollectorAtas() {
         createAssociatedTokenAccountInstruction(
           provider.wallet.publicKey, // payer
           ata, // ata address
-          feeCollector, // owner
+          treasury, // owner
           mint, // mint
           programId, // token program
         )
@@ -1080,7 +1315,7 @@ async function main() {
         break;
 
       case "create-atas":
-        await createFeeCollectorAtas();
+        await createTreasuryVaults();
         break;
 
       case "register":
@@ -1124,6 +1359,14 @@ async function main() {
         await cancelSymbol(args[1]);
         break;
 
+      case "release":
+        if (args.length < 2) {
+          console.log("Usage: npx tsx app/demo.ts release <symbol>");
+          process.exit(1);
+        }
+        await releaseSymbol(args[1]);
+        break;
+
       case "verify":
         if (args.length < 2) {
           console.log("Usage: npx tsx app/demo.ts verify <symbol>");
@@ -1134,6 +1377,14 @@ async function main() {
         await verifyOrClose(args[1]);
         break;
 
+      case "migrate":
+        if (args.length < 2) {
+          console.log("Usage: npx tsx app/demo.ts migrate <symbol>");
+          process.exit(1);
+        }
+        await migrateTokenAccount(args[1]);
+        break;
+
       case "lookup":
         if (args.length < 2) {
           console.log("Usage: npx tsx app/demo.ts lookup <symbol>");
@@ -1268,17 +1519,39 @@ async function main() {
         await adminCloseSymbol(args[1]);
         break;
 
+      case "init-keeper-vault":
+        await initKeeperVault();
+        break;
+
+      case "init-oracle-config":
+        await initOracleConfig();
+        break;
+
+      case "init-bond-vault":
+        await initBondVault();
+        break;
+
+      case "sweep-bond-vault":
+        await sweepBondVault();
+        break;
+
+      case "reconcile-keeper-vault":
+        await reconcileKeeperVault(args.includes("--sweep"));
+        break;
+
       default:
         console.log("TNS (Token Naming Service) Demo CLI\n");
         console.log("User Commands:");
         console.log("  init [fee_collector]                     - Initialize config (starts PAUSED)");
-        console.log("  create-atas                              - Create USDC/USDT/TNS ATAs for fee collector");
+        console.log("  create-atas                              - Create USDC/USDT/TNS treasury vault ATAs");
         console.log("  register <symbol> <mint> <years>         - Register a symbol (1-10 years, pays SOL)");
         console.log("  renew <symbol> <years>                   - Renew a symbol (pays SOL)");
         console.log("  update-mint <symbol> <new_mint>          - Update mint for a symbol (pays SOL)");
         console.log("  transfer <symbol> <new_owner>            - Transfer symbol ownership (free)");
         console.log("  cancel <symbol>                          - Cancel symbol and reclaim rent");
+        console.log("  release <symbol>                         - Release your symbol (rent + deposit refunded)");
         console.log("  verify <symbol>                          - Verify symbol matches metadata (keeper)");
+        console.log("  migrate <symbol>                         - Grow symbol account to current layout (pays rent)");
         console.log("  lookup <symbol>                          - Lookup symbol details");
         console.log("  lookup-mint <mint>                       - Reverse lookup by mint");
         console.log("  pda <symbol>                             - Derive token PDA address");
@@ -1296,6 +1569,11 @@ async function main() {
         console.log("    --mint <pubkey>                        - Set new mint");
         console.log("    --expires <timestamp>                  - Set new expiration (unix timestamp)");
         console.log("  admin-close <symbol>                     - Force-close and delete a symbol");
+        console.log("  init-keeper-vault                        - Create the keeper vault");
+        console.log("  init-oracle-config                       - Create the oracle config with default feeds");
+        console.log("  init-bond-vault                          - Create the bond vault for bond pricing mode");
+        console.log("  sweep-bond-vault                         - Sweep forfeited bonds to the fee collector");
+        console.log("  reconcile-keeper-vault [--sweep]         - Reconcile keeper vault (optionally sweep surplus)");
         console.log("\nProtocol Phases:");
         console.log("  1 - Genesis: Admin-only, verified tokens seeded via admin scripts");
         console.log("  2 - Open:    Anyone can register unseeded symbols, reserved tradfi protected");
@@ -1304,7 +1582,7 @@ async function main() {
         console.log("  Base price: ~$10/year (converted to SOL via Pyth oracle)");
         console.log("  Multi-year discounts: 5-25% for 2-10 years");
         console.log("  Pay with TNS token for 25% discount");
-        console.log("  Also accepts USDC and USDT");
+        console.log("  Also accepts USDC, USDT and any other registered payment asset");
         console.log("\nExamples:");
         console.log("  npx tsx app/demo.ts init                     # Initialize (starts paused)");
         console.log("  npx tsx app/demo.ts config                   # Check current state");
@@ -29,6 +29,26 @@ pub const ANNUAL_INCREASE_BPS: u16 = 0;
 /// Paid to keepers who close abandoned symbols or detect metadata drift
 pub const KEEPER_REWARD_LAMPORTS: u64 = 50_000_000;
 
+/// Default lock-up for majority-holder claims (7 days)
+/// Tokens must sit in program escrow this long before ownership passes,
+/// so supply borrowed or concentrated within one transaction can't take a symbol
+pub const MAJORITY_HOLD_PERIOD_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Claim precedence levels for claim_ownership and majority claims
+/// A claimant can only take a symbol from an owner whose claim has equal or lower precedence
+pub const CLAIM_PRECEDENCE_NONE: u8 = 0;
+pub const CLAIM_PRECEDENCE_MAJORITY_HOLDER: u8 = 1;
+pub const CLAIM_PRECEDENCE_MINT_AUTHORITY: u8 = 2;
+pub const CLAIM_PRECEDENCE_UPDATE_AUTHORITY: u8 = 3;
+
+/// Default cooldown after a claim before a claimant of the same precedence can take the symbol (1 day)
+/// A strictly higher-precedence claimant is never blocked by the cooldown
+pub const CLAIM_COOLDOWN_SECONDS: i64 = 24 * 60 * 60;
+
+/// Default remediation window after a keeper flags metadata drift (7 days)
+/// The owner can restore metadata or point the symbol at a matching mint before it can be closed
+pub const DRIFT_GRACE_PERIOD_SECONDS: i64 = 7 * 24 * 60 * 60;
+
 /// Fee for updating mint in basis points of base price (5000 = 50%)
 pub const UPDATE_FEE_BPS: u16 = 5000;
 
@@ -37,12 +57,32 @@ pub const UPDATE_FEE_BPS: u16 = 5000;
 /// Year 6: 16%, Year 7: 18%, Year 8: 20%, Year 9: 22%, Year 10: 25%
 pub const MULTI_YEAR_DISCOUNT_BPS: [u16; 10] = [0, 500, 800, 1100, 1400, 1600, 1800, 2000, 2200, 2500];
 
-/// Maximum staleness for Pyth price feed (60 seconds — pull oracle is always fresh)
+/// Default maximum staleness for Pyth price feeds (60 seconds — pull oracle is always fresh)
+/// Initial value of OracleConfig.max_staleness_seconds
 pub const MAX_PRICE_STALENESS_SECONDS: u64 = 60;
 
-/// Pyth SOL/USD price feed ID (used with pull oracle PriceUpdateV2)
+/// Upper bound for OracleConfig.max_staleness_seconds (1 hour)
+pub const MAX_ORACLE_STALENESS_SECONDS: u64 = 3600;
+
+/// Maximum lifetime of an admin-posted fallback SOL/USD price (15 minutes)
+pub const MAX_FALLBACK_PRICE_TTL_SECONDS: i64 = 15 * 60;
+
+/// Default number of consecutive violating SOL/USD updates that trip the circuit breaker
+pub const SOL_CIRCUIT_TRIP_VIOLATIONS: u8 = 3;
+
+/// Default age after which the last good SOL/USD price no longer anchors the deviation guard (1 hour)
+pub const SOL_LAST_GOOD_PRICE_MAX_AGE_SECONDS: i64 = 60 * 60;
+
+/// Default minimum Wormhole guardian signatures on a partially verified price update
+pub const DEFAULT_MIN_GUARDIAN_SIGNATURES: u8 = 3;
+
+/// Pyth SOL/USD price feed ID (initial OracleConfig.sol_usd_feed_id)
 pub const SOL_USD_FEED_ID: [u8; 32] = hex_to_bytes("ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d");
 
+/// Default band around $1 within which Stablecoin-priced payment assets are accepted (200 = ±2%)
+/// Outside the band the stablecoin is treated as depegged and payments are rejected
+pub const STABLECOIN_DEPEG_BAND_BPS: u16 = 200;
+
 /// Compile-time hex string to byte array conversion
 const fn hex_to_bytes(hex: &str) -> [u8; 32] {
     let bytes = hex.as_bytes();
@@ -66,38 +106,80 @@ const fn hex_char_to_nibble(c: u8) -> u8 {
     }
 }
 
-/// Discount for paying with TNS token in basis points (2500 = 25%)
-pub const TNS_DISCOUNT_BPS: u16 = 2500;
-
 /// TNS token mint address on mainnet
+/// Priced by the TNS TWAP (PaymentAsset TnsTwap source) and burned by the revenue split
 pub const TNS_MINT: Pubkey = pubkey!("6jwcLLjhEcUrnnPtnWvqVKEeAzSTXT6qtV1GEjcopump");
 
-/// USDC mint address on mainnet
-pub const USDC_MINT: Pubkey = pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
-
-/// USDT mint address on mainnet
-pub const USDT_MINT: Pubkey = pubkey!("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB");
-
-/// Stablecoin decimals (USDC, USDT, and TNS all use 6 decimals)
+/// Stablecoin and TNS decimals (6)
 /// 1 token = 1,000,000 raw units = $1
 pub const STABLECOIN_DECIMALS: u8 = 6;
 pub const STABLECOIN_MULTIPLIER: u64 = 1_000_000;
 
 /// Maximum platform fee in basis points (5000 = 50%)
-/// Platforms (launchpads like pump.fun) can receive up to 50% of registration fees
+/// Upper bound for a registered Platform's max_fee_bps; launchpads like pump.fun
+/// can receive up to 50% of registration fees
 pub const MAX_PLATFORM_FEE_BPS: u16 = 5000;
 
 /// Wrapped SOL mint address
 pub const WSOL_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
 
-/// Pump AMM pool for TNS/SOL pricing
-pub const PUMP_TNS_SOL_POOL: Pubkey = pubkey!("4vvoQ1icW9kQJcoqsUxFm9cxyYKJgUHafRo7Bxiyf5Cp");
+/// Maximum number of DEX pools in TnsPriceSources
+pub const MAX_TNS_POOL_SOURCES: usize = 4;
+
+/// Raydium CPMM program (owner of CPMM pool state accounts)
+pub const RAYDIUM_CPMM_PROGRAM_ID: Pubkey = pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+
+/// Anchor discriminator of the Raydium CPMM swap_base_input instruction
+pub const RAYDIUM_CPMM_SWAP_BASE_INPUT: [u8; 8] = [143, 190, 90, 218, 196, 30, 51, 222];
+
+/// Maximum buyback slippage below the TWAP-implied output (2000 = 20%)
+pub const MAX_BUYBACK_SLIPPAGE_BPS: u16 = 2000;
 
-/// Pool's TNS reserve token account (holds TNS tokens in the pool)
-pub const PUMP_POOL_TNS_RESERVE: Pubkey = pubkey!("8UGTQaaDjjWoye9YiG9YuGUc5RHcTb8sHcr877Eg5L73");
+/// Number of TNS/USD samples kept in the TnsPriceObservation ring buffer
+pub const TNS_OBSERVATION_CAPACITY: usize = 48;
 
-/// Pool's SOL reserve token account (holds WSOL tokens in the pool)
-pub const PUMP_POOL_SOL_RESERVE: Pubkey = pubkey!("B4PNGmGrdPbPjb2iu7MAKHBovQduv5gjLJdC9i5UNQaJ");
+/// Minimum time between TNS/USD samples (60 seconds)
+/// With TNS_OBSERVATION_CAPACITY samples the buffer spans at least 47 minutes
+pub const TNS_OBSERVATION_MIN_INTERVAL_SECONDS: i64 = 60;
+
+/// Default TWAP window for TNS-denominated fees (30 minutes)
+pub const TNS_TWAP_WINDOW_SECONDS: u32 = 30 * 60;
+
+/// Maximum configurable TWAP window (47 minutes)
+/// Cranking at the minimum interval, the buffer only reaches this far back;
+/// a longer window would have no starting sample and block TNS pricing.
+pub const MAX_TNS_TWAP_WINDOW_SECONDS: u32 =
+    ((TNS_OBSERVATION_CAPACITY - 1) as i64 * TNS_OBSERVATION_MIN_INTERVAL_SECONDS) as u32;
+
+/// Default maximum distance of the TNS spot price from the TWAP (500 = 5%)
+pub const TNS_MAX_SPOT_DEVIATION_BPS: u16 = 500;
 
 /// SOL decimals (9 decimals)
 pub const SOL_DECIMALS: u8 = 9;
+
+/// Maximum number of arbiters in the dispute arbiter set
+pub const MAX_ARBITERS: usize = 10;
+
+/// Maximum number of allowlisted treasury withdrawal destinations
+pub const MAX_TREASURY_DESTINATIONS: usize = 8;
+
+/// Maximum length for a dispute evidence URI
+pub const MAX_EVIDENCE_URI_LENGTH: usize = 200;
+
+/// Default minimum challenger bond (1 SOL = 1,000,000,000 lamports)
+pub const DISPUTE_MIN_BOND_LAMPORTS: u64 = 1_000_000_000;
+
+/// Default time the owner has to post a counter-bond (14 days)
+pub const DISPUTE_RESPONSE_WINDOW_SECONDS: i64 = 14 * 24 * 60 * 60;
+
+/// Default time arbiters have to vote after the response window (30 days)
+pub const DISPUTE_VOTING_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Maximum number of symbols processed by a single batch instruction
+pub const MAX_BATCH_SIZE: usize = 20;
+
+/// Auto-renew crank may renew a symbol this long before it expires (30 days)
+pub const AUTO_RENEW_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Tip paid from the auto-renew escrow to the cranker ($0.05, in micro USD)
+pub const AUTO_RENEW_TIP_USD_MICRO: u64 = 50_000;
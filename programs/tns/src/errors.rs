@@ -56,7 +56,7 @@ pub enum TnsError {
     #[msg("Invalid phase transition - phase can only increase from 1 to 2 to 3")]
     InvalidPhase,
 
-    #[msg("SOL cost exceeds maximum specified")]
+    #[msg("Cost exceeds maximum specified")]
     SlippageExceeded,
 
     #[msg("Symbol has not been expired long enough to cancel (requires 1 year after grace period)")]
@@ -94,4 +94,235 @@ pub enum TnsError {
 
     #[msg("No metadata drift detected - symbol still matches")]
     NoDriftDetected,
+
+    #[msg("Symbol has an open dispute - ownership changes are frozen")]
+    DisputeOpen,
+
+    #[msg("Dispute bond is below the configured minimum")]
+    DisputeBondTooLow,
+
+    #[msg("Evidence URI exceeds maximum length")]
+    EvidenceUriTooLong,
+
+    #[msg("Invalid dispute config: arbiter set must be 1-10 unique keys and quorum a strict majority")]
+    InvalidDisputeConfig,
+
+    #[msg("Dispute response window has closed")]
+    ResponseWindowClosed,
+
+    #[msg("Owner has already responded to this dispute")]
+    AlreadyResponded,
+
+    #[msg("Caller is not a member of the arbiter set")]
+    NotArbiter,
+
+    #[msg("Arbiter has already voted on this dispute")]
+    AlreadyVoted,
+
+    #[msg("Dispute voting is not open")]
+    VotingNotOpen,
+
+    #[msg("Dispute cannot be resolved yet - no quorum and voting still open")]
+    DisputeNotResolvable,
+
+    #[msg("Majority hold period must be greater than zero")]
+    InvalidHoldPeriod,
+
+    #[msg("Escrowed amount must be more than 50% of supply")]
+    NotMajorityHolder,
+
+    #[msg("Majority claim hold period has not elapsed")]
+    HoldPeriodNotElapsed,
+
+    #[msg("Current owner claimed with higher authority precedence")]
+    LowerClaimPrecedence,
+
+    #[msg("Symbol was claimed recently - claim cooldown still active")]
+    ClaimCooldownActive,
+
+    #[msg("Claim cooldown must be greater than zero")]
+    InvalidClaimCooldown,
+
+    #[msg("Drift grace period must be greater than zero")]
+    InvalidDriftGracePeriod,
+
+    #[msg("Price lock escalation cannot exceed 10000 basis points")]
+    InvalidPriceLockPolicy,
+
+    #[msg("Stablecoin depeg band must be between 1 and 10000 basis points")]
+    InvalidDepegBand,
+
+    #[msg("Drift remediation window is still open")]
+    DriftGracePeriodActive,
+
+    #[msg("Mint authority must be revoked")]
+    MintAuthorityNotRevoked,
+
+    #[msg("Mint must not have a freeze authority")]
+    FreezeAuthorityPresent,
+
+    #[msg("Mint supply is below the required minimum")]
+    SupplyBelowMinimum,
+
+    #[msg("Mint has a disallowed Token-2022 extension")]
+    DangerousMintExtension,
+
+    #[msg("Keeper vault cannot cover the recorded deposit")]
+    KeeperVaultInsufficient,
+
+    #[msg("Batch must contain between 1 and MAX_BATCH_SIZE items")]
+    InvalidBatchSize,
+
+    #[msg("Batch accounts do not match the batch items")]
+    InvalidBatchAccounts,
+
+    #[msg("Batch contains the same symbol more than once")]
+    DuplicateBatchItem,
+
+    #[msg("Invalid auto-renew settings")]
+    InvalidAutoRenewConfig,
+
+    #[msg("Symbol is not yet inside the auto-renew window")]
+    AutoRenewNotDue,
+
+    #[msg("Auto-renew year cap reached")]
+    AutoRenewYearsExhausted,
+
+    #[msg("Renewal fee exceeds the auto-renew price cap")]
+    AutoRenewPriceCapExceeded,
+
+    #[msg("Auto-renew escrow cannot cover the fee and tip")]
+    AutoRenewInsufficientEscrow,
+
+    #[msg("Symbol owner has changed since auto-renew was enabled")]
+    AutoRenewOwnerMismatch,
+
+    #[msg("Invalid payment asset settings")]
+    InvalidPaymentAsset,
+
+    #[msg("Payment asset is disabled")]
+    PaymentAssetDisabled,
+
+    #[msg("Price account required by the payment asset's price source is missing")]
+    MissingPriceAccount,
+
+    #[msg("Stablecoin is trading outside the accepted band around $1")]
+    StablecoinDepegged,
+
+    #[msg("TNS price was sampled too recently")]
+    ObservationTooSoon,
+
+    #[msg("Not enough recent TNS price samples to cover the TWAP window")]
+    TwapUnavailable,
+
+    #[msg("TNS spot price deviates too far from the TWAP")]
+    SpotPriceDeviation,
+
+    #[msg("Invalid TNS TWAP policy")]
+    InvalidTwapPolicy,
+
+    #[msg("Invalid TNS price sources")]
+    InvalidPriceSources,
+
+    #[msg("Not enough TNS price sources")]
+    InsufficientPriceSources,
+
+    #[msg("Platform fee requires a registered platform")]
+    PlatformRequired,
+
+    #[msg("Platform is not active")]
+    PlatformInactive,
+
+    #[msg("Platform fee exceeds the platform's maximum")]
+    PlatformFeeAboveLimit,
+
+    #[msg("Platform fee account does not belong to the platform's payout wallet")]
+    InvalidPlatformPayout,
+
+    #[msg("Signer is not the platform authority")]
+    UnauthorizedPlatformAuthority,
+
+    #[msg("Revenue split must sum to 10000 basis points")]
+    InvalidRevenueSplit,
+
+    #[msg("Account required by the revenue split is missing")]
+    MissingRevenueAccount,
+
+    #[msg("Revenue vault does not match the payment mint")]
+    InvalidRevenueVault,
+
+    #[msg("Invalid buyback settings")]
+    InvalidBuybackSettings,
+
+    #[msg("Buyback is not configured for this revenue vault")]
+    BuybackDisabled,
+
+    #[msg("Buyback amount exceeds the burn balance")]
+    InvalidBuybackAmount,
+
+    #[msg("Buyback is only supported for SOL and payment assets other than TNS")]
+    UnsupportedBuybackMint,
+
+    #[msg("Buyback returned less TNS than the TWAP-implied minimum")]
+    BuybackOutputTooLow,
+
+    #[msg("Treasury allowlist must hold 1-8 unique, non-default wallets")]
+    InvalidTreasuryAllowlist,
+
+    #[msg("Withdrawal destination is not on the treasury allowlist")]
+    DestinationNotAllowed,
+
+    #[msg("Withdrawal amount must be non-zero and within the vault balance")]
+    InsufficientTreasuryBalance,
+
+    #[msg("Invalid oracle config")]
+    InvalidOracleConfig,
+
+    #[msg("Price update account is not owned by the configured oracle program")]
+    InvalidPriceUpdateOwner,
+
+    #[msg("Oracle confidence interval is too wide relative to the price")]
+    PriceConfidenceTooWide,
+
+    #[msg("SOL/USD price is outside the configured bounds")]
+    SolPriceOutOfBounds,
+
+    #[msg("SOL/USD price deviates too far from the last good price")]
+    SolPriceDeviationTooLarge,
+
+    #[msg("SOL/USD circuit breaker is tripped; admin reset required")]
+    SolPriceCircuitTripped,
+
+    #[msg("Fallback price must be positive, within the SOL/USD guards and live for at most 15 minutes")]
+    InvalidFallbackPrice,
+
+    #[msg("Payment mint has a Token-2022 extension the protocol cannot account for")]
+    UnsupportedPaymentMintExtension,
+
+    #[msg("Bond pricing mode only takes SOL bonds without a platform fee")]
+    BondModeUnsupported,
+
+    #[msg("Bond keeper share must be at most 10000 bps")]
+    InvalidBondPolicy,
+
+    #[msg("Bond vault balance is insufficient to cover recorded bonds")]
+    BondVaultInsufficient,
+
+    #[msg("Account is not a Token account of this program")]
+    InvalidTokenAccount,
+
+    #[msg("Token account already uses the current layout")]
+    TokenAccountUpToDate,
+
+    #[msg("Symbol is expired or in its grace period")]
+    SymbolNotActive,
+
+    #[msg("Symbol is flagged for metadata drift")]
+    SymbolDriftFlagged,
+
+    #[msg("Bond vault account is required in bond mode and for bonded symbols")]
+    MissingBondVault,
+
+    #[msg("Pyth price update is below the minimum verification level")]
+    InsufficientPriceVerification,
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::{BondPolicy, DisputeOutcome, EligibilityPolicy, OracleVerificationLevel, PriceLockPolicy, PriceProvider, PriceSource, RevenueSplit, TnsTwapPolicy, TnsPoolSource};
 
 /// Emitted when a new symbol is registered
 #[event]
@@ -13,10 +14,14 @@ pub struct SymbolRegistered {
     pub owner: Pubkey,
     /// Number of years registered for
     pub years: u8,
-    /// Registration fee paid (total cost to user)
+    /// Registration fee paid (total cost to user, excluding any bond)
     pub fee_paid: u64,
     /// Platform fee paid to referrer/launchpad (0 if none)
     pub platform_fee: u64,
+    /// Provider of the SOL/USD price the fee was quoted at (None when not SOL-priced)
+    pub sol_price_provider: Option<PriceProvider>,
+    /// Refundable bond locked in the bond vault (0 when registered for a fee)
+    pub bond_posted: u64,
     /// Unix timestamp of registration
     pub registered_at: i64,
     /// Unix timestamp when registration expires
@@ -40,6 +45,8 @@ pub struct SymbolRenewed {
     pub fee_paid: u64,
     /// Platform fee paid to referrer (0 if none)
     pub platform_fee: u64,
+    /// Provider of the SOL/USD price the fee was quoted at (None when not SOL-priced)
+    pub sol_price_provider: Option<PriceProvider>,
     /// Previous expiration timestamp
     pub old_expires_at: i64,
     /// New expiration timestamp
@@ -48,6 +55,71 @@ pub struct SymbolRenewed {
     pub renewed_at: i64,
 }
 
+/// Emitted when an owner enables auto-renew for a symbol
+#[event]
+pub struct AutoRenewEnabled {
+    /// The auto-renew PDA address
+    pub auto_renew: Pubkey,
+    /// The Token account it renews
+    pub token_account: Pubkey,
+    /// The symbol string
+    pub symbol: String,
+    /// Owner who funded the escrow
+    pub owner: Pubkey,
+    /// Payment asset (Pubkey::default() for SOL)
+    pub payment_mint: Pubkey,
+    /// Years added per renewal
+    pub years_per_renewal: u8,
+    /// Total years the crank may add
+    pub max_years: u16,
+    /// Maximum fee per renewal
+    pub max_fee_per_renewal: u64,
+    /// Initial escrow deposit
+    pub deposit: u64,
+    /// Unix timestamp
+    pub enabled_at: i64,
+}
+
+/// Emitted when the crank renews a symbol from its auto-renew escrow
+#[event]
+pub struct AutoRenewCranked {
+    /// The auto-renew PDA address
+    pub auto_renew: Pubkey,
+    /// The Token account renewed
+    pub token_account: Pubkey,
+    /// The symbol string
+    pub symbol: String,
+    /// Who ran the crank (receives the tip)
+    pub cranker: Pubkey,
+    /// Years added
+    pub years: u8,
+    /// Renewal fee paid from escrow
+    pub fee_paid: u64,
+    /// Provider of the SOL/USD price the fee was quoted at (None when not SOL-priced)
+    pub sol_price_provider: Option<PriceProvider>,
+    /// Tip paid to the cranker from escrow
+    pub tip_paid: u64,
+    /// Years the crank may still add
+    pub years_remaining: u16,
+    /// Unix timestamp
+    pub cranked_at: i64,
+}
+
+/// Emitted when an owner withdraws the remaining auto-renew escrow
+#[event]
+pub struct AutoRenewWithdrawn {
+    /// The auto-renew PDA address (now closed)
+    pub auto_renew: Pubkey,
+    /// The Token account it renewed
+    pub token_account: Pubkey,
+    /// Owner receiving the escrow
+    pub owner: Pubkey,
+    /// Escrow amount returned (lamports for SOL, token units for stablecoins)
+    pub amount: u64,
+    /// Unix timestamp
+    pub withdrawn_at: i64,
+}
+
 /// Emitted when an expired symbol is claimed by a new owner
 #[event]
 pub struct SymbolClaimed {
@@ -65,16 +137,43 @@ pub struct SymbolClaimed {
     pub new_mint: Pubkey,
     /// Number of years registered for
     pub years: u8,
-    /// Fee paid (total cost to user)
+    /// Fee paid (total cost to user, excluding any bond)
     pub fee_paid: u64,
     /// Platform fee paid to referrer (0 if none)
     pub platform_fee: u64,
+    /// Provider of the SOL/USD price the fee was quoted at (None when not SOL-priced)
+    pub sol_price_provider: Option<PriceProvider>,
+    /// Refundable bond locked in the bond vault (0 when claimed for a fee)
+    pub bond_posted: u64,
+    /// Previous owner's bond forfeited to the treasury
+    pub bond_forfeited: u64,
     /// Unix timestamp
     pub claimed_at: i64,
     /// New expiration timestamp
     pub expires_at: i64,
 }
 
+/// Emitted when an owner voluntarily releases a symbol (account closed)
+#[event]
+pub struct SymbolReleased {
+    /// The token PDA address (now closed)
+    pub token_account: Pubkey,
+    /// The symbol that was released
+    pub symbol: String,
+    /// Owner who released the symbol (receives rent + deposit)
+    pub owner: Pubkey,
+    /// The mint the symbol pointed to
+    pub mint: Pubkey,
+    /// Unix timestamp
+    pub released_at: i64,
+    /// Rent returned to owner in lamports
+    pub rent_returned: u64,
+    /// Keeper deposit refunded from the keeper vault in lamports
+    pub deposit_refunded: u64,
+    /// Bond refunded from the bond vault in lamports
+    pub bond_refunded: u64,
+}
+
 /// Emitted when an abandoned symbol is canceled (account closed)
 #[event]
 pub struct SymbolCanceled {
@@ -92,6 +191,12 @@ pub struct SymbolCanceled {
     pub canceled_at: i64,
     /// Rent returned to keeper in lamports
     pub rent_returned: u64,
+    /// Keeper deposit paid from the keeper vault in lamports
+    pub keeper_reward: u64,
+    /// Bond forfeited in lamports
+    pub bond_forfeited: u64,
+    /// Keeper's share of the forfeited bond in lamports (the rest stays with the treasury)
+    pub bond_keeper_reward: u64,
 }
 
 /// Emitted when a symbol's mint is updated (symbol transferred to new token)
@@ -111,6 +216,8 @@ pub struct MintUpdated {
     pub fee_paid: u64,
     /// Platform fee paid to referrer (0 if none)
     pub platform_fee: u64,
+    /// Provider of the SOL/USD price the fee was quoted at (None when not SOL-priced)
+    pub sol_price_provider: Option<PriceProvider>,
     /// Unix timestamp
     pub updated_at: i64,
 }
@@ -128,6 +235,10 @@ pub struct OwnershipTransferred {
     pub new_owner: Pubkey,
     /// Unix timestamp
     pub transferred_at: i64,
+    /// Keeper deposit refunded to the previous owner in lamports
+    pub deposit_refunded: u64,
+    /// Bond refunded to the previous owner in lamports
+    pub bond_refunded: u64,
 }
 
 /// Emitted when token authority claims ownership of a symbol
@@ -143,8 +254,14 @@ pub struct OwnershipClaimed {
     pub new_owner: Pubkey,
     /// How ownership was claimed: "mint_authority", "update_authority", or "majority_holder"
     pub claim_type: String,
+    /// Precedence level of the claim (1 = majority holder, 2 = mint authority, 3 = update authority)
+    pub precedence: u8,
     /// Unix timestamp
     pub claimed_at: i64,
+    /// Keeper deposit refunded to the previous owner in lamports
+    pub deposit_refunded: u64,
+    /// Bond refunded to the previous owner in lamports
+    pub bond_refunded: u64,
 }
 
 /// Emitted when config is updated
@@ -158,6 +275,15 @@ pub struct ConfigUpdated {
     pub tns_usd_pyth_feed: Option<Pubkey>,
     pub paused: bool,
     pub phase: u8,
+    pub majority_hold_period_seconds: i64,
+    pub claim_cooldown_seconds: i64,
+    pub drift_grace_period_seconds: i64,
+    pub eligibility_policy: EligibilityPolicy,
+    pub price_lock_policy: PriceLockPolicy,
+    pub stablecoin_depeg_band_bps: u16,
+    pub tns_twap_policy: TnsTwapPolicy,
+    pub revenue_split: RevenueSplit,
+    pub bond_policy: BondPolicy,
 }
 
 /// Emitted when the protocol is initialized
@@ -196,6 +322,55 @@ pub struct SymbolSeeded {
     pub expires_at: i64,
 }
 
+/// Emitted once per batch renewal, after the per-symbol SymbolRenewed events
+#[event]
+pub struct BatchRenewed {
+    /// Who paid for the batch
+    pub renewed_by: Pubkey,
+    /// Number of symbols renewed
+    pub count: u8,
+    /// Years added to each symbol
+    pub years: u8,
+    /// Total fee paid across the batch
+    pub total_fee_paid: u64,
+    /// Total platform fee paid across the batch (0 if none)
+    pub total_platform_fee: u64,
+    /// Provider of the SOL/USD price the fees were quoted at
+    pub sol_price_provider: Option<PriceProvider>,
+    /// Unix timestamp of renewal
+    pub renewed_at: i64,
+}
+
+/// Emitted once per batch cancel, after the per-symbol SymbolCanceled events
+#[event]
+pub struct BatchCanceled {
+    /// Keeper who canceled the batch
+    pub canceled_by: Pubkey,
+    /// Number of symbols canceled
+    pub count: u8,
+    /// Total rent returned to the keeper in lamports
+    pub total_rent_returned: u64,
+    /// Total keeper deposits paid from the keeper vault in lamports
+    pub total_keeper_reward: u64,
+    /// Total keeper share of forfeited bonds paid from the bond vault in lamports
+    pub total_bond_keeper_reward: u64,
+    /// Unix timestamp
+    pub canceled_at: i64,
+}
+
+/// Emitted once per batch seed, after the per-symbol SymbolSeeded events
+#[event]
+pub struct BatchSeeded {
+    /// Admin who seeded the batch
+    pub admin: Pubkey,
+    /// Number of symbols seeded
+    pub count: u8,
+    /// Total rent paid by the admin in lamports
+    pub total_rent_paid: u64,
+    /// Unix timestamp of seeding
+    pub seeded_at: i64,
+}
+
 /// Emitted when admin force-updates a symbol
 #[event]
 pub struct SymbolUpdatedByAdmin {
@@ -234,6 +409,8 @@ pub struct SymbolClosedByAdmin {
     pub previous_mint: Pubkey,
     /// Admin who closed it
     pub admin: Pubkey,
+    /// Bond forfeited to the treasury in lamports
+    pub bond_forfeited: u64,
     /// Unix timestamp
     pub closed_at: i64,
 }
@@ -245,7 +422,7 @@ pub struct SymbolDriftDetected {
     pub token_account: Pubkey,
     /// The registered symbol (what TNS had stored)
     pub symbol: String,
-    /// The new metadata symbol (what the owner changed it to)
+    /// The current metadata symbol (may still match if another field drifted)
     pub new_metadata_symbol: String,
     /// The mint address
     pub mint: Pubkey,
@@ -253,8 +430,559 @@ pub struct SymbolDriftDetected {
     pub previous_owner: Pubkey,
     /// Keeper who detected the drift and receives rent
     pub keeper: Pubkey,
+    /// Which fields drifted from the registration
+    pub symbol_changed: bool,
+    pub name_changed: bool,
+    pub uri_changed: bool,
+    pub update_authority_changed: bool,
     /// Unix timestamp when drift was detected
     pub detected_at: i64,
     /// Rent returned to keeper in lamports
     pub rent_returned: u64,
+    /// Keeper deposit paid from the keeper vault in lamports
+    pub keeper_reward: u64,
+    /// Bond forfeited in lamports
+    pub bond_forfeited: u64,
+    /// Keeper's share of the forfeited bond in lamports (the rest stays with the treasury)
+    pub bond_keeper_reward: u64,
+}
+
+/// Emitted when a keeper flags metadata drift and the remediation window starts
+#[event]
+pub struct SymbolDriftFlagged {
+    /// The PDA address of the Token account
+    pub token_account: Pubkey,
+    /// The registered symbol
+    pub symbol: String,
+    /// The current metadata symbol (may still match if another field drifted)
+    pub new_metadata_symbol: String,
+    /// The mint address
+    pub mint: Pubkey,
+    /// Current owner who must remediate
+    pub owner: Pubkey,
+    /// Keeper who flagged the drift
+    pub keeper: Pubkey,
+    /// Which fields drifted from the registration
+    pub symbol_changed: bool,
+    pub name_changed: bool,
+    pub uri_changed: bool,
+    pub update_authority_changed: bool,
+    /// Unix timestamp when drift was flagged
+    pub flagged_at: i64,
+    /// Unix timestamp after which the symbol can be closed
+    pub closable_at: i64,
+}
+
+/// Emitted when the owner re-commits to the mint's current metadata
+#[event]
+pub struct MetadataRecommitted {
+    /// The PDA address of the Token account
+    pub token_account: Pubkey,
+    /// The symbol string
+    pub symbol: String,
+    /// Owner who paid for the re-commit
+    pub owner: Pubkey,
+    /// Which committed fields changed
+    pub name_changed: bool,
+    pub uri_changed: bool,
+    pub update_authority_changed: bool,
+    /// Fee paid (total cost to user)
+    pub fee_paid: u64,
+    /// Platform fee paid to referrer (0 if none)
+    pub platform_fee: u64,
+    /// Provider of the SOL/USD price the fee was quoted at (None when not SOL-priced)
+    pub sol_price_provider: Option<PriceProvider>,
+    /// Unix timestamp
+    pub committed_at: i64,
+}
+
+/// Emitted when a flagged symbol's metadata matches again
+#[event]
+pub struct SymbolDriftCleared {
+    /// The PDA address of the Token account
+    pub token_account: Pubkey,
+    /// The registered symbol
+    pub symbol: String,
+    /// Who called verify_or_close
+    pub caller: Pubkey,
+    /// Unix timestamp
+    pub cleared_at: i64,
+}
+
+/// Emitted when the admin sweeps forfeited bonds from the bond vault
+#[event]
+pub struct BondVaultSwept {
+    /// The bond vault PDA address
+    pub bond_vault: Pubkey,
+    /// Vault lamports after the sweep
+    pub balance: u64,
+    /// Bonds still owed to owners of open symbols
+    pub outstanding_bonds: u64,
+    /// Surplus swept to the fee collector
+    pub swept: u64,
+    /// Unix timestamp
+    pub swept_at: i64,
+}
+
+/// Emitted when the admin reconciles the keeper vault
+#[event]
+pub struct KeeperVaultReconciled {
+    /// The keeper vault PDA address
+    pub keeper_vault: Pubkey,
+    /// Vault lamports after reconciliation
+    pub balance: u64,
+    /// Deposits still owed to keepers of open symbols
+    pub outstanding_deposits: u64,
+    /// Legacy keeper reward lamports reserved for symbols without a deposit
+    pub legacy_reserve: u64,
+    /// Lamports above rent + outstanding deposits + legacy reserve
+    pub surplus: u64,
+    /// Lamports missing to cover rent + outstanding deposits + legacy reserve
+    pub deficit: u64,
+    /// Legacy keeper reward lamports moved from the Config PDA
+    pub migrated_from_config: u64,
+    /// Surplus swept to the fee collector
+    pub swept: u64,
+    /// Unix timestamp
+    pub reconciled_at: i64,
+}
+
+/// Emitted when the dispute config is created or updated
+#[event]
+pub struct DisputeConfigUpdated {
+    /// The dispute config PDA address
+    pub dispute_config: Pubkey,
+    /// Current arbiter set
+    pub arbiters: Vec<Pubkey>,
+    /// Votes a side needs to prevail
+    pub quorum: u8,
+    /// Minimum challenger bond in lamports
+    pub min_bond_lamports: u64,
+    /// Owner response window in seconds
+    pub response_window_seconds: i64,
+    /// Arbiter voting window in seconds
+    pub voting_window_seconds: i64,
+}
+
+/// Emitted when a challenger opens a dispute against a symbol
+#[event]
+pub struct DisputeOpened {
+    /// The dispute PDA address
+    pub dispute: Pubkey,
+    /// The PDA address of the contested Token account
+    pub token_account: Pubkey,
+    /// The symbol string
+    pub symbol: String,
+    /// Who opened the dispute
+    pub challenger: Pubkey,
+    /// Current owner of the symbol
+    pub owner: Pubkey,
+    /// Bond posted by the challenger in lamports
+    pub challenger_bond: u64,
+    /// Off-chain evidence supporting the complaint
+    pub evidence_uri: String,
+    /// Deadline for the owner to post a counter-bond
+    pub response_deadline: i64,
+    /// Deadline for arbiters to vote
+    pub voting_deadline: i64,
+    /// Unix timestamp
+    pub opened_at: i64,
+}
+
+/// Emitted when the owner posts a counter-bond
+#[event]
+pub struct DisputeResponded {
+    /// The dispute PDA address
+    pub dispute: Pubkey,
+    /// The PDA address of the contested Token account
+    pub token_account: Pubkey,
+    /// The symbol string
+    pub symbol: String,
+    /// Owner who responded
+    pub owner: Pubkey,
+    /// Counter-bond posted in lamports
+    pub owner_bond: u64,
+    /// Unix timestamp
+    pub responded_at: i64,
+}
+
+/// Emitted when an arbiter votes on a dispute
+#[event]
+pub struct DisputeVoteCast {
+    /// The dispute PDA address
+    pub dispute: Pubkey,
+    /// The PDA address of the contested Token account
+    pub token_account: Pubkey,
+    /// Arbiter who voted
+    pub arbiter: Pubkey,
+    /// true = in favor of the challenger, false = in favor of the owner
+    pub for_challenger: bool,
+    /// Running total of votes for the challenger
+    pub votes_for_challenger: u8,
+    /// Running total of votes for the owner
+    pub votes_for_owner: u8,
+    /// Unix timestamp
+    pub voted_at: i64,
+}
+
+/// Emitted when a dispute is resolved and its account closed
+#[event]
+pub struct DisputeResolved {
+    /// The dispute PDA address (now closed)
+    pub dispute: Pubkey,
+    /// The PDA address of the contested Token account
+    pub token_account: Pubkey,
+    /// The symbol string
+    pub symbol: String,
+    /// Who opened the dispute
+    pub challenger: Pubkey,
+    /// Owner at the time the dispute was opened
+    pub owner: Pubkey,
+    /// Which side prevailed, or Dismissed (no quorum)
+    pub outcome: DisputeOutcome,
+    /// Owner of the symbol after resolution
+    pub new_owner: Pubkey,
+    /// Final votes for the challenger
+    pub votes_for_challenger: u8,
+    /// Final votes for the owner
+    pub votes_for_owner: u8,
+    /// Bond slashed to the fee collector in lamports (0 if dismissed)
+    pub bond_slashed: u64,
+    /// Keeper deposit refunded to the owner when the challenger prevails, in lamports
+    pub deposit_refunded: u64,
+    /// Symbol bond refunded to the owner when the challenger prevails, in lamports
+    pub symbol_bond_refunded: u64,
+    /// Who triggered the resolution
+    pub resolved_by: Pubkey,
+    /// Unix timestamp
+    pub resolved_at: i64,
+}
+
+/// Emitted when a majority holder escrows tokens to start a claim
+#[event]
+pub struct MajorityClaimStarted {
+    /// The majority claim PDA address
+    pub majority_claim: Pubkey,
+    /// The PDA address of the Token account
+    pub token_account: Pubkey,
+    /// The symbol string
+    pub symbol: String,
+    /// The claimant who escrowed tokens
+    pub claimant: Pubkey,
+    /// The mint whose supply is held
+    pub mint: Pubkey,
+    /// Amount of tokens escrowed
+    pub amount: u64,
+    /// Mint supply when the claim started
+    pub supply: u64,
+    /// Unix timestamp when the claim can complete
+    pub unlocks_at: i64,
+    /// Unix timestamp
+    pub started_at: i64,
+}
+
+/// Emitted when a majority claim ends and its escrowed tokens are released
+#[event]
+pub struct MajorityClaimEnded {
+    /// The majority claim PDA address (now closed)
+    pub majority_claim: Pubkey,
+    /// The PDA address of the Token account
+    pub token_account: Pubkey,
+    /// The claimant who receives the tokens back
+    pub claimant: Pubkey,
+    /// Amount of tokens released
+    pub amount: u64,
+    /// Whether ownership passed to the claimant (false if canceled)
+    pub ownership_transferred: bool,
+    /// Unix timestamp
+    pub ended_at: i64,
+}
+
+/// Emitted when a payment asset is added to or updated in the registry
+#[event]
+pub struct PaymentAssetUpdated {
+    /// The payment asset PDA address
+    pub payment_asset: Pubkey,
+    /// The accepted mint
+    pub mint: Pubkey,
+    /// Mint decimals
+    pub decimals: u8,
+    /// USD price source
+    pub price_source: PriceSource,
+    /// Discount in basis points
+    pub discount_bps: u16,
+    /// Whether payments in this asset are accepted
+    pub enabled: bool,
+    /// Unix timestamp
+    pub updated_at: i64,
+}
+
+/// Emitted when the TNS price crank records a sample
+#[event]
+pub struct TnsPriceObserved {
+    /// The price observation PDA address
+    pub price_observation: Pubkey,
+    /// Who cranked the sample
+    pub cranker: Pubkey,
+    /// Recorded TNS/USD price in micro-cents (spot clamped to the step from the previous sample)
+    pub price_usd_micro: u64,
+    /// Spot TNS/USD price read by the crank, in micro-cents
+    pub spot_price_usd_micro: u64,
+    /// Cumulative price up to this sample
+    pub cumulative_price: u128,
+    /// Unix timestamp
+    pub observed_at: i64,
+}
+
+/// Emitted when the TNS price sources are created or updated
+#[event]
+pub struct TnsPriceSourcesUpdated {
+    /// The price sources PDA address
+    pub price_sources: Pubkey,
+    /// Configured pools
+    pub pools: Vec<TnsPoolSource>,
+    /// Minimum number of sources that must price TNS
+    pub min_sources: u8,
+}
+
+/// Emitted when a token-denominated fee is charged
+#[event]
+pub struct TokenFeeCharged {
+    /// The PDA address of the Token account the fee was paid for
+    pub token_account: Pubkey,
+    /// Who paid
+    pub payer: Pubkey,
+    /// Payment mint
+    pub mint: Pubkey,
+    /// Fee quoted at the current price, in token units
+    pub quoted_amount: u64,
+    /// Most the payer agreed to pay
+    pub max_token_amount: u64,
+    /// Amount debited from the payer
+    pub charged_amount: u64,
+    /// Token-2022 transfer fee withheld on top of the quoted amount
+    pub transfer_fee: u64,
+}
+
+/// Emitted when a platform is registered or updated
+#[event]
+pub struct PlatformUpdated {
+    /// The platform PDA address
+    pub platform: Pubkey,
+    /// Platform authority
+    pub authority: Pubkey,
+    /// Wallet receiving the platform's fee share
+    pub payout_wallet: Pubkey,
+    /// Maximum platform fee in basis points
+    pub max_fee_bps: u16,
+    /// Whether fee splits to the platform are honored
+    pub active: bool,
+    /// Who made the change
+    pub updated_by: Pubkey,
+    /// Unix timestamp
+    pub updated_at: i64,
+}
+
+/// Emitted when a fee's protocol revenue is split beyond the treasury
+#[event]
+pub struct RevenueDistributed {
+    /// Payment mint (WSOL for SOL)
+    pub mint: Pubkey,
+    /// Paid to the fee collector
+    pub treasury: u64,
+    /// Paid to the keeper vault (SOL) or the keeper bucket (tokens)
+    pub keeper: u64,
+    /// Burned (TNS) or held for buyback-and-burn
+    pub burn: u64,
+    /// Held in the staker bucket
+    pub staker: u64,
+}
+
+/// Emitted when a revenue vault is created or its buyback settings change
+#[event]
+pub struct RevenueVaultUpdated {
+    /// The revenue vault PDA address
+    pub revenue_vault: Pubkey,
+    /// Payment mint
+    pub mint: Pubkey,
+    /// Raydium CPMM pool used for buybacks (default = disabled)
+    pub buyback_pool: Pubkey,
+    /// Maximum buyback slippage below the TWAP-implied output, in basis points
+    pub buyback_max_slippage_bps: u16,
+    /// Unix timestamp
+    pub updated_at: i64,
+}
+
+/// Emitted when TNS is burned from protocol revenue
+#[event]
+pub struct TnsBurned {
+    /// Mint the burned TNS was paid or bought with
+    pub source_mint: Pubkey,
+    /// Amount of the source mint used (equals tns_burned for direct TNS burns)
+    pub amount_in: u64,
+    /// TNS burned
+    pub tns_burned: u64,
+    /// Who triggered the burn (payer or cranker)
+    pub burned_by: Pubkey,
+    /// Unix timestamp
+    pub burned_at: i64,
+}
+
+/// Emitted when the treasury is created or its allowlist changes
+#[event]
+pub struct TreasuryUpdated {
+    /// The treasury PDA address
+    pub treasury: Pubkey,
+    /// Wallets whose token accounts may receive withdrawals
+    pub allowlist: Vec<Pubkey>,
+    /// Unix timestamp
+    pub updated_at: i64,
+}
+
+/// Emitted when the admin withdraws from a treasury vault
+#[event]
+pub struct TreasuryWithdrawn {
+    /// The treasury PDA address
+    pub treasury: Pubkey,
+    /// Asset withdrawn
+    pub mint: Pubkey,
+    /// Destination token account
+    pub destination: Pubkey,
+    /// Allowlisted wallet owning the destination
+    pub destination_owner: Pubkey,
+    /// Amount withdrawn
+    pub amount: u64,
+    /// Vault balance left for this asset
+    pub remaining_balance: u64,
+    /// Admin who withdrew
+    pub withdrawn_by: Pubkey,
+    /// Unix timestamp
+    pub withdrawn_at: i64,
+}
+
+/// Emitted when the oracle config is created or updated
+#[event]
+pub struct OracleConfigUpdated {
+    /// The oracle config PDA address
+    pub oracle_config: Pubkey,
+    /// Pyth SOL/USD feed ID
+    pub sol_usd_feed_id: [u8; 32],
+    /// Maximum price age in seconds
+    pub max_staleness_seconds: u64,
+    /// Minimum verification level accepted
+    pub min_verification: OracleVerificationLevel,
+    /// Program that must own price update accounts
+    pub price_update_owner: Pubkey,
+    /// Maximum confidence/price ratio in basis points (0 = disabled)
+    pub max_confidence_bps: u16,
+    /// Lowest accepted SOL/USD price in micro-cents (0 = disabled)
+    pub min_sol_price_usd_micro: u64,
+    /// Highest accepted SOL/USD price in micro-cents (0 = disabled)
+    pub max_sol_price_usd_micro: u64,
+    /// Maximum deviation from the last good price in basis points (0 = disabled)
+    pub max_deviation_bps: u16,
+    /// Whether guard violations trip the circuit breaker
+    pub auto_pause: bool,
+    /// Consecutive violating updates that trip the circuit breaker (0 = default)
+    pub trip_after_violations: u8,
+    /// Age after which the last good price stops anchoring the deviation guard (0 = default)
+    pub max_last_good_age_seconds: i64,
+    /// Unix timestamp
+    pub updated_at: i64,
+}
+
+/// Emitted when a SOL/USD price passing every guard is recorded as the last good price
+#[event]
+pub struct SolPriceRecorded {
+    /// The oracle config PDA address
+    pub oracle_config: Pubkey,
+    /// Who cranked the price
+    pub cranker: Pubkey,
+    /// SOL/USD price in micro-cents
+    pub price_usd_micro: u64,
+    /// Unix timestamp
+    pub recorded_at: i64,
+}
+
+/// Emitted when a SOL/USD guard violation trips the circuit breaker
+#[event]
+pub struct SolCircuitTripped {
+    /// The oracle config PDA address
+    pub oracle_config: Pubkey,
+    /// Who cranked the price
+    pub cranker: Pubkey,
+    /// Offending SOL/USD price in micro-cents
+    pub price_usd_micro: u64,
+    /// Last good price at the time, in micro-cents
+    pub last_good_price_usd_micro: u64,
+    /// Name of the violated guard's error
+    pub reason: String,
+    /// Unix timestamp
+    pub tripped_at: i64,
+}
+
+/// Emitted when record_sol_price counts a violating SOL/USD update without tripping the circuit
+#[event]
+pub struct SolPriceViolationRecorded {
+    /// The oracle config PDA address
+    pub oracle_config: Pubkey,
+    /// Who cranked the price
+    pub cranker: Pubkey,
+    /// Offending SOL/USD price in micro-cents
+    pub price_usd_micro: u64,
+    /// Name of the violated guard's error
+    pub reason: String,
+    /// Consecutive violations so far
+    pub violations: u8,
+    /// Violations that trip the circuit
+    pub trip_after_violations: u8,
+    /// Unix timestamp
+    pub recorded_at: i64,
+}
+
+/// Emitted when the admin resets the SOL/USD circuit breaker
+#[event]
+pub struct SolCircuitReset {
+    /// The oracle config PDA address
+    pub oracle_config: Pubkey,
+    /// New last good price in micro-cents (0 = re-anchor on the next recorded price)
+    pub last_good_price_usd_micro: u64,
+    /// Admin who reset the circuit
+    pub reset_by: Pubkey,
+    /// Unix timestamp
+    pub reset_at: i64,
+}
+
+/// Emitted when the admin posts or clears the fallback SOL/USD price
+#[event]
+pub struct FallbackPriceUpdated {
+    /// The oracle config PDA address
+    pub oracle_config: Pubkey,
+    /// Fallback SOL/USD price in micro-cents
+    pub price_usd_micro: u64,
+    /// Unix timestamp after which the price is no longer used
+    pub expires_at: i64,
+    /// Admin who posted the price
+    pub posted_by: Pubkey,
+    /// Unix timestamp
+    pub posted_at: i64,
+}
+
+/// Emitted when a Token account is grown to the current layout
+#[event]
+pub struct TokenAccountMigrated {
+    /// The PDA address of the migrated Token account
+    pub token_account: Pubkey,
+    /// The symbol string
+    pub symbol: String,
+    /// Account data length before the migration
+    pub old_len: u64,
+    /// Account data length after the migration
+    pub new_len: u64,
+    /// Lamports paid by the payer to keep the account rent-exempt
+    pub rent_paid: u64,
+    /// Who paid for the migration
+    pub payer: Pubkey,
+    /// Unix timestamp
+    pub migrated_at: i64,
 }
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::{Config, PaymentAsset, PriceSource, PaymentAssetUpdated, TnsError};
+use crate::instructions::registrar::helpers::{validate_payment_asset_settings, validate_payment_mint_extensions};
+
+/// Admin-only instruction to accept a new payment asset
+/// Decimals are read from the mint; the generic *_asset instructions accept it once enabled.
+/// Token-2022 mints may only carry extensions the payment helpers account for.
+#[derive(Accounts)]
+pub struct AddPaymentAsset<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The payment mint (SPL or Token-2022)
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PaymentAsset::INIT_SPACE,
+        seeds = [PaymentAsset::SEED_PREFIX, mint.key().as_ref()],
+        bump
+    )]
+    pub payment_asset: Account<'info, PaymentAsset>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<AddPaymentAsset>,
+    price_source: PriceSource,
+    discount_bps: u16,
+    enabled: bool,
+) -> Result<()> {
+    validate_payment_asset_settings(&price_source, discount_bps, &ctx.accounts.mint.key())?;
+    validate_payment_mint_extensions(&ctx.accounts.mint)?;
+
+    let payment_asset = &mut ctx.accounts.payment_asset;
+    payment_asset.mint = ctx.accounts.mint.key();
+    payment_asset.decimals = ctx.accounts.mint.decimals;
+    payment_asset.price_source = price_source;
+    payment_asset.discount_bps = discount_bps;
+    payment_asset.enabled = enabled;
+    payment_asset.bump = ctx.bumps.payment_asset;
+
+    emit!(PaymentAssetUpdated {
+        payment_asset: payment_asset.key(),
+        mint: payment_asset.mint,
+        decimals: payment_asset.decimals,
+        price_source: payment_asset.price_source,
+        discount_bps: payment_asset.discount_bps,
+        enabled: payment_asset.enabled,
+        updated_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
-use crate::{Config, Token, TnsError, SymbolClosedByAdmin};
+use crate::{BondVault, Config, KeeperVault, Token, TnsError, SymbolClosedByAdmin};
+use crate::instructions::registrar::helpers::{validate_no_open_dispute, record_bond_forfeit};
 
 /// Admin-only instruction to force-close a symbol account.
 /// Closes the account immediately, returning rent to admin.
 /// The symbol becomes available for fresh registration.
+/// Disputed symbols must be resolved first so bonds aren't stranded.
+/// The symbol's keeper deposit stays in the keeper vault as surplus, and its bond
+/// is forfeited to the treasury.
 #[derive(Accounts)]
 pub struct AdminCloseSymbol<'info> {
     #[account(mut)]
@@ -16,6 +20,22 @@ pub struct AdminCloseSymbol<'info> {
     )]
     pub config: Account<'info, Config>,
 
+    /// Keeper vault releases the symbol's outstanding keeper deposit
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Account<'info, KeeperVault>,
+
+    /// Bond vault forfeits the symbol's bond (required when the symbol has a bond)
+    #[account(
+        mut,
+        seeds = [BondVault::SEED_PREFIX],
+        bump = bond_vault.bump,
+    )]
+    pub bond_vault: Option<Account<'info, BondVault>>,
+
     #[account(
         mut,
         close = admin,
@@ -26,12 +46,20 @@ pub struct AdminCloseSymbol<'info> {
 pub fn handler(ctx: Context<AdminCloseSymbol>) -> Result<()> {
     let clock = Clock::get()?;
 
+    validate_no_open_dispute(&ctx.accounts.token_account)?;
+
+    ctx.accounts.keeper_vault.forfeit_deposit(ctx.accounts.token_account.keeper_deposit)?;
+
+    let bond_forfeited = ctx.accounts.token_account.bond_lamports;
+    record_bond_forfeit(ctx.accounts.bond_vault.as_mut(), bond_forfeited)?;
+
     emit!(SymbolClosedByAdmin {
         token_account: ctx.accounts.token_account.key(),
         symbol: ctx.accounts.token_account.symbol.clone(),
         previous_owner: ctx.accounts.token_account.owner,
         previous_mint: ctx.accounts.token_account.mint,
         admin: ctx.accounts.admin.key(),
+        bond_forfeited,
         closed_at: clock.unix_timestamp,
     });
 
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use crate::{Config, Token, TnsError, SymbolUpdatedByAdmin};
+use crate::{Config, Token, TnsError, SymbolUpdatedByAdmin, MetadataCommitment};
+use crate::instructions::registrar::helpers::validate_no_open_dispute;
 
 /// Admin-only instruction to force-update a symbol's owner, mint, or expiration.
 /// Use cases: fix mistakes, revoke from bad actors, extend expiration for partners.
+/// Owner changes are frozen while a dispute is open - disputes are settled by the arbiters.
 #[derive(Accounts)]
 pub struct AdminUpdateSymbol<'info> {
     #[account(mut)]
@@ -35,11 +37,16 @@ pub fn handler(
 
     // Apply updates
     if let Some(owner) = new_owner {
+        validate_no_open_dispute(token)?;
         token.owner = owner;
+        token.clear_claim();
     }
 
     if let Some(mint) = new_mint {
         token.mint = mint;
+        token.drift_flagged_at = 0;
+        // No metadata accounts here - fall back to symbol-only drift checks
+        token.metadata_commitment = MetadataCommitment::default();
     }
 
     if let Some(expires_at) = new_expires_at {
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::{Config, Token, TnsError, SymbolSeeded, BatchSeeded, PriceLock};
+use crate::instructions::registrar::helpers::{
+    validate_symbol_format, validate_and_calculate_expiration, validate_mint_metadata,
+    validate_batch_size, initialize_token_account, create_token_pda, SymbolInitData,
+};
+
+/// Number of remaining_accounts per batch seed entry: token_account, token_mint, token_metadata
+const ACCOUNTS_PER_SEED_ENTRY: usize = 3;
+
+/// A single symbol to seed in a batch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BatchSeedEntry {
+    pub symbol: String,
+    pub years: u8,
+    pub owner: Pubkey,
+}
+
+/// Admin-only instruction to seed several verified tokens in one transaction.
+/// Same rules as seed_symbol. For each entry, remaining_accounts holds
+/// [token_account PDA (writable), token_mint, token_metadata] in entry order.
+#[derive(Accounts)]
+pub struct BatchSeedSymbols<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchSeedSymbols<'info>>,
+    entries: Vec<BatchSeedEntry>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    validate_batch_size(entries.len())?;
+    require!(
+        ctx.remaining_accounts.len() == entries.len() * ACCOUNTS_PER_SEED_ENTRY,
+        TnsError::InvalidBatchAccounts
+    );
+
+    let mut total_rent_paid: u64 = 0;
+
+    for (entry, accounts) in entries.iter().zip(ctx.remaining_accounts.chunks(ACCOUNTS_PER_SEED_ENTRY)) {
+        let token_info = &accounts[0];
+        let mint_info = &accounts[1];
+        let metadata_info = &accounts[2];
+
+        let normalized_symbol = validate_symbol_format(&entry.symbol)?;
+
+        let (expected_pda, bump) = Pubkey::find_program_address(
+            &[Token::SEED_PREFIX, normalized_symbol.as_bytes()],
+            ctx.program_id,
+        );
+        require_keys_eq!(token_info.key(), expected_pda, TnsError::InvalidBatchAccounts);
+
+        let expires_at = validate_and_calculate_expiration(
+            clock.unix_timestamp,
+            entry.years,
+            clock.unix_timestamp,
+        )?;
+
+        // The verified token mint
+        let token_mint = InterfaceAccount::<Mint>::try_from(mint_info)?;
+
+        // Validate mint metadata matches symbol
+        let metadata = validate_mint_metadata(
+            metadata_info,
+            &token_mint.to_account_info(),
+            &normalized_symbol,
+        )?;
+
+        // Create the token PDA (fails if the symbol is already registered)
+        let rent_paid = create_token_pda(
+            &ctx.accounts.admin.to_account_info(),
+            token_info,
+            &ctx.accounts.system_program.to_account_info(),
+            &[Token::SEED_PREFIX, normalized_symbol.as_bytes(), &[bump]],
+        )?;
+        total_rent_paid = total_rent_paid.saturating_add(rent_paid);
+
+        let mut token_account = Account::<Token>::try_from_unchecked(token_info)?;
+        initialize_token_account(
+            &mut token_account,
+            SymbolInitData {
+                symbol: normalized_symbol.clone(),
+                mint: token_mint.key(),
+                owner: entry.owner,
+                current_time: clock.unix_timestamp,
+                expires_at,
+                bump,
+                metadata_commitment: metadata.commitment(clock.unix_timestamp),
+                keeper_deposit: 0,
+                price_lock: PriceLock::default(),
+                bond_lamports: 0,
+            },
+        );
+        token_account.exit(ctx.program_id)?;
+
+        emit!(SymbolSeeded {
+            token_account: token_info.key(),
+            symbol: normalized_symbol,
+            mint: token_mint.key(),
+            owner: entry.owner,
+            years: entry.years,
+            seeded_at: clock.unix_timestamp,
+            expires_at,
+        });
+    }
+
+    emit!(BatchSeeded {
+        admin: ctx.accounts.admin.key(),
+        count: entries.len() as u8,
+        total_rent_paid,
+        seeded_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
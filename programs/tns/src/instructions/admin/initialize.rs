@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use crate::{
-    Config, ProtocolInitialized,
+    BondPolicy, Config, EligibilityPolicy, PriceLockPolicy, RevenueSplit, TnsTwapPolicy, ProtocolInitialized,
     BASE_PRICE_USD_MICRO, ANNUAL_INCREASE_BPS, UPDATE_FEE_BPS, KEEPER_REWARD_LAMPORTS,
+    MAJORITY_HOLD_PERIOD_SECONDS, CLAIM_COOLDOWN_SECONDS, DRIFT_GRACE_PERIOD_SECONDS,
+    TNS_TWAP_WINDOW_SECONDS, TNS_MAX_SPOT_DEVIATION_BPS,
 };
 
 #[derive(Accounts)]
@@ -44,12 +46,23 @@ pub fn handler(ctx: Context<Initialize>) -> Result<()> {
     config.annual_increase_bps = ANNUAL_INCREASE_BPS;
     config.update_fee_bps = UPDATE_FEE_BPS;
     config.sol_usd_pyth_feed = ctx.accounts.sol_usd_pyth_feed.key();
-    config.tns_usd_pyth_feed = None; // Reserved for future direct TNS/USD Pyth feed
+    config.tns_usd_pyth_feed = None; // Optional direct TNS/USD Pyth source, set via update_config
     config.keeper_reward_lamports = KEEPER_REWARD_LAMPORTS;
     config.launch_timestamp = clock.unix_timestamp;
     config.paused = true;
     config.phase = 1; // Start in Phase 1 (Genesis)
     config.bump = ctx.bumps.config;
+    config.majority_hold_period_seconds = MAJORITY_HOLD_PERIOD_SECONDS;
+    config.claim_cooldown_seconds = CLAIM_COOLDOWN_SECONDS;
+    config.drift_grace_period_seconds = DRIFT_GRACE_PERIOD_SECONDS;
+    config.eligibility_policy = EligibilityPolicy::default();
+    config.price_lock_policy = PriceLockPolicy::default();
+    config.tns_twap_policy = TnsTwapPolicy {
+        window_seconds: TNS_TWAP_WINDOW_SECONDS,
+        max_deviation_bps: TNS_MAX_SPOT_DEVIATION_BPS,
+    };
+    config.revenue_split = RevenueSplit::TREASURY_ONLY;
+    config.bond_policy = BondPolicy::default();
 
     emit!(ProtocolInitialized {
         config: ctx.accounts.config.key(),
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::{BondVault, Config, TnsError};
+
+/// Admin-only instruction to create the bond vault.
+/// In bond pricing mode SOL registrations and expired claims lock their price here;
+/// bonds are refunded on release and forfeited on cancellation or drift close.
+#[derive(Accounts)]
+pub struct InitializeBondVault<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + BondVault::INIT_SPACE,
+        seeds = [BondVault::SEED_PREFIX],
+        bump
+    )]
+    pub bond_vault: Account<'info, BondVault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeBondVault>) -> Result<()> {
+    let bond_vault = &mut ctx.accounts.bond_vault;
+    bond_vault.outstanding_bonds = 0;
+    bond_vault.total_bonded = 0;
+    bond_vault.total_refunded = 0;
+    bond_vault.total_forfeited = 0;
+    bond_vault.bump = ctx.bumps.bond_vault;
+
+    Ok(())
+}
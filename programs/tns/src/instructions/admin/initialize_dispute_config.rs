@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::{
+    Config, DisputeConfig, DisputeConfigUpdated, TnsError,
+    DISPUTE_MIN_BOND_LAMPORTS, DISPUTE_RESPONSE_WINDOW_SECONDS, DISPUTE_VOTING_WINDOW_SECONDS,
+};
+use crate::instructions::registrar::helpers::validate_dispute_config;
+
+/// Admin-only instruction to create the dispute config with the initial arbiter set.
+/// Bond minimum and windows start at their defaults and can be changed with update_dispute_config.
+#[derive(Accounts)]
+pub struct InitializeDisputeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + DisputeConfig::INIT_SPACE,
+        seeds = [DisputeConfig::SEED_PREFIX],
+        bump
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeDisputeConfig>, arbiters: Vec<Pubkey>, quorum: u8) -> Result<()> {
+    validate_dispute_config(&arbiters, quorum)?;
+
+    let dispute_config = &mut ctx.accounts.dispute_config;
+    dispute_config.arbiters = arbiters;
+    dispute_config.quorum = quorum;
+    dispute_config.min_bond_lamports = DISPUTE_MIN_BOND_LAMPORTS;
+    dispute_config.response_window_seconds = DISPUTE_RESPONSE_WINDOW_SECONDS;
+    dispute_config.voting_window_seconds = DISPUTE_VOTING_WINDOW_SECONDS;
+    dispute_config.bump = ctx.bumps.dispute_config;
+
+    emit!(DisputeConfigUpdated {
+        dispute_config: dispute_config.key(),
+        arbiters: dispute_config.arbiters.clone(),
+        quorum: dispute_config.quorum,
+        min_bond_lamports: dispute_config.min_bond_lamports,
+        response_window_seconds: dispute_config.response_window_seconds,
+        voting_window_seconds: dispute_config.voting_window_seconds,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::{Config, KeeperVault, TnsError};
+
+/// Admin-only instruction to create the keeper vault.
+/// Registrations deposit their keeper reward here; keepers that close a symbol are paid
+/// exactly that symbol's deposit. Legacy lamports held by the Config PDA can be moved
+/// over with reconcile_keeper_vault.
+#[derive(Accounts)]
+pub struct InitializeKeeperVault<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + KeeperVault::INIT_SPACE,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump
+    )]
+    pub keeper_vault: Account<'info, KeeperVault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeKeeperVault>) -> Result<()> {
+    let keeper_vault = &mut ctx.accounts.keeper_vault;
+    keeper_vault.outstanding_deposits = 0;
+    keeper_vault.total_deposited = 0;
+    keeper_vault.total_paid = 0;
+    keeper_vault.bump = ctx.bumps.keeper_vault;
+
+    Ok(())
+}
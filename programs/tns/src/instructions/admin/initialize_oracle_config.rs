@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::{
+    Config, OracleConfig, OracleConfigUpdated, OracleVerificationLevel, TnsError, SOL_USD_FEED_ID,
+    MAX_PRICE_STALENESS_SECONDS, DEFAULT_MIN_GUARDIAN_SIGNATURES, SOL_CIRCUIT_TRIP_VIOLATIONS,
+    SOL_LAST_GOOD_PRICE_MAX_AGE_SECONDS,
+};
+
+/// Admin-only instruction to create the oracle config with the default SOL/USD feed,
+/// 60s staleness, 3-signature partial verification and the Pyth receiver as owner
+/// Circuit-breaker guards start disabled.
+#[derive(Accounts)]
+pub struct InitializeOracleConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + OracleConfig::INIT_SPACE,
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeOracleConfig>) -> Result<()> {
+    let oracle_config = &mut ctx.accounts.oracle_config;
+    oracle_config.sol_usd_feed_id = SOL_USD_FEED_ID;
+    oracle_config.max_staleness_seconds = MAX_PRICE_STALENESS_SECONDS;
+    oracle_config.min_verification = OracleVerificationLevel::Partial {
+        num_signatures: DEFAULT_MIN_GUARDIAN_SIGNATURES,
+    };
+    oracle_config.price_update_owner = pyth_solana_receiver_sdk::ID;
+    oracle_config.max_confidence_bps = 0;
+    oracle_config.min_sol_price_usd_micro = 0;
+    oracle_config.max_sol_price_usd_micro = 0;
+    oracle_config.max_deviation_bps = 0;
+    oracle_config.last_good_sol_price_micro = 0;
+    oracle_config.last_good_sol_price_at = 0;
+    oracle_config.fallback_sol_price_usd_micro = 0;
+    oracle_config.fallback_sol_price_expires_at = 0;
+    oracle_config.auto_pause = false;
+    oracle_config.sol_circuit_tripped = false;
+    oracle_config.trip_after_violations = SOL_CIRCUIT_TRIP_VIOLATIONS;
+    oracle_config.sol_violation_count = 0;
+    oracle_config.last_violation_publish_time = 0;
+    oracle_config.max_last_good_age_seconds = SOL_LAST_GOOD_PRICE_MAX_AGE_SECONDS;
+    oracle_config.bump = ctx.bumps.oracle_config;
+
+    emit!(OracleConfigUpdated {
+        oracle_config: oracle_config.key(),
+        sol_usd_feed_id: oracle_config.sol_usd_feed_id,
+        max_staleness_seconds: oracle_config.max_staleness_seconds,
+        min_verification: oracle_config.min_verification,
+        price_update_owner: oracle_config.price_update_owner,
+        max_confidence_bps: oracle_config.max_confidence_bps,
+        min_sol_price_usd_micro: oracle_config.min_sol_price_usd_micro,
+        max_sol_price_usd_micro: oracle_config.max_sol_price_usd_micro,
+        max_deviation_bps: oracle_config.max_deviation_bps,
+        auto_pause: oracle_config.auto_pause,
+        trip_after_violations: oracle_config.trip_after_violations,
+        max_last_good_age_seconds: oracle_config.max_last_good_age_seconds,
+        updated_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{Config, RevenueVault, RevenueVaultUpdated, TnsError};
+use crate::instructions::registrar::helpers::validate_buyback_settings;
+
+/// Admin-only instruction to create the revenue vault for a payment mint
+/// Use the WSOL mint for SOL revenue. The vault token account is created for
+/// every mint; for WSOL it is only used to wrap lamports during buybacks.
+#[derive(Accounts)]
+pub struct InitializeRevenueVault<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The payment mint (SPL or Token-2022)
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RevenueVault::INIT_SPACE,
+        seeds = [RevenueVault::SEED_PREFIX, mint.key().as_ref()],
+        bump
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+
+    /// Token account holding the vault's token revenue, owned by the revenue vault PDA
+    #[account(
+        init,
+        payer = admin,
+        seeds = [RevenueVault::TOKEN_SEED_PREFIX, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = revenue_vault,
+        token::token_program = token_program,
+    )]
+    pub revenue_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeRevenueVault>,
+    buyback_pool: Pubkey,
+    buyback_max_slippage_bps: u16,
+) -> Result<()> {
+    validate_buyback_settings(buyback_max_slippage_bps)?;
+
+    let revenue_vault = &mut ctx.accounts.revenue_vault;
+    revenue_vault.mint = ctx.accounts.mint.key();
+    revenue_vault.buyback_pool = buyback_pool;
+    revenue_vault.buyback_max_slippage_bps = buyback_max_slippage_bps;
+    revenue_vault.token_bump = ctx.bumps.revenue_token_account;
+    revenue_vault.bump = ctx.bumps.revenue_vault;
+
+    emit!(RevenueVaultUpdated {
+        revenue_vault: revenue_vault.key(),
+        mint: revenue_vault.mint,
+        buyback_pool: revenue_vault.buyback_pool,
+        buyback_max_slippage_bps: revenue_vault.buyback_max_slippage_bps,
+        updated_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::{Config, TnsPriceObservation, TnsError};
+
+/// Admin-only instruction to create the TNS price observation buffer.
+/// Samples are recorded by the permissionless update_tns_price_observation crank;
+/// TNS-denominated fees are rejected until the buffer covers the TWAP window.
+#[derive(Accounts)]
+pub struct InitializeTnsPriceObservation<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + TnsPriceObservation::INIT_SPACE,
+        seeds = [TnsPriceObservation::SEED_PREFIX],
+        bump
+    )]
+    pub tns_price_observation: Box<Account<'info, TnsPriceObservation>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeTnsPriceObservation>) -> Result<()> {
+    let price_observation = &mut ctx.accounts.tns_price_observation;
+    price_observation.head = 0;
+    price_observation.count = 0;
+    price_observation.bump = ctx.bumps.tns_price_observation;
+
+    Ok(())
+}
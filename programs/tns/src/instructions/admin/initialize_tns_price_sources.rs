@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::{Config, TnsPriceSources, TnsPoolSource, TnsPriceSourcesUpdated, TnsError};
+use crate::instructions::registrar::helpers::validate_tns_price_sources;
+
+/// Admin-only instruction to create the list of DEX pools used to price TNS.
+/// Pools can be changed later with update_tns_price_sources.
+#[derive(Accounts)]
+pub struct InitializeTnsPriceSources<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + TnsPriceSources::INIT_SPACE,
+        seeds = [TnsPriceSources::SEED_PREFIX],
+        bump
+    )]
+    pub tns_price_sources: Box<Account<'info, TnsPriceSources>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeTnsPriceSources>,
+    pools: Vec<TnsPoolSource>,
+    min_sources: u8,
+) -> Result<()> {
+    validate_tns_price_sources(&pools, min_sources)?;
+
+    let price_sources = &mut ctx.accounts.tns_price_sources;
+    price_sources.pools = pools;
+    price_sources.min_sources = min_sources;
+    price_sources.bump = ctx.bumps.tns_price_sources;
+
+    emit!(TnsPriceSourcesUpdated {
+        price_sources: price_sources.key(),
+        pools: price_sources.pools.clone(),
+        min_sources: price_sources.min_sources,
+    });
+
+    Ok(())
+}
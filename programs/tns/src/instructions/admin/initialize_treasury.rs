@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{Config, Treasury, TreasuryUpdated, TnsError};
+use crate::instructions::registrar::helpers::validate_treasury_allowlist;
+
+/// Admin-only instruction to create the treasury PDA with its withdrawal allowlist
+/// Vaults are the treasury's associated token accounts; anyone can create them
+/// through the associated token program before the first payment in that mint.
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [Treasury::SEED_PREFIX],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeTreasury>, allowlist: Vec<Pubkey>) -> Result<()> {
+    validate_treasury_allowlist(&allowlist)?;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.allowlist = allowlist;
+    treasury.withdrawal_count = 0;
+    treasury.bump = ctx.bumps.treasury;
+
+    emit!(TreasuryUpdated {
+        treasury: treasury.key(),
+        allowlist: treasury.allowlist.clone(),
+        updated_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
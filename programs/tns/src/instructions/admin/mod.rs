@@ -1,17 +1,86 @@
 pub mod initialize;
 pub mod seed_symbol;
+pub mod batch_seed_symbols;
 pub mod update_config;
 pub mod admin_update_symbol;
 pub mod admin_close_symbol;
+pub mod initialize_dispute_config;
+pub mod update_dispute_config;
+pub mod initialize_keeper_vault;
+pub mod reconcile_keeper_vault;
+pub mod add_payment_asset;
+pub mod update_payment_asset;
+pub mod initialize_tns_price_observation;
+pub mod initialize_tns_price_sources;
+pub mod update_tns_price_sources;
+pub mod register_platform;
+pub mod update_platform;
+pub mod initialize_revenue_vault;
+pub mod update_revenue_vault;
+pub mod initialize_treasury;
+pub mod update_treasury_allowlist;
+pub mod withdraw_treasury;
+pub mod initialize_oracle_config;
+pub mod update_oracle_config;
+pub mod reset_sol_circuit;
+pub mod post_fallback_sol_price;
+pub mod initialize_bond_vault;
+pub mod sweep_bond_vault;
 
 pub use initialize::Initialize;
 pub use seed_symbol::SeedSymbol;
+pub use batch_seed_symbols::{BatchSeedSymbols, BatchSeedEntry};
 pub use update_config::UpdateConfig;
 pub use admin_update_symbol::AdminUpdateSymbol;
 pub use admin_close_symbol::AdminCloseSymbol;
+pub use initialize_dispute_config::InitializeDisputeConfig;
+pub use update_dispute_config::UpdateDisputeConfig;
+pub use initialize_keeper_vault::InitializeKeeperVault;
+pub use reconcile_keeper_vault::ReconcileKeeperVault;
+pub use add_payment_asset::AddPaymentAsset;
+pub use update_payment_asset::UpdatePaymentAsset;
+pub use initialize_tns_price_observation::InitializeTnsPriceObservation;
+pub use initialize_tns_price_sources::InitializeTnsPriceSources;
+pub use update_tns_price_sources::UpdateTnsPriceSources;
+pub use register_platform::RegisterPlatform;
+pub use update_platform::UpdatePlatform;
+pub use initialize_revenue_vault::InitializeRevenueVault;
+pub use update_revenue_vault::UpdateRevenueVault;
+pub use initialize_treasury::InitializeTreasury;
+pub use update_treasury_allowlist::UpdateTreasuryAllowlist;
+pub use withdraw_treasury::WithdrawTreasury;
+pub use initialize_oracle_config::InitializeOracleConfig;
+pub use update_oracle_config::UpdateOracleConfig;
+pub use reset_sol_circuit::ResetSolCircuit;
+pub use post_fallback_sol_price::PostFallbackSolPrice;
+pub use initialize_bond_vault::InitializeBondVault;
+pub use sweep_bond_vault::SweepBondVault;
 
 pub(crate) use initialize::__client_accounts_initialize;
 pub(crate) use seed_symbol::__client_accounts_seed_symbol;
+pub(crate) use batch_seed_symbols::__client_accounts_batch_seed_symbols;
 pub(crate) use update_config::__client_accounts_update_config;
 pub(crate) use admin_update_symbol::__client_accounts_admin_update_symbol;
 pub(crate) use admin_close_symbol::__client_accounts_admin_close_symbol;
+pub(crate) use initialize_dispute_config::__client_accounts_initialize_dispute_config;
+pub(crate) use update_dispute_config::__client_accounts_update_dispute_config;
+pub(crate) use initialize_keeper_vault::__client_accounts_initialize_keeper_vault;
+pub(crate) use reconcile_keeper_vault::__client_accounts_reconcile_keeper_vault;
+pub(crate) use add_payment_asset::__client_accounts_add_payment_asset;
+pub(crate) use update_payment_asset::__client_accounts_update_payment_asset;
+pub(crate) use initialize_tns_price_observation::__client_accounts_initialize_tns_price_observation;
+pub(crate) use initialize_tns_price_sources::__client_accounts_initialize_tns_price_sources;
+pub(crate) use update_tns_price_sources::__client_accounts_update_tns_price_sources;
+pub(crate) use register_platform::__client_accounts_register_platform;
+pub(crate) use update_platform::__client_accounts_update_platform;
+pub(crate) use initialize_revenue_vault::__client_accounts_initialize_revenue_vault;
+pub(crate) use update_revenue_vault::__client_accounts_update_revenue_vault;
+pub(crate) use initialize_treasury::__client_accounts_initialize_treasury;
+pub(crate) use update_treasury_allowlist::__client_accounts_update_treasury_allowlist;
+pub(crate) use withdraw_treasury::__client_accounts_withdraw_treasury;
+pub(crate) use initialize_oracle_config::__client_accounts_initialize_oracle_config;
+pub(crate) use update_oracle_config::__client_accounts_update_oracle_config;
+pub(crate) use reset_sol_circuit::__client_accounts_reset_sol_circuit;
+pub(crate) use post_fallback_sol_price::__client_accounts_post_fallback_sol_price;
+pub(crate) use initialize_bond_vault::__client_accounts_initialize_bond_vault;
+pub(crate) use sweep_bond_vault::__client_accounts_sweep_bond_vault;
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{Config, OracleConfig, FallbackPriceUpdated, TnsError};
+use crate::instructions::registrar::helpers::validate_fallback_sol_price;
+
+/// Admin-only instruction to post a short-lived SOL/USD fallback price
+/// Used by SOL payments and TNS spot pricing while the Pyth update is missing or
+/// stale. A zero TTL clears the fallback.
+#[derive(Accounts)]
+pub struct PostFallbackSolPrice<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+}
+
+pub fn handler(ctx: Context<PostFallbackSolPrice>, price_usd_micro: u64, ttl_seconds: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    let oracle_config = &mut ctx.accounts.oracle_config;
+
+    validate_fallback_sol_price(oracle_config, price_usd_micro, ttl_seconds, clock.unix_timestamp)?;
+
+    oracle_config.fallback_sol_price_usd_micro = if ttl_seconds > 0 { price_usd_micro } else { 0 };
+    oracle_config.fallback_sol_price_expires_at = clock.unix_timestamp + ttl_seconds;
+
+    emit!(FallbackPriceUpdated {
+        oracle_config: oracle_config.key(),
+        price_usd_micro: oracle_config.fallback_sol_price_usd_micro,
+        expires_at: oracle_config.fallback_sol_price_expires_at,
+        posted_by: ctx.accounts.admin.key(),
+        posted_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::{Config, KeeperVault, TnsError, KeeperVaultReconciled};
+
+/// Admin-only instruction to reconcile the keeper vault balance against its recorded deposits.
+///
+/// - Moves any legacy keeper reward lamports held by the Config PDA (above its rent minimum)
+///   into the vault's legacy reserve, which pays keepers closing symbols without a deposit
+/// - Reports the surplus (lamports above rent + outstanding deposits + legacy reserve) or deficit
+/// - Optionally sweeps the surplus to the fee collector
+///
+/// Outstanding deposits and the legacy reserve are never swept, so every open symbol's
+/// keeper reward stays covered.
+#[derive(Accounts)]
+pub struct ReconcileKeeperVault<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Account<'info, KeeperVault>,
+
+    /// CHECK: Fee collector receives any swept surplus
+    #[account(
+        mut,
+        address = config.fee_collector,
+    )]
+    pub fee_collector: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<ReconcileKeeperVault>, sweep_surplus: bool) -> Result<()> {
+    let clock = Clock::get()?;
+    let rent = Rent::get()?;
+
+    let config_info = ctx.accounts.config.to_account_info();
+    let vault_info = ctx.accounts.keeper_vault.to_account_info();
+
+    // Migrate legacy keeper reward lamports from the Config PDA
+    let config_min_rent = rent.minimum_balance(config_info.data_len());
+    let migrated_from_config = config_info.lamports().saturating_sub(config_min_rent);
+
+    if migrated_from_config > 0 {
+        **config_info.try_borrow_mut_lamports()? -= migrated_from_config;
+        **vault_info.try_borrow_mut_lamports()? += migrated_from_config;
+        ctx.accounts.keeper_vault.record_legacy_migration(migrated_from_config)?;
+    }
+
+    // Compare the vault balance against rent + outstanding deposits + legacy reserve
+    let vault_min_rent = rent.minimum_balance(vault_info.data_len());
+    let required = vault_min_rent
+        .checked_add(ctx.accounts.keeper_vault.committed_lamports()?)
+        .ok_or(TnsError::MathOverflow)?;
+    let surplus = vault_info.lamports().saturating_sub(required);
+    let deficit = required.saturating_sub(vault_info.lamports());
+
+    let swept = if sweep_surplus { surplus } else { 0 };
+
+    if swept > 0 {
+        **vault_info.try_borrow_mut_lamports()? -= swept;
+        **ctx.accounts.fee_collector.try_borrow_mut_lamports()? += swept;
+    }
+
+    emit!(KeeperVaultReconciled {
+        keeper_vault: ctx.accounts.keeper_vault.key(),
+        balance: vault_info.lamports(),
+        outstanding_deposits: ctx.accounts.keeper_vault.outstanding_deposits,
+        legacy_reserve: ctx.accounts.keeper_vault.legacy_reserve,
+        surplus,
+        deficit,
+        migrated_from_config,
+        swept,
+        reconciled_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::{Config, Platform, PlatformUpdated, TnsError};
+use crate::instructions::registrar::helpers::validate_platform_fee_bps;
+
+/// Admin-only instruction to register a platform allowed to take a fee share
+/// The platform's authority can later change its payout wallet.
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct RegisterPlatform<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Platform::INIT_SPACE,
+        seeds = [Platform::SEED_PREFIX, authority.as_ref()],
+        bump
+    )]
+    pub platform: Account<'info, Platform>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterPlatform>,
+    authority: Pubkey,
+    payout_wallet: Pubkey,
+    max_fee_bps: u16,
+) -> Result<()> {
+    validate_platform_fee_bps(max_fee_bps)?;
+
+    let clock = Clock::get()?;
+
+    let platform = &mut ctx.accounts.platform;
+    platform.authority = authority;
+    platform.payout_wallet = payout_wallet;
+    platform.max_fee_bps = max_fee_bps;
+    platform.active = true;
+    platform.registered_at = clock.unix_timestamp;
+    platform.bump = ctx.bumps.platform;
+
+    emit!(PlatformUpdated {
+        platform: platform.key(),
+        authority: platform.authority,
+        payout_wallet: platform.payout_wallet,
+        max_fee_bps: platform.max_fee_bps,
+        active: platform.active,
+        updated_by: ctx.accounts.admin.key(),
+        updated_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
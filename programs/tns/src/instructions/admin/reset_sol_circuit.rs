@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::{Config, OracleConfig, SolCircuitReset, TnsError};
+
+/// Admin-only instruction to clear a tripped SOL/USD circuit breaker
+/// Sets the reference price for the deviation guard; zero re-anchors it on the
+/// next price recorded by record_sol_price.
+#[derive(Accounts)]
+pub struct ResetSolCircuit<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+}
+
+pub fn handler(ctx: Context<ResetSolCircuit>, last_good_price_usd_micro: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let oracle_config = &mut ctx.accounts.oracle_config;
+    oracle_config.sol_circuit_tripped = false;
+    oracle_config.sol_violation_count = 0;
+    oracle_config.last_good_sol_price_micro = last_good_price_usd_micro;
+    oracle_config.last_good_sol_price_at = clock.unix_timestamp;
+
+    emit!(SolCircuitReset {
+        oracle_config: oracle_config.key(),
+        last_good_price_usd_micro,
+        reset_by: ctx.accounts.admin.key(),
+        reset_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
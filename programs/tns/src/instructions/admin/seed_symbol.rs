@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
-use crate::{Config, Token, TnsError, SymbolSeeded};
+use crate::{Config, Token, TnsError, SymbolSeeded, PriceLock};
 use crate::instructions::registrar::helpers::{
     validate_symbol_format, validate_and_calculate_expiration,
-    validate_mint_metadata,
+    validate_mint_metadata, initialize_token_account, SymbolInitData,
 };
 
 /// Admin-only instruction to seed the registry with verified tokens.
@@ -53,19 +53,27 @@ pub fn handler(ctx: Context<SeedSymbol>, symbol: String, years: u8, owner: Pubke
     )?;
 
     // Validate mint metadata matches symbol
-    validate_mint_metadata(
+    let metadata = validate_mint_metadata(
         &ctx.accounts.token_metadata,
         &ctx.accounts.token_mint.to_account_info(),
         &normalized_symbol,
     )?;
 
-    ctx.accounts.token_account.symbol = normalized_symbol.clone();
-    ctx.accounts.token_account.mint = ctx.accounts.token_mint.key();
-    ctx.accounts.token_account.owner = owner;
-    ctx.accounts.token_account.registered_at = clock.unix_timestamp;
-    ctx.accounts.token_account.expires_at = expires_at;
-    ctx.accounts.token_account.bump = ctx.bumps.token_account;
-    ctx.accounts.token_account._reserved = [0u8; 64];
+    initialize_token_account(
+        &mut ctx.accounts.token_account,
+        SymbolInitData {
+            symbol: normalized_symbol.clone(),
+            mint: ctx.accounts.token_mint.key(),
+            owner,
+            current_time: clock.unix_timestamp,
+            expires_at,
+            bump: ctx.bumps.token_account,
+            metadata_commitment: metadata.commitment(clock.unix_timestamp),
+            keeper_deposit: 0,
+            price_lock: PriceLock::default(),
+            bond_lamports: 0,
+        },
+    );
 
     emit!(SymbolSeeded {
         token_account: ctx.accounts.token_account.key(),
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::{BondVault, Config, TnsError, BondVaultSwept};
+
+/// Admin-only instruction to sweep forfeited bonds from the bond vault to the fee collector.
+/// Only lamports above rent + outstanding bonds are swept, so every open symbol's bond
+/// stays covered.
+#[derive(Accounts)]
+pub struct SweepBondVault<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [BondVault::SEED_PREFIX],
+        bump = bond_vault.bump,
+    )]
+    pub bond_vault: Account<'info, BondVault>,
+
+    /// CHECK: Fee collector receives the swept surplus
+    #[account(
+        mut,
+        address = config.fee_collector,
+    )]
+    pub fee_collector: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<SweepBondVault>) -> Result<()> {
+    let clock = Clock::get()?;
+    let vault_info = ctx.accounts.bond_vault.to_account_info();
+
+    let required = Rent::get()?
+        .minimum_balance(vault_info.data_len())
+        .checked_add(ctx.accounts.bond_vault.outstanding_bonds)
+        .ok_or(TnsError::MathOverflow)?;
+    let swept = vault_info.lamports().saturating_sub(required);
+
+    if swept > 0 {
+        **vault_info.try_borrow_mut_lamports()? -= swept;
+        **ctx.accounts.fee_collector.try_borrow_mut_lamports()? += swept;
+    }
+
+    emit!(BondVaultSwept {
+        bond_vault: ctx.accounts.bond_vault.key(),
+        balance: vault_info.lamports(),
+        outstanding_bonds: ctx.accounts.bond_vault.outstanding_bonds,
+        swept,
+        swept_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::{Config, ConfigUpdated, TnsError};
+use crate::{
+    BondPolicy, Config, ConfigUpdated, EligibilityPolicy, PriceLockPolicy, RevenueSplit, TnsTwapPolicy, TnsError,
+    TNS_OBSERVATION_MIN_INTERVAL_SECONDS, MAX_TNS_TWAP_WINDOW_SECONDS,
+};
 
 #[derive(Accounts)]
 pub struct UpdateConfig<'info> {
@@ -19,6 +22,7 @@ pub struct UpdateConfig<'info> {
     pub new_admin: Option<Signer<'info>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<UpdateConfig>,
     new_fee_collector: Option<Pubkey>,
@@ -28,6 +32,15 @@ pub fn handler(
     keeper_reward_lamports: Option<u64>,
     base_price_usd_micro: Option<u64>,
     annual_increase_bps: Option<u16>,
+    majority_hold_period_seconds: Option<i64>,
+    claim_cooldown_seconds: Option<i64>,
+    drift_grace_period_seconds: Option<i64>,
+    eligibility_policy: Option<EligibilityPolicy>,
+    price_lock_policy: Option<PriceLockPolicy>,
+    stablecoin_depeg_band_bps: Option<u16>,
+    tns_twap_policy: Option<TnsTwapPolicy>,
+    revenue_split: Option<RevenueSplit>,
+    bond_policy: Option<BondPolicy>,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
 
@@ -51,7 +64,8 @@ pub fn handler(
     }
 
     if let Some(feed) = tns_usd_pyth_feed {
-        config.tns_usd_pyth_feed = Some(feed);
+        // The default pubkey clears the feed
+        config.tns_usd_pyth_feed = (feed != Pubkey::default()).then_some(feed);
     }
 
     if let Some(reward) = keeper_reward_lamports {
@@ -66,6 +80,56 @@ pub fn handler(
         config.annual_increase_bps = increase;
     }
 
+    if let Some(period) = majority_hold_period_seconds {
+        require!(period > 0, TnsError::InvalidHoldPeriod);
+        config.majority_hold_period_seconds = period;
+    }
+
+    if let Some(cooldown) = claim_cooldown_seconds {
+        require!(cooldown > 0, TnsError::InvalidClaimCooldown);
+        config.claim_cooldown_seconds = cooldown;
+    }
+
+    if let Some(grace) = drift_grace_period_seconds {
+        require!(grace > 0, TnsError::InvalidDriftGracePeriod);
+        config.drift_grace_period_seconds = grace;
+    }
+
+    if let Some(policy) = eligibility_policy {
+        config.eligibility_policy = policy;
+    }
+
+    if let Some(policy) = price_lock_policy {
+        require!(policy.max_escalation_bps <= 10000, TnsError::InvalidPriceLockPolicy);
+        config.price_lock_policy = policy;
+    }
+
+    if let Some(band) = stablecoin_depeg_band_bps {
+        require!((1..=10000).contains(&band), TnsError::InvalidDepegBand);
+        config.stablecoin_depeg_band_bps = band;
+    }
+
+    if let Some(policy) = tns_twap_policy {
+        // The window must span at least one sample interval and fit in the observation buffer
+        require!(
+            policy.window_seconds as i64 >= TNS_OBSERVATION_MIN_INTERVAL_SECONDS
+                && policy.window_seconds <= MAX_TNS_TWAP_WINDOW_SECONDS
+                && (1..=10000).contains(&policy.max_deviation_bps),
+            TnsError::InvalidTwapPolicy
+        );
+        config.tns_twap_policy = policy;
+    }
+
+    if let Some(split) = revenue_split {
+        require!(split.total_bps() == 10000, TnsError::InvalidRevenueSplit);
+        config.revenue_split = split;
+    }
+
+    if let Some(policy) = bond_policy {
+        require!(policy.keeper_share_bps <= 10000, TnsError::InvalidBondPolicy);
+        config.bond_policy = policy;
+    }
+
     emit!(ConfigUpdated {
         admin: config.admin,
         fee_collector: config.fee_collector,
@@ -75,6 +139,15 @@ pub fn handler(
         tns_usd_pyth_feed: config.tns_usd_pyth_feed,
         paused: config.paused,
         phase: config.phase,
+        majority_hold_period_seconds: config.get_majority_hold_period(),
+        claim_cooldown_seconds: config.get_claim_cooldown(),
+        drift_grace_period_seconds: config.get_drift_grace_period(),
+        eligibility_policy: config.eligibility_policy,
+        price_lock_policy: config.price_lock_policy,
+        stablecoin_depeg_band_bps: config.get_stablecoin_depeg_band(),
+        tns_twap_policy: config.tns_twap_policy,
+        revenue_split: config.get_revenue_split(),
+        bond_policy: config.bond_policy,
     });
 
     Ok(())
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::{Config, DisputeConfig, DisputeConfigUpdated, TnsError};
+use crate::instructions::registrar::helpers::validate_dispute_config;
+
+/// Admin-only instruction to update the arbiter set and dispute parameters.
+/// Open disputes keep the quorum they were opened with.
+#[derive(Accounts)]
+pub struct UpdateDisputeConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [DisputeConfig::SEED_PREFIX],
+        bump = dispute_config.bump,
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateDisputeConfig>,
+    arbiters: Option<Vec<Pubkey>>,
+    quorum: Option<u8>,
+    min_bond_lamports: Option<u64>,
+    response_window_seconds: Option<i64>,
+    voting_window_seconds: Option<i64>,
+) -> Result<()> {
+    let dispute_config = &mut ctx.accounts.dispute_config;
+
+    if let Some(arbiters) = arbiters {
+        dispute_config.arbiters = arbiters;
+    }
+
+    if let Some(quorum) = quorum {
+        dispute_config.quorum = quorum;
+    }
+
+    // Re-validate together so a new arbiter set can't leave a stale quorum behind
+    validate_dispute_config(&dispute_config.arbiters, dispute_config.quorum)?;
+
+    if let Some(bond) = min_bond_lamports {
+        // A zero bond would let anyone freeze symbols with free disputes
+        require!(bond > 0, TnsError::InvalidDisputeConfig);
+        dispute_config.min_bond_lamports = bond;
+    }
+
+    if let Some(window) = response_window_seconds {
+        require!(window > 0, TnsError::InvalidDisputeConfig);
+        dispute_config.response_window_seconds = window;
+    }
+
+    if let Some(window) = voting_window_seconds {
+        require!(window > 0, TnsError::InvalidDisputeConfig);
+        dispute_config.voting_window_seconds = window;
+    }
+
+    emit!(DisputeConfigUpdated {
+        dispute_config: dispute_config.key(),
+        arbiters: dispute_config.arbiters.clone(),
+        quorum: dispute_config.quorum,
+        min_bond_lamports: dispute_config.min_bond_lamports,
+        response_window_seconds: dispute_config.response_window_seconds,
+        voting_window_seconds: dispute_config.voting_window_seconds,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::{Config, OracleConfig, OracleConfigUpdated, OracleVerificationLevel, TnsError};
+use crate::instructions::registrar::helpers::validate_oracle_config;
+
+/// Admin-only instruction to rotate Pyth feeds or change staleness, verification,
+/// the expected price update owner and the SOL/USD circuit-breaker guards
+#[derive(Accounts)]
+pub struct UpdateOracleConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<UpdateOracleConfig>,
+    sol_usd_feed_id: Option<[u8; 32]>,
+    max_staleness_seconds: Option<u64>,
+    min_verification: Option<OracleVerificationLevel>,
+    price_update_owner: Option<Pubkey>,
+    max_confidence_bps: Option<u16>,
+    min_sol_price_usd_micro: Option<u64>,
+    max_sol_price_usd_micro: Option<u64>,
+    max_deviation_bps: Option<u16>,
+    auto_pause: Option<bool>,
+    trip_after_violations: Option<u8>,
+    max_last_good_age_seconds: Option<i64>,
+) -> Result<()> {
+    let oracle_config = &mut ctx.accounts.oracle_config;
+
+    if let Some(feed_id) = sol_usd_feed_id {
+        oracle_config.sol_usd_feed_id = feed_id;
+    }
+    if let Some(seconds) = max_staleness_seconds {
+        oracle_config.max_staleness_seconds = seconds;
+    }
+    if let Some(level) = min_verification {
+        oracle_config.min_verification = level;
+    }
+    if let Some(owner) = price_update_owner {
+        oracle_config.price_update_owner = owner;
+    }
+    if let Some(bps) = max_confidence_bps {
+        oracle_config.max_confidence_bps = bps;
+    }
+    if let Some(price) = min_sol_price_usd_micro {
+        oracle_config.min_sol_price_usd_micro = price;
+    }
+    if let Some(price) = max_sol_price_usd_micro {
+        oracle_config.max_sol_price_usd_micro = price;
+    }
+    if let Some(bps) = max_deviation_bps {
+        oracle_config.max_deviation_bps = bps;
+    }
+    if let Some(enabled) = auto_pause {
+        oracle_config.auto_pause = enabled;
+    }
+    if let Some(violations) = trip_after_violations {
+        oracle_config.trip_after_violations = violations;
+    }
+    if let Some(seconds) = max_last_good_age_seconds {
+        oracle_config.max_last_good_age_seconds = seconds;
+    }
+
+    validate_oracle_config(oracle_config)?;
+
+    emit!(OracleConfigUpdated {
+        oracle_config: oracle_config.key(),
+        sol_usd_feed_id: oracle_config.sol_usd_feed_id,
+        max_staleness_seconds: oracle_config.max_staleness_seconds,
+        min_verification: oracle_config.min_verification,
+        price_update_owner: oracle_config.price_update_owner,
+        max_confidence_bps: oracle_config.max_confidence_bps,
+        min_sol_price_usd_micro: oracle_config.min_sol_price_usd_micro,
+        max_sol_price_usd_micro: oracle_config.max_sol_price_usd_micro,
+        max_deviation_bps: oracle_config.max_deviation_bps,
+        auto_pause: oracle_config.auto_pause,
+        trip_after_violations: oracle_config.trip_after_violations,
+        max_last_good_age_seconds: oracle_config.max_last_good_age_seconds,
+        updated_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::{Config, PaymentAsset, PriceSource, PaymentAssetUpdated, TnsError};
+use crate::instructions::registrar::helpers::validate_payment_asset_settings;
+
+/// Admin-only instruction to change a payment asset's price source, discount or enabled flag
+#[derive(Accounts)]
+pub struct UpdatePaymentAsset<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [PaymentAsset::SEED_PREFIX, payment_asset.mint.as_ref()],
+        bump = payment_asset.bump,
+    )]
+    pub payment_asset: Account<'info, PaymentAsset>,
+}
+
+pub fn handler(
+    ctx: Context<UpdatePaymentAsset>,
+    price_source: Option<PriceSource>,
+    discount_bps: Option<u16>,
+    enabled: Option<bool>,
+) -> Result<()> {
+    let payment_asset = &mut ctx.accounts.payment_asset;
+
+    if let Some(source) = price_source {
+        payment_asset.price_source = source;
+    }
+
+    if let Some(bps) = discount_bps {
+        payment_asset.discount_bps = bps;
+    }
+
+    if let Some(e) = enabled {
+        payment_asset.enabled = e;
+    }
+
+    validate_payment_asset_settings(
+        &payment_asset.price_source,
+        payment_asset.discount_bps,
+        &payment_asset.mint,
+    )?;
+
+    emit!(PaymentAssetUpdated {
+        payment_asset: payment_asset.key(),
+        mint: payment_asset.mint,
+        decimals: payment_asset.decimals,
+        price_source: payment_asset.price_source,
+        discount_bps: payment_asset.discount_bps,
+        enabled: payment_asset.enabled,
+        updated_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::{Config, Platform, PlatformUpdated, TnsError};
+use crate::instructions::registrar::helpers::validate_platform_fee_bps;
+
+/// Admin-only instruction to change a platform's fee limit, payout wallet or active flag
+#[derive(Accounts)]
+pub struct UpdatePlatform<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Platform::SEED_PREFIX, platform.authority.as_ref()],
+        bump = platform.bump,
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+pub fn handler(
+    ctx: Context<UpdatePlatform>,
+    payout_wallet: Option<Pubkey>,
+    max_fee_bps: Option<u16>,
+    active: Option<bool>,
+) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+
+    if let Some(wallet) = payout_wallet {
+        platform.payout_wallet = wallet;
+    }
+
+    if let Some(bps) = max_fee_bps {
+        validate_platform_fee_bps(bps)?;
+        platform.max_fee_bps = bps;
+    }
+
+    if let Some(a) = active {
+        platform.active = a;
+    }
+
+    emit!(PlatformUpdated {
+        platform: platform.key(),
+        authority: platform.authority,
+        payout_wallet: platform.payout_wallet,
+        max_fee_bps: platform.max_fee_bps,
+        active: platform.active,
+        updated_by: ctx.accounts.admin.key(),
+        updated_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::{Config, RevenueVault, RevenueVaultUpdated, TnsError};
+use crate::instructions::registrar::helpers::validate_buyback_settings;
+
+/// Admin-only instruction to change a revenue vault's buyback pool or slippage limit
+/// Setting the pool to the default pubkey disables buybacks for the mint.
+#[derive(Accounts)]
+pub struct UpdateRevenueVault<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [RevenueVault::SEED_PREFIX, revenue_vault.mint.as_ref()],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Account<'info, RevenueVault>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateRevenueVault>,
+    buyback_pool: Option<Pubkey>,
+    buyback_max_slippage_bps: Option<u16>,
+) -> Result<()> {
+    let revenue_vault = &mut ctx.accounts.revenue_vault;
+
+    if let Some(pool) = buyback_pool {
+        revenue_vault.buyback_pool = pool;
+    }
+
+    if let Some(bps) = buyback_max_slippage_bps {
+        revenue_vault.buyback_max_slippage_bps = bps;
+    }
+
+    validate_buyback_settings(revenue_vault.buyback_max_slippage_bps)?;
+
+    emit!(RevenueVaultUpdated {
+        revenue_vault: revenue_vault.key(),
+        mint: revenue_vault.mint,
+        buyback_pool: revenue_vault.buyback_pool,
+        buyback_max_slippage_bps: revenue_vault.buyback_max_slippage_bps,
+        updated_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::{Config, TnsPriceSources, TnsPoolSource, TnsPriceSourcesUpdated, TnsError};
+use crate::instructions::registrar::helpers::validate_tns_price_sources;
+
+/// Admin-only instruction to replace the TNS pool list or the minimum source count.
+#[derive(Accounts)]
+pub struct UpdateTnsPriceSources<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [TnsPriceSources::SEED_PREFIX],
+        bump = tns_price_sources.bump,
+    )]
+    pub tns_price_sources: Box<Account<'info, TnsPriceSources>>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateTnsPriceSources>,
+    pools: Option<Vec<TnsPoolSource>>,
+    min_sources: Option<u8>,
+) -> Result<()> {
+    let price_sources = &mut ctx.accounts.tns_price_sources;
+
+    if let Some(pools) = pools {
+        price_sources.pools = pools;
+    }
+
+    if let Some(min_sources) = min_sources {
+        price_sources.min_sources = min_sources;
+    }
+
+    // Re-validate together so a shorter pool list can't leave a stale minimum behind
+    validate_tns_price_sources(&price_sources.pools, price_sources.min_sources)?;
+
+    emit!(TnsPriceSourcesUpdated {
+        price_sources: price_sources.key(),
+        pools: price_sources.pools.clone(),
+        min_sources: price_sources.min_sources,
+    });
+
+    Ok(())
+}
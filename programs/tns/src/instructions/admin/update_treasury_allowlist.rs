@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::{Config, Treasury, TreasuryUpdated, TnsError};
+use crate::instructions::registrar::helpers::validate_treasury_allowlist;
+
+/// Admin-only instruction to replace the treasury withdrawal allowlist
+#[derive(Accounts)]
+pub struct UpdateTreasuryAllowlist<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+pub fn handler(ctx: Context<UpdateTreasuryAllowlist>, allowlist: Vec<Pubkey>) -> Result<()> {
+    validate_treasury_allowlist(&allowlist)?;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.allowlist = allowlist;
+
+    emit!(TreasuryUpdated {
+        treasury: treasury.key(),
+        allowlist: treasury.allowlist.clone(),
+        updated_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
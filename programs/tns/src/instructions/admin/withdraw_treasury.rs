@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
+use crate::{Config, Treasury, TreasuryWithdrawn, TnsError};
+
+/// Admin-only instruction to move tokens out of a treasury vault
+/// The destination must be a token account of the same mint owned by an
+/// allowlisted wallet.
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+        has_one = admin @ TnsError::UnauthorizedAdmin,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Treasury vault for the mint (the treasury PDA's associated token account)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = treasury.allowlist.contains(&destination.owner) @ TnsError::DestinationNotAllowed,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    require!(
+        amount > 0 && amount <= ctx.accounts.treasury_vault.amount,
+        TnsError::InsufficientTreasuryBalance
+    );
+
+    let signer_seeds: &[&[&[u8]]] = &[&[Treasury::SEED_PREFIX, &[ctx.accounts.treasury.bump]]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.treasury_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    ctx.accounts.treasury.withdrawal_count = ctx.accounts.treasury.withdrawal_count.saturating_add(1);
+
+    emit!(TreasuryWithdrawn {
+        treasury: ctx.accounts.treasury.key(),
+        mint: ctx.accounts.mint.key(),
+        destination: ctx.accounts.destination.key(),
+        destination_owner: ctx.accounts.destination.owner,
+        amount,
+        remaining_balance: ctx.accounts.treasury_vault.amount - amount,
+        withdrawn_by: ctx.accounts.admin.key(),
+        withdrawn_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
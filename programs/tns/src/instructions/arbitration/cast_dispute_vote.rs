@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::{Dispute, DisputeConfig, DisputeVoteCast, TnsError};
+
+/// Arbiter votes for the challenger or the owner.
+/// Voting opens when the owner responds or the response window closes,
+/// and ends at the voting deadline. Each arbiter votes once.
+#[derive(Accounts)]
+pub struct CastDisputeVote<'info> {
+    pub arbiter: Signer<'info>,
+
+    #[account(
+        seeds = [DisputeConfig::SEED_PREFIX],
+        bump = dispute_config.bump,
+        constraint = dispute_config.is_arbiter(&arbiter.key()) @ TnsError::NotArbiter,
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+
+    #[account(
+        mut,
+        seeds = [Dispute::SEED_PREFIX, dispute.token_account.as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+}
+
+pub fn handler(ctx: Context<CastDisputeVote>, for_challenger: bool) -> Result<()> {
+    let clock = Clock::get()?;
+    let arbiter = ctx.accounts.arbiter.key();
+    let dispute = &mut ctx.accounts.dispute;
+
+    require!(dispute.is_voting_open(clock.unix_timestamp), TnsError::VotingNotOpen);
+
+    require!(!dispute.voters.contains(&arbiter), TnsError::AlreadyVoted);
+
+    dispute.voters.push(arbiter);
+
+    if for_challenger {
+        dispute.votes_for_challenger += 1;
+    } else {
+        dispute.votes_for_owner += 1;
+    }
+
+    emit!(DisputeVoteCast {
+        dispute: dispute.key(),
+        token_account: dispute.token_account,
+        arbiter,
+        for_challenger,
+        votes_for_challenger: dispute.votes_for_challenger,
+        votes_for_owner: dispute.votes_for_owner,
+        voted_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+pub mod open_dispute;
+pub mod respond_to_dispute;
+pub mod cast_dispute_vote;
+pub mod resolve_dispute;
+
+pub use open_dispute::OpenDispute;
+pub use respond_to_dispute::RespondToDispute;
+pub use cast_dispute_vote::CastDisputeVote;
+pub use resolve_dispute::ResolveDispute;
+
+pub(crate) use open_dispute::__client_accounts_open_dispute;
+pub(crate) use respond_to_dispute::__client_accounts_respond_to_dispute;
+pub(crate) use cast_dispute_vote::__client_accounts_cast_dispute_vote;
+pub(crate) use resolve_dispute::__client_accounts_resolve_dispute;
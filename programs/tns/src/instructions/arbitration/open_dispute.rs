@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::{Config, Token, Dispute, DisputeConfig, DisputeOpened, TnsError, MAX_EVIDENCE_URI_LENGTH};
+use crate::instructions::registrar::helpers::{
+    validate_not_paused, validate_symbol_not_expired, validate_no_open_dispute,
+};
+
+/// Open a dispute against a registered symbol by posting a bond.
+///
+/// The challenger claims the current owner is impersonating their brand.
+/// The bond is held in the Dispute PDA and ownership of the symbol is frozen
+/// until the dispute is resolved. If the challenger prevails, the symbol
+/// is transferred to them; if the owner prevails, the challenger's bond is slashed.
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    /// The challenger - pays for the Dispute account and posts the bond
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [DisputeConfig::SEED_PREFIX],
+        bump = dispute_config.bump,
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+
+    #[account(
+        mut,
+        seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
+        bump = token_account.bump,
+    )]
+    pub token_account: Account<'info, Token>,
+
+    #[account(
+        init,
+        payer = challenger,
+        space = 8 + Dispute::INIT_SPACE,
+        seeds = [Dispute::SEED_PREFIX, token_account.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenDispute>, bond_lamports: u64, evidence_uri: String) -> Result<()> {
+    let clock = Clock::get()?;
+    let challenger = ctx.accounts.challenger.key();
+    let dispute_config = &ctx.accounts.dispute_config;
+    let token = &ctx.accounts.token_account;
+
+    // Validate
+    validate_not_paused(&ctx.accounts.config)?;
+
+    validate_symbol_not_expired(token, clock.unix_timestamp)?;
+
+    validate_no_open_dispute(token)?;
+
+    require!(token.owner != challenger, TnsError::AlreadyOwner);
+
+    require!(
+        bond_lamports >= dispute_config.min_bond_lamports,
+        TnsError::DisputeBondTooLow
+    );
+
+    require!(
+        evidence_uri.len() <= MAX_EVIDENCE_URI_LENGTH,
+        TnsError::EvidenceUriTooLong
+    );
+
+    // Post the challenger bond into the Dispute PDA
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.challenger.to_account_info(),
+                to: ctx.accounts.dispute.to_account_info(),
+            },
+        ),
+        bond_lamports,
+    )?;
+
+    let response_deadline = clock.unix_timestamp + dispute_config.response_window_seconds;
+    let voting_deadline = response_deadline + dispute_config.voting_window_seconds;
+
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.token_account = token.key();
+    dispute.challenger = challenger;
+    dispute.owner = token.owner;
+    dispute.challenger_bond = bond_lamports;
+    dispute.owner_bond = 0;
+    dispute.evidence_uri = evidence_uri;
+    dispute.opened_at = clock.unix_timestamp;
+    dispute.response_deadline = response_deadline;
+    dispute.voting_deadline = voting_deadline;
+    dispute.quorum = dispute_config.quorum;
+    dispute.votes_for_challenger = 0;
+    dispute.votes_for_owner = 0;
+    dispute.voters = Vec::new();
+    dispute.bump = ctx.bumps.dispute;
+
+    // Freeze ownership changes until resolution
+    ctx.accounts.token_account.dispute_open = true;
+
+    emit!(DisputeOpened {
+        dispute: ctx.accounts.dispute.key(),
+        token_account: ctx.accounts.token_account.key(),
+        symbol: ctx.accounts.token_account.symbol.clone(),
+        challenger,
+        owner: ctx.accounts.dispute.owner,
+        challenger_bond: bond_lamports,
+        evidence_uri: ctx.accounts.dispute.evidence_uri.clone(),
+        response_deadline,
+        voting_deadline,
+        opened_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
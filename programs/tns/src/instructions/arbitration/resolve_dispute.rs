@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use crate::{BondVault, Config, KeeperVault, Token, Dispute, DisputeOutcome, DisputeResolved, TnsError};
+use crate::instructions::registrar::helpers::refund_owner_deposits;
+
+/// Resolve a dispute once a side reaches quorum or the voting window ends.
+/// Anyone can call this.
+///
+/// Outcomes:
+/// - Challenger prevails: symbol is transferred to the challenger, owner's bond is slashed
+///   and the symbol's keeper deposit and bond are refunded to the owner
+/// - Owner prevails: challenger's bond is slashed, owner's bond is refunded
+/// - No quorum by the deadline: dismissed, both bonds are refunded
+///
+/// Slashed bonds go to the fee collector. The Dispute account is closed to
+/// the challenger (who paid its rent) and the symbol is unfrozen.
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    pub resolver: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
+        bump = token_account.bump,
+    )]
+    pub token_account: Account<'info, Token>,
+
+    #[account(
+        mut,
+        seeds = [Dispute::SEED_PREFIX, token_account.key().as_ref()],
+        bump = dispute.bump,
+        has_one = challenger,
+        has_one = owner,
+        close = challenger,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// CHECK: Challenger receives the Dispute rent and their bond unless slashed
+    #[account(mut)]
+    pub challenger: AccountInfo<'info>,
+
+    /// CHECK: Owner at the time the dispute was opened, receives their bond unless slashed
+    /// (and the symbol's keeper deposit and bond if the challenger prevails)
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+
+    /// CHECK: Fee collector receives slashed bonds
+    #[account(
+        mut,
+        address = config.fee_collector,
+    )]
+    pub fee_collector: AccountInfo<'info>,
+
+    /// Keeper vault refunds the symbol's keeper deposit to the owner
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Account<'info, KeeperVault>,
+
+    /// Bond vault refunds the symbol's bond to the owner (required when the symbol has a bond)
+    #[account(
+        mut,
+        seeds = [BondVault::SEED_PREFIX],
+        bump = bond_vault.bump,
+    )]
+    pub bond_vault: Option<Account<'info, BondVault>>,
+}
+
+pub fn handler(ctx: Context<ResolveDispute>) -> Result<()> {
+    let clock = Clock::get()?;
+    let dispute = &ctx.accounts.dispute;
+
+    require!(dispute.is_resolvable(clock.unix_timestamp), TnsError::DisputeNotResolvable);
+
+    let (outcome, bond_slashed, owner_refund) = if dispute.challenger_prevails() {
+        (DisputeOutcome::Challenger, dispute.owner_bond, 0)
+    } else if dispute.owner_prevails() {
+        (DisputeOutcome::Owner, dispute.challenger_bond, dispute.owner_bond)
+    } else {
+        (DisputeOutcome::Dismissed, 0, dispute.owner_bond)
+    };
+
+    // Move bonds out of the Dispute PDA; the remainder (rent + any unslashed
+    // challenger bond) goes to the challenger via Anchor's close constraint
+    let dispute_info = ctx.accounts.dispute.to_account_info();
+
+    if bond_slashed > 0 {
+        **dispute_info.try_borrow_mut_lamports()? -= bond_slashed;
+        **ctx.accounts.fee_collector.try_borrow_mut_lamports()? += bond_slashed;
+    }
+
+    if owner_refund > 0 {
+        **dispute_info.try_borrow_mut_lamports()? -= owner_refund;
+        **ctx.accounts.owner.try_borrow_mut_lamports()? += owner_refund;
+    }
+
+    let token = &mut ctx.accounts.token_account;
+
+    let (deposit_refunded, symbol_bond_refunded) = if outcome == DisputeOutcome::Challenger {
+        token.owner = ctx.accounts.dispute.challenger;
+        token.clear_claim();
+
+        refund_owner_deposits(
+            token,
+            &mut ctx.accounts.keeper_vault,
+            ctx.accounts.bond_vault.as_mut(),
+            &ctx.accounts.owner,
+        )?
+    } else {
+        (0, 0)
+    };
+
+    token.dispute_open = false;
+
+    emit!(DisputeResolved {
+        dispute: ctx.accounts.dispute.key(),
+        token_account: token.key(),
+        symbol: token.symbol.clone(),
+        challenger: ctx.accounts.dispute.challenger,
+        owner: ctx.accounts.dispute.owner,
+        outcome,
+        new_owner: token.owner,
+        votes_for_challenger: ctx.accounts.dispute.votes_for_challenger,
+        votes_for_owner: ctx.accounts.dispute.votes_for_owner,
+        bond_slashed,
+        deposit_refunded,
+        symbol_bond_refunded,
+        resolved_by: ctx.accounts.resolver.key(),
+        resolved_at: clock.unix_timestamp,
+    });
+
+    // Account closure is handled by Anchor's close = challenger constraint
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::{Token, Dispute, DisputeResponded, TnsError};
+
+/// Owner posts a counter-bond matching the challenger's bond.
+/// Must happen within the response window. Opens voting immediately.
+#[derive(Accounts)]
+pub struct RespondToDispute<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
+        bump = token_account.bump,
+        has_one = owner @ TnsError::UnauthorizedOwner,
+    )]
+    pub token_account: Account<'info, Token>,
+
+    #[account(
+        mut,
+        seeds = [Dispute::SEED_PREFIX, token_account.key().as_ref()],
+        bump = dispute.bump,
+        has_one = owner @ TnsError::UnauthorizedOwner,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RespondToDispute>) -> Result<()> {
+    let clock = Clock::get()?;
+    let dispute = &ctx.accounts.dispute;
+
+    require!(!dispute.has_response(), TnsError::AlreadyResponded);
+
+    require!(
+        clock.unix_timestamp <= dispute.response_deadline,
+        TnsError::ResponseWindowClosed
+    );
+
+    let owner_bond = dispute.challenger_bond;
+
+    // Post the counter-bond into the Dispute PDA
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.dispute.to_account_info(),
+            },
+        ),
+        owner_bond,
+    )?;
+
+    ctx.accounts.dispute.owner_bond = owner_bond;
+
+    emit!(DisputeResponded {
+        dispute: ctx.accounts.dispute.key(),
+        token_account: ctx.accounts.token_account.key(),
+        symbol: ctx.accounts.token_account.symbol.clone(),
+        owner: ctx.accounts.owner.key(),
+        owner_bond,
+        responded_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,29 +1,46 @@
 pub mod admin;
 pub mod registrar;
+pub mod arbitration;
 
 pub use admin::*;
 pub use registrar::*;
+pub use arbitration::*;
 
 pub(crate) use admin::__client_accounts_initialize;
 pub(crate) use admin::__client_accounts_seed_symbol;
 pub(crate) use admin::__client_accounts_update_config;
 pub(crate) use registrar::__client_accounts_register_symbol_sol;
-pub(crate) use registrar::__client_accounts_register_symbol_tns;
-pub(crate) use registrar::__client_accounts_register_symbol_usdc;
-pub(crate) use registrar::__client_accounts_register_symbol_usdt;
+pub(crate) use registrar::__client_accounts_register_symbol_asset;
 pub(crate) use registrar::__client_accounts_renew_symbol_sol;
-pub(crate) use registrar::__client_accounts_renew_symbol_tns;
-pub(crate) use registrar::__client_accounts_renew_symbol_usdc;
-pub(crate) use registrar::__client_accounts_renew_symbol_usdt;
+pub(crate) use registrar::__client_accounts_renew_symbol_asset;
 pub(crate) use registrar::__client_accounts_update_mint_sol;
-pub(crate) use registrar::__client_accounts_update_mint_tns;
-pub(crate) use registrar::__client_accounts_update_mint_usdc;
-pub(crate) use registrar::__client_accounts_update_mint_usdt;
+pub(crate) use registrar::__client_accounts_update_mint_asset;
+pub(crate) use registrar::__client_accounts_recommit_metadata_sol;
+pub(crate) use registrar::__client_accounts_recommit_metadata_asset;
 pub(crate) use registrar::__client_accounts_claim_expired_symbol_sol;
-pub(crate) use registrar::__client_accounts_claim_expired_symbol_tns;
-pub(crate) use registrar::__client_accounts_claim_expired_symbol_usdc;
-pub(crate) use registrar::__client_accounts_claim_expired_symbol_usdt;
+pub(crate) use registrar::__client_accounts_claim_expired_symbol_asset;
 pub(crate) use registrar::__client_accounts_cancel_symbol;
+pub(crate) use registrar::__client_accounts_release_symbol;
 pub(crate) use registrar::__client_accounts_transfer_ownership;
 pub(crate) use registrar::__client_accounts_claim_ownership;
 pub(crate) use registrar::__client_accounts_verify_or_close;
+pub(crate) use registrar::__client_accounts_update_tns_price_observation;
+pub(crate) use registrar::__client_accounts_update_platform_payout;
+pub(crate) use registrar::__client_accounts_buyback_and_burn;
+pub(crate) use registrar::__client_accounts_record_sol_price;
+pub(crate) use registrar::__client_accounts_migrate_token_account;
+pub(crate) use registrar::__client_accounts_start_majority_claim;
+pub(crate) use registrar::__client_accounts_complete_majority_claim;
+pub(crate) use registrar::__client_accounts_cancel_majority_claim;
+pub(crate) use registrar::__client_accounts_batch_renew_symbols_sol;
+pub(crate) use registrar::__client_accounts_batch_cancel_symbols;
+pub(crate) use registrar::__client_accounts_enable_auto_renew_sol;
+pub(crate) use registrar::__client_accounts_enable_auto_renew_stablecoin;
+pub(crate) use registrar::__client_accounts_crank_auto_renew_sol;
+pub(crate) use registrar::__client_accounts_crank_auto_renew_stablecoin;
+pub(crate) use registrar::__client_accounts_withdraw_auto_renew_sol;
+pub(crate) use registrar::__client_accounts_withdraw_auto_renew_stablecoin;
+pub(crate) use arbitration::__client_accounts_open_dispute;
+pub(crate) use arbitration::__client_accounts_respond_to_dispute;
+pub(crate) use arbitration::__client_accounts_cast_dispute_vote;
+pub(crate) use arbitration::__client_accounts_resolve_dispute;
@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use crate::{
+    Config, OracleConfig, Token, AutoRenew, AutoRenewCranked, SymbolRenewed, TnsError, KeeperVault, RevenueVault,
+    AUTO_RENEW_TIP_USD_MICRO, WSOL_MINT,
+};
+use super::super::helpers::{
+    validate_not_paused, validate_auto_renewal, get_sol_price, update_symbol_on_renewal,
+    transfer_sol_fees_with_platform, SolFeeAccounts,
+};
+
+/// Renew a symbol from its SOL auto-renew escrow (permissionless crank)
+/// Allowed within AUTO_RENEW_WINDOW_SECONDS of expiration. The renewal fee
+/// uses the normal renewal pricing and is divided by the revenue split like a
+/// manual renewal; the cranker receives a small tip. Both are paid from the escrow.
+#[derive(Accounts)]
+pub struct CrankAutoRenewSol<'info> {
+    /// Anyone can crank - receives the tip
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
+        bump = token_account.bump,
+    )]
+    pub token_account: Account<'info, Token>,
+
+    #[account(
+        mut,
+        seeds = [AutoRenew::SEED_PREFIX, token_account.key().as_ref(), token_account.owner.as_ref()],
+        bump = auto_renew.bump,
+        constraint = auto_renew.is_sol() @ TnsError::InvalidMint,
+    )]
+    pub auto_renew: Account<'info, AutoRenew>,
+
+    /// CHECK: Fee collector receives renewal fee
+    #[account(
+        mut,
+        address = config.fee_collector,
+    )]
+    pub fee_collector: AccountInfo<'info>,
+
+    /// CHECK: Pyth pull oracle price update account (owner verified against OracleConfig); a stale update falls back to a live fallback price
+    pub price_update: AccountInfo<'info>,
+
+    #[account(
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    /// Keeper vault - required when the revenue split has a keeper share
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Option<Box<Account<'info, KeeperVault>>>,
+
+    /// WSOL revenue vault - required when the revenue split has burn or staker shares
+    #[account(
+        mut,
+        seeds = [RevenueVault::SEED_PREFIX, WSOL_MINT.as_ref()],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Option<Box<Account<'info, RevenueVault>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CrankAutoRenewSol>) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.config;
+
+    // Validate
+    validate_not_paused(config)?;
+
+    let old_expires_at = ctx.accounts.token_account.expires_at;
+    let new_expires_at = validate_auto_renewal(
+        &ctx.accounts.auto_renew,
+        &ctx.accounts.token_account,
+        clock.unix_timestamp,
+    )?;
+
+    // Renewal fee and tip from a single oracle read
+    let years = ctx.accounts.auto_renew.years_per_renewal;
+    let sol_price = get_sol_price(&ctx.accounts.oracle_config, &ctx.accounts.price_update, clock.unix_timestamp)?;
+    let fee_lamports = config.calculate_renewal_price_lamports(
+        &ctx.accounts.token_account,
+        clock.unix_timestamp,
+        years,
+        sol_price.price_micro,
+    );
+    let tip_lamports = config.usd_to_lamports(AUTO_RENEW_TIP_USD_MICRO, sol_price.price_micro);
+
+    require!(
+        fee_lamports <= ctx.accounts.auto_renew.max_fee_per_renewal,
+        TnsError::AutoRenewPriceCapExceeded
+    );
+
+    // Pay fee and tip from escrow lamports above rent
+    let auto_renew_info = ctx.accounts.auto_renew.to_account_info();
+    let min_rent = Rent::get()?.minimum_balance(auto_renew_info.data_len());
+    let available = auto_renew_info.lamports().saturating_sub(min_rent);
+    let total = fee_lamports.checked_add(tip_lamports).ok_or(TnsError::MathOverflow)?;
+    require!(available >= total, TnsError::AutoRenewInsufficientEscrow);
+
+    transfer_sol_fees_with_platform(
+        SolFeeAccounts {
+            payer: &auto_renew_info,
+            fee_collector: &ctx.accounts.fee_collector,
+            keeper_vault: ctx.accounts.keeper_vault.as_deref(),
+            revenue_vault: ctx.accounts.revenue_vault.as_deref_mut(),
+            platform: None,
+            platform_fee_account: None,
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        &config.get_revenue_split(),
+        fee_lamports,
+        0,
+    )?;
+
+    **auto_renew_info.try_borrow_mut_lamports()? -= tip_lamports;
+    **ctx.accounts.cranker.to_account_info().try_borrow_mut_lamports()? += tip_lamports;
+
+    // Update symbol
+    update_symbol_on_renewal(&mut ctx.accounts.token_account, new_expires_at);
+
+    let auto_renew = &mut ctx.accounts.auto_renew;
+    auto_renew.years_remaining -= years as u16;
+    auto_renew.last_renewed_at = clock.unix_timestamp;
+
+    emit!(SymbolRenewed {
+        token_account: ctx.accounts.token_account.key(),
+        symbol: ctx.accounts.token_account.symbol.clone(),
+        renewed_by: ctx.accounts.cranker.key(),
+        owner: ctx.accounts.token_account.owner,
+        years,
+        fee_paid: fee_lamports,
+        platform_fee: 0,
+        sol_price_provider: Some(sol_price.provider),
+        old_expires_at,
+        new_expires_at,
+        renewed_at: clock.unix_timestamp,
+    });
+
+    emit!(AutoRenewCranked {
+        auto_renew: auto_renew.key(),
+        token_account: ctx.accounts.token_account.key(),
+        symbol: ctx.accounts.token_account.symbol.clone(),
+        cranker: ctx.accounts.cranker.key(),
+        years,
+        fee_paid: fee_lamports,
+        sol_price_provider: Some(sol_price.provider),
+        tip_paid: tip_lamports,
+        years_remaining: auto_renew.years_remaining,
+        cranked_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
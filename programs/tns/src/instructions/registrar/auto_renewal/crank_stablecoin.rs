@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
+use crate::{
+    Config, OracleConfig, PaymentAsset, Token, AutoRenew, AutoRenewCranked, SymbolRenewed, TnsError,
+    AUTO_RENEW_TIP_USD_MICRO, Treasury, RevenueVault,
+};
+use super::super::helpers::{
+    validate_not_paused, validate_auto_renewal, update_symbol_on_renewal, calculate_asset_amount,
+    get_asset_price_micro, AssetPriceAccounts, transfer_token_fees_with_platform, PlatformTokenFeeAccounts,
+    calculate_token_fee_charge,
+};
+
+/// Renew a symbol from its token auto-renew escrow (permissionless crank)
+/// Allowed within AUTO_RENEW_WINDOW_SECONDS of expiration. The renewal fee
+/// uses the normal renewal pricing, converted at the payment asset's price, and is
+/// divided by the revenue split like a manual renewal; the cranker receives a small
+/// tip. Both are paid from the escrow.
+/// Rejected while the stablecoin is depegged.
+#[derive(Accounts)]
+pub struct CrankAutoRenewStablecoin<'info> {
+    /// Anyone can crank - receives the tip
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
+        bump = token_account.bump,
+    )]
+    pub token_account: Box<Account<'info, Token>>,
+
+    #[account(
+        mut,
+        seeds = [AutoRenew::SEED_PREFIX, token_account.key().as_ref(), token_account.owner.as_ref()],
+        bump = auto_renew.bump,
+    )]
+    pub auto_renew: Box<Account<'info, AutoRenew>>,
+
+    #[account(address = auto_renew.payment_mint @ TnsError::InvalidMint)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [PaymentAsset::SEED_PREFIX, payment_mint.key().as_ref()],
+        bump = payment_asset.bump,
+        constraint = payment_asset.enabled @ TnsError::PaymentAssetDisabled,
+    )]
+    pub payment_asset: Box<Account<'info, PaymentAsset>>,
+
+    #[account(
+        mut,
+        seeds = [AutoRenew::ESCROW_SEED_PREFIX, auto_renew.key().as_ref()],
+        bump = auto_renew.escrow_bump,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    /// Treasury PDA owning the fee vaults
+    #[account(
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump,
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    /// Treasury vault for the payment mint (the treasury PDA's associated token account)
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Cranker's token account for the payment mint (receives the tip)
+    #[account(
+        mut,
+        token::mint = payment_mint,
+    )]
+    pub cranker_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Pyth feed for the payment asset (owner verified against OracleConfig)
+    pub price_update: AccountInfo<'info>,
+
+    #[account(
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    /// Revenue vault for the payment mint - required when the revenue split has non-treasury shares
+    #[account(
+        mut,
+        seeds = [RevenueVault::SEED_PREFIX, payment_mint.key().as_ref()],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Option<Box<Account<'info, RevenueVault>>>,
+
+    /// Revenue vault token account - must be owned by revenue_vault
+    #[account(mut)]
+    pub revenue_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<CrankAutoRenewStablecoin>) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.config;
+
+    // Validate
+    validate_not_paused(config)?;
+
+    let old_expires_at = ctx.accounts.token_account.expires_at;
+    let new_expires_at = validate_auto_renewal(
+        &ctx.accounts.auto_renew,
+        &ctx.accounts.token_account,
+        clock.unix_timestamp,
+    )?;
+
+    // Calculate fee and tip in USD, then convert at the asset's price (rejected if depegged)
+    let years = ctx.accounts.auto_renew.years_per_renewal;
+    let payment_asset = &ctx.accounts.payment_asset;
+    let price_accounts = AssetPriceAccounts {
+        config,
+        oracle_config: &ctx.accounts.oracle_config,
+        price_update: Some(&ctx.accounts.price_update),
+        tns_price_observation: None,
+        tns_price_sources: None,
+        tns_usd_price_update: None,
+        pool_accounts: &[],
+        current_time: clock.unix_timestamp,
+    };
+    let fee_usd_micro = config.calculate_renewal_price_usd(&ctx.accounts.token_account, clock.unix_timestamp, years);
+    let fee_amount = calculate_asset_amount(payment_asset, fee_usd_micro, &price_accounts)?;
+    let tip_amount = payment_asset
+        .usd_to_amount(AUTO_RENEW_TIP_USD_MICRO, get_asset_price_micro(payment_asset, &price_accounts)?)
+        .ok_or(TnsError::MathOverflow)?;
+
+    // Cap and escrow checks use the amount debited once grossed up for Token-2022 transfer fees
+    let revenue_split = config.get_revenue_split();
+    let charged_amount = calculate_token_fee_charge(&ctx.accounts.payment_mint, &revenue_split, fee_amount, 0)?;
+    require!(
+        charged_amount <= ctx.accounts.auto_renew.max_fee_per_renewal,
+        TnsError::AutoRenewPriceCapExceeded
+    );
+
+    let total = charged_amount.checked_add(tip_amount).ok_or(TnsError::MathOverflow)?;
+    require!(ctx.accounts.escrow.amount >= total, TnsError::AutoRenewInsufficientEscrow);
+
+    let token_account_key = ctx.accounts.token_account.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AutoRenew::SEED_PREFIX,
+        token_account_key.as_ref(),
+        ctx.accounts.auto_renew.owner.as_ref(),
+        &[ctx.accounts.auto_renew.bump],
+    ]];
+
+    let auto_renew_info = ctx.accounts.auto_renew.to_account_info();
+    transfer_token_fees_with_platform(
+        &mut PlatformTokenFeeAccounts {
+            payer: &auto_renew_info,
+            payer_seeds: signer_seeds,
+            payer_token_account: &ctx.accounts.escrow,
+            vault: &ctx.accounts.treasury_vault,
+            platform: None,
+            platform_token_account: None,
+            revenue_vault: ctx.accounts.revenue_vault.as_deref_mut(),
+            revenue_token_account: ctx.accounts.revenue_token_account.as_deref(),
+            revenue_split,
+            mint: &ctx.accounts.payment_mint,
+            token_program: &ctx.accounts.token_program,
+        },
+        fee_amount,
+        0,
+    )?;
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.escrow.to_account_info(),
+                mint: ctx.accounts.payment_mint.to_account_info(),
+                to: ctx.accounts.cranker_token_account.to_account_info(),
+                authority: auto_renew_info,
+            },
+            signer_seeds,
+        ),
+        tip_amount,
+        ctx.accounts.payment_mint.decimals,
+    )?;
+
+    // Update symbol
+    update_symbol_on_renewal(&mut ctx.accounts.token_account, new_expires_at);
+
+    let auto_renew = &mut ctx.accounts.auto_renew;
+    auto_renew.years_remaining -= years as u16;
+    auto_renew.last_renewed_at = clock.unix_timestamp;
+
+    emit!(SymbolRenewed {
+        token_account: token_account_key,
+        symbol: ctx.accounts.token_account.symbol.clone(),
+        renewed_by: ctx.accounts.cranker.key(),
+        owner: ctx.accounts.token_account.owner,
+        years,
+        fee_paid: fee_amount,
+        platform_fee: 0,
+        sol_price_provider: None,
+        old_expires_at,
+        new_expires_at,
+        renewed_at: clock.unix_timestamp,
+    });
+
+    emit!(AutoRenewCranked {
+        auto_renew: auto_renew.key(),
+        token_account: token_account_key,
+        symbol: ctx.accounts.token_account.symbol.clone(),
+        cranker: ctx.accounts.cranker.key(),
+        years,
+        fee_paid: fee_amount,
+        sol_price_provider: None,
+        tip_paid: tip_amount,
+        years_remaining: auto_renew.years_remaining,
+        cranked_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::{Config, Token, AutoRenew, AutoRenewEnabled, TnsError};
+use super::super::helpers::{validate_not_paused, validate_auto_renew_settings};
+
+/// Opt a symbol into auto-renewal funded with SOL (owner only)
+/// The deposit is held as lamports on the AutoRenew PDA; anyone can top it up
+/// with a plain SOL transfer, and the owner can withdraw it at any time.
+#[derive(Accounts)]
+pub struct EnableAutoRenewSol<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
+        bump = token_account.bump,
+        has_one = owner @ TnsError::UnauthorizedOwner,
+    )]
+    pub token_account: Account<'info, Token>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + AutoRenew::INIT_SPACE,
+        seeds = [AutoRenew::SEED_PREFIX, token_account.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub auto_renew: Account<'info, AutoRenew>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<EnableAutoRenewSol>,
+    years_per_renewal: u8,
+    max_years: u16,
+    max_fee_per_renewal: u64,
+    deposit_lamports: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Validate
+    validate_not_paused(&ctx.accounts.config)?;
+
+    validate_auto_renew_settings(years_per_renewal, max_years, max_fee_per_renewal)?;
+
+    // Fund the escrow
+    if deposit_lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.auto_renew.to_account_info(),
+                },
+            ),
+            deposit_lamports,
+        )?;
+    }
+
+    let auto_renew = &mut ctx.accounts.auto_renew;
+    auto_renew.token_account = ctx.accounts.token_account.key();
+    auto_renew.owner = ctx.accounts.owner.key();
+    auto_renew.payment_mint = Pubkey::default();
+    auto_renew.years_per_renewal = years_per_renewal;
+    auto_renew.years_remaining = max_years;
+    auto_renew.max_fee_per_renewal = max_fee_per_renewal;
+    auto_renew.last_renewed_at = 0;
+    auto_renew.bump = ctx.bumps.auto_renew;
+    auto_renew.escrow_bump = 0;
+
+    emit!(AutoRenewEnabled {
+        auto_renew: auto_renew.key(),
+        token_account: auto_renew.token_account,
+        symbol: ctx.accounts.token_account.symbol.clone(),
+        owner: auto_renew.owner,
+        payment_mint: auto_renew.payment_mint,
+        years_per_renewal,
+        max_years,
+        max_fee_per_renewal,
+        deposit: deposit_lamports,
+        enabled_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
+use crate::{Config, Token, AutoRenew, AutoRenewEnabled, PaymentAsset, PriceSource, TnsError};
+use super::super::helpers::{validate_not_paused, validate_auto_renew_settings};
+
+/// Opt a symbol into auto-renewal funded with a Stablecoin-priced PaymentAsset (owner only)
+/// The deposit is held in an escrow token account owned by the AutoRenew PDA;
+/// anyone can top it up with a plain token transfer, and the owner can withdraw it at any time.
+#[derive(Accounts)]
+pub struct EnableAutoRenewStablecoin<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
+        bump = token_account.bump,
+        has_one = owner @ TnsError::UnauthorizedOwner,
+    )]
+    pub token_account: Box<Account<'info, Token>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + AutoRenew::INIT_SPACE,
+        seeds = [AutoRenew::SEED_PREFIX, token_account.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub auto_renew: Box<Account<'info, AutoRenew>>,
+
+    /// Registry entry for the payment mint - must be priced from a stablecoin feed
+    #[account(
+        seeds = [PaymentAsset::SEED_PREFIX, payment_mint.key().as_ref()],
+        bump = payment_asset.bump,
+        constraint = payment_asset.enabled @ TnsError::PaymentAssetDisabled,
+        constraint = matches!(payment_asset.price_source, PriceSource::Stablecoin { .. })
+            @ TnsError::InvalidMint,
+    )]
+    pub payment_asset: Box<Account<'info, PaymentAsset>>,
+
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Escrow holding the prefunded stablecoin, owned by the auto-renew PDA
+    #[account(
+        init,
+        payer = owner,
+        seeds = [AutoRenew::ESCROW_SEED_PREFIX, auto_renew.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = auto_renew,
+        token::token_program = token_program,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<EnableAutoRenewStablecoin>,
+    years_per_renewal: u8,
+    max_years: u16,
+    max_fee_per_renewal: u64,
+    deposit_amount: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Validate
+    validate_not_paused(&ctx.accounts.config)?;
+
+    validate_auto_renew_settings(years_per_renewal, max_years, max_fee_per_renewal)?;
+
+    // Fund the escrow
+    if deposit_amount > 0 {
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.owner_token_account.to_account_info(),
+                    mint: ctx.accounts.payment_mint.to_account_info(),
+                    to: ctx.accounts.escrow.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            deposit_amount,
+            ctx.accounts.payment_mint.decimals,
+        )?;
+    }
+
+    let auto_renew = &mut ctx.accounts.auto_renew;
+    auto_renew.token_account = ctx.accounts.token_account.key();
+    auto_renew.owner = ctx.accounts.owner.key();
+    auto_renew.payment_mint = ctx.accounts.payment_mint.key();
+    auto_renew.years_per_renewal = years_per_renewal;
+    auto_renew.years_remaining = max_years;
+    auto_renew.max_fee_per_renewal = max_fee_per_renewal;
+    auto_renew.last_renewed_at = 0;
+    auto_renew.bump = ctx.bumps.auto_renew;
+    auto_renew.escrow_bump = ctx.bumps.escrow;
+
+    emit!(AutoRenewEnabled {
+        auto_renew: auto_renew.key(),
+        token_account: auto_renew.token_account,
+        symbol: ctx.accounts.token_account.symbol.clone(),
+        owner: auto_renew.owner,
+        payment_mint: auto_renew.payment_mint,
+        years_per_renewal,
+        max_years,
+        max_fee_per_renewal,
+        deposit: deposit_amount,
+        enabled_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+pub mod enable_sol;
+pub mod enable_stablecoin;
+pub mod crank_sol;
+pub mod crank_stablecoin;
+pub mod withdraw_sol;
+pub mod withdraw_stablecoin;
+
+pub use enable_sol::EnableAutoRenewSol;
+pub use enable_stablecoin::EnableAutoRenewStablecoin;
+pub use crank_sol::CrankAutoRenewSol;
+pub use crank_stablecoin::CrankAutoRenewStablecoin;
+pub use withdraw_sol::WithdrawAutoRenewSol;
+pub use withdraw_stablecoin::WithdrawAutoRenewStablecoin;
+
+pub(crate) use enable_sol::__client_accounts_enable_auto_renew_sol;
+pub(crate) use enable_stablecoin::__client_accounts_enable_auto_renew_stablecoin;
+pub(crate) use crank_sol::__client_accounts_crank_auto_renew_sol;
+pub(crate) use crank_stablecoin::__client_accounts_crank_auto_renew_stablecoin;
+pub(crate) use withdraw_sol::__client_accounts_withdraw_auto_renew_sol;
+pub(crate) use withdraw_stablecoin::__client_accounts_withdraw_auto_renew_stablecoin;
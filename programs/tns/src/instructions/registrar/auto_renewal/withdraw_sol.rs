@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::{AutoRenew, AutoRenewWithdrawn, TnsError};
+
+/// Disable auto-renew and withdraw the remaining SOL escrow (owner only)
+/// Available at any time, even if the symbol has since been closed,
+/// so funds can never be stranded in escrow.
+#[derive(Accounts)]
+pub struct WithdrawAutoRenewSol<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [AutoRenew::SEED_PREFIX, auto_renew.token_account.as_ref(), owner.key().as_ref()],
+        bump = auto_renew.bump,
+        has_one = owner @ TnsError::UnauthorizedOwner,
+        constraint = auto_renew.is_sol() @ TnsError::InvalidMint,
+        close = owner,
+    )]
+    pub auto_renew: Account<'info, AutoRenew>,
+}
+
+pub fn handler(ctx: Context<WithdrawAutoRenewSol>) -> Result<()> {
+    let clock = Clock::get()?;
+    let auto_renew_info = ctx.accounts.auto_renew.to_account_info();
+
+    // Escrow above rent (rent is returned too on close)
+    let min_rent = Rent::get()?.minimum_balance(auto_renew_info.data_len());
+    let amount = auto_renew_info.lamports().saturating_sub(min_rent);
+
+    emit!(AutoRenewWithdrawn {
+        auto_renew: ctx.accounts.auto_renew.key(),
+        token_account: ctx.accounts.auto_renew.token_account,
+        owner: ctx.accounts.owner.key(),
+        amount,
+        withdrawn_at: clock.unix_timestamp,
+    });
+
+    // Auto-renew closure is handled by Anchor's close = owner constraint
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{AutoRenew, AutoRenewWithdrawn, TnsError};
+use super::super::helpers::{release_escrow, EscrowReleaseAccounts};
+
+/// Disable auto-renew and withdraw the remaining USDC/USDT escrow (owner only)
+/// Available at any time, even if the symbol has since been closed,
+/// so funds can never be stranded in escrow.
+#[derive(Accounts)]
+pub struct WithdrawAutoRenewStablecoin<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(address = auto_renew.payment_mint @ TnsError::InvalidMint)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [AutoRenew::SEED_PREFIX, auto_renew.token_account.as_ref(), owner.key().as_ref()],
+        bump = auto_renew.bump,
+        has_one = owner @ TnsError::UnauthorizedOwner,
+        close = owner,
+    )]
+    pub auto_renew: Account<'info, AutoRenew>,
+
+    #[account(
+        mut,
+        seeds = [AutoRenew::ESCROW_SEED_PREFIX, auto_renew.key().as_ref()],
+        bump = auto_renew.escrow_bump,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<WithdrawAutoRenewStablecoin>) -> Result<()> {
+    let clock = Clock::get()?;
+    let auto_renew = &ctx.accounts.auto_renew;
+    let token_account_key = auto_renew.token_account;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AutoRenew::SEED_PREFIX,
+        token_account_key.as_ref(),
+        auto_renew.owner.as_ref(),
+        &[auto_renew.bump],
+    ]];
+
+    let amount = release_escrow(
+        &EscrowReleaseAccounts {
+            escrow: &ctx.accounts.escrow,
+            escrow_authority: &ctx.accounts.auto_renew.to_account_info(),
+            destination_token_account: &ctx.accounts.owner_token_account,
+            rent_destination: &ctx.accounts.owner.to_account_info(),
+            mint: &ctx.accounts.payment_mint,
+            token_program: &ctx.accounts.token_program,
+        },
+        signer_seeds,
+    )?;
+
+    emit!(AutoRenewWithdrawn {
+        auto_renew: ctx.accounts.auto_renew.key(),
+        token_account: token_account_key,
+        owner: ctx.accounts.owner.key(),
+        amount,
+        withdrawn_at: clock.unix_timestamp,
+    });
+
+    // Auto-renew closure is handled by Anchor's close = owner constraint
+    Ok(())
+}
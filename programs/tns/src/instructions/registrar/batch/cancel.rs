@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::{BondVault, Config, KeeperVault, SymbolCanceled, BatchCanceled, TnsError};
+use super::super::helpers::{
+    validate_not_paused, validate_no_open_dispute, record_keeper_reward, transfer_from_keeper_vault,
+    record_bond_forfeit, transfer_from_bond_vault, load_batch_token_accounts,
+};
+
+/// Cancel several abandoned symbols (1+ year past grace period) in one transaction
+/// Token accounts to cancel are passed as writable remaining_accounts.
+/// The keeper receives every account's rent plus the sum of their keeper deposits,
+/// paid from the KeeperVault PDA in a single transfer, plus the keeper share of every
+/// forfeited bond, paid from the BondVault PDA in a single transfer.
+#[derive(Accounts)]
+pub struct BatchCancelSymbols<'info> {
+    /// Anyone can cancel abandoned symbols - receives rent + keeper deposits
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Keeper vault pays out the symbols' keeper deposits
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Account<'info, KeeperVault>,
+
+    /// Bond vault forfeits the symbols' bonds (required when any symbol has a bond)
+    #[account(
+        mut,
+        seeds = [BondVault::SEED_PREFIX],
+        bump = bond_vault.bump,
+    )]
+    pub bond_vault: Option<Account<'info, BondVault>>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, BatchCancelSymbols<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Validate not paused
+    validate_not_paused(&ctx.accounts.config)?;
+
+    let tokens = load_batch_token_accounts(ctx.remaining_accounts)?;
+    let keeper_info = ctx.accounts.keeper.to_account_info();
+
+    let mut total_rent_returned: u64 = 0;
+    let mut total_keeper_reward: u64 = 0;
+    let mut total_bond_keeper_reward: u64 = 0;
+
+    for token in tokens.iter() {
+        validate_no_open_dispute(token)?;
+
+        // Verify the symbol is cancelable (1 year past grace period)
+        require!(
+            token.is_cancelable(clock.unix_timestamp),
+            TnsError::NotYetCancelable
+        );
+
+        let rent_returned = token.to_account_info().lamports();
+        total_rent_returned = total_rent_returned
+            .checked_add(rent_returned)
+            .ok_or(TnsError::MathOverflow)?;
+
+        // Record the keeper deposit (or legacy reward); paid in one transfer below
+        let keeper_reward = record_keeper_reward(
+            &mut ctx.accounts.keeper_vault,
+            token.keeper_deposit,
+            ctx.accounts.config.get_keeper_reward_lamports(),
+        )?;
+        total_keeper_reward = total_keeper_reward
+            .checked_add(keeper_reward)
+            .ok_or(TnsError::MathOverflow)?;
+
+        // Forfeit the bond posted in bond pricing mode; the keeper share is paid below
+        record_bond_forfeit(ctx.accounts.bond_vault.as_mut(), token.bond_lamports)?;
+        let bond_keeper_reward = ctx.accounts.config.bond_policy.keeper_share(token.bond_lamports);
+        total_bond_keeper_reward = total_bond_keeper_reward
+            .checked_add(bond_keeper_reward)
+            .ok_or(TnsError::MathOverflow)?;
+
+        emit!(SymbolCanceled {
+            token_account: token.key(),
+            symbol: token.symbol.clone(),
+            previous_owner: token.owner,
+            previous_mint: token.mint,
+            canceled_by: ctx.accounts.keeper.key(),
+            canceled_at: clock.unix_timestamp,
+            rent_returned,
+            keeper_reward,
+            bond_forfeited: token.bond_lamports,
+            bond_keeper_reward,
+        });
+
+        token.close(keeper_info.clone())?;
+    }
+
+    // Pay every recorded keeper reward from the keeper vault in one transfer
+    transfer_from_keeper_vault(&ctx.accounts.keeper_vault, &keeper_info, total_keeper_reward)?;
+
+    // Pay the keeper share of every forfeited bond from the bond vault in one transfer
+    // (no bond vault means no symbol had a bond, so nothing is owed)
+    if let Some(bond_vault) = ctx.accounts.bond_vault.as_ref() {
+        transfer_from_bond_vault(bond_vault, &keeper_info, total_bond_keeper_reward)?;
+    }
+
+    emit!(BatchCanceled {
+        canceled_by: ctx.accounts.keeper.key(),
+        count: tokens.len() as u8,
+        total_rent_returned,
+        total_keeper_reward,
+        total_bond_keeper_reward,
+        canceled_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
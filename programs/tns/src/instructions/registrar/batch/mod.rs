@@ -0,0 +1,8 @@
+pub mod renew_sol;
+pub mod cancel;
+
+pub use renew_sol::BatchRenewSymbolsSol;
+pub use cancel::BatchCancelSymbols;
+
+pub(crate) use renew_sol::__client_accounts_batch_renew_symbols_sol;
+pub(crate) use cancel::__client_accounts_batch_cancel_symbols;
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use crate::{Config, OracleConfig, SymbolRenewed, BatchRenewed, TnsError, Platform, KeeperVault, RevenueVault, WSOL_MINT};
+use super::super::helpers::{
+    validate_not_paused, validate_symbol_not_expired,
+    validate_and_calculate_expiration, validate_slippage, validate_platform_fee, record_platform_volume,
+    get_sol_price, calculate_platform_split, transfer_sol_split, SolFeeAccounts, update_symbol_on_renewal,
+    load_batch_token_accounts,
+};
+
+/// Renew several symbols in one transaction paying with SOL
+/// Token accounts to renew are passed as writable remaining_accounts.
+/// Every symbol is extended by the same number of years, so the oracle is read
+/// and the per-symbol fee computed once; fees are paid in one transfer per recipient.
+#[derive(Accounts)]
+pub struct BatchRenewSymbolsSol<'info> {
+    /// Shared Accounts
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+
+    // Solana Payment Accounts
+
+    /// CHECK: Fee collector receives renewal fees
+    #[account(
+        mut,
+        address = config.fee_collector,
+    )]
+    pub fee_collector: AccountInfo<'info>,
+
+    /// CHECK: Pyth pull oracle price update account (owner verified against OracleConfig); a stale update falls back to a live fallback price
+    pub price_update: AccountInfo<'info>,
+
+    #[account(
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    /// Registered platform taking a share of the fee (required when platform_fee_bps > 0)
+    #[account(mut)]
+    pub platform: Option<Box<Account<'info, Platform>>>,
+
+    /// CHECK: Platform fee recipient - must be the platform's payout wallet
+    #[account(mut)]
+    pub platform_fee_account: Option<AccountInfo<'info>>,
+
+    /// Keeper vault - required when the revenue split has a keeper share
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Option<Box<Account<'info, KeeperVault>>>,
+
+    /// WSOL revenue vault - required when the revenue split has burn or staker shares
+    #[account(
+        mut,
+        seeds = [RevenueVault::SEED_PREFIX, WSOL_MINT.as_ref()],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Option<Box<Account<'info, RevenueVault>>>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchRenewSymbolsSol<'info>>,
+    years: u8,
+    max_sol_cost: u64,
+    platform_fee_bps: u16,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.config;
+
+    // Validate
+    validate_not_paused(config)?;
+
+    validate_platform_fee(ctx.accounts.platform.as_deref(), platform_fee_bps)?;
+
+    let mut tokens = load_batch_token_accounts(ctx.remaining_accounts)?;
+
+    // Single oracle read for the whole batch; each symbol is priced
+    // separately since price locks can differ per symbol
+    let sol_price = get_sol_price(&ctx.accounts.oracle_config, &ctx.accounts.price_update, clock.unix_timestamp)?;
+
+    let mut fees: Vec<(u64, u64)> = Vec::with_capacity(tokens.len());
+    let mut total_fee: u64 = 0;
+    let mut total_fee_usd_micro: u64 = 0;
+    let mut total_treasury: u64 = 0;
+    let mut total_platform: u64 = 0;
+
+    for token in tokens.iter() {
+        let fee_usd_micro = config.calculate_renewal_price_usd(token, clock.unix_timestamp, years);
+        let fee_lamports = config.usd_to_lamports(fee_usd_micro, sol_price.price_micro);
+        let (treasury_amount, platform_amount) =
+            calculate_platform_split(fee_lamports, platform_fee_bps);
+
+        total_fee = total_fee.checked_add(fee_lamports).ok_or(TnsError::MathOverflow)?;
+        total_fee_usd_micro = total_fee_usd_micro.checked_add(fee_usd_micro).ok_or(TnsError::MathOverflow)?;
+        total_treasury = total_treasury.checked_add(treasury_amount).ok_or(TnsError::MathOverflow)?;
+        total_platform = total_platform.checked_add(platform_amount).ok_or(TnsError::MathOverflow)?;
+        fees.push((fee_lamports, platform_amount));
+    }
+
+    // Validate slippage against the batch total
+    validate_slippage(total_fee, max_sol_cost)?;
+
+    // One transfer per recipient
+    transfer_sol_split(
+        SolFeeAccounts {
+            payer: &ctx.accounts.payer.to_account_info(),
+            fee_collector: &ctx.accounts.fee_collector,
+            keeper_vault: ctx.accounts.keeper_vault.as_deref(),
+            revenue_vault: ctx.accounts.revenue_vault.as_deref_mut(),
+            platform: ctx.accounts.platform.as_deref(),
+            platform_fee_account: ctx.accounts.platform_fee_account.as_ref(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        &config.get_revenue_split(),
+        total_treasury,
+        total_platform,
+    )?;
+
+    // Record the batch as one operation on the platform's counters
+    record_platform_volume(ctx.accounts.platform.as_deref_mut(), total_fee_usd_micro, platform_fee_bps);
+
+    for (token, (fee_lamports, platform_amount)) in tokens.iter_mut().zip(fees) {
+        validate_symbol_not_expired(token, clock.unix_timestamp)?;
+
+        let old_expires_at = token.expires_at;
+        let new_expires_at = validate_and_calculate_expiration(
+            old_expires_at,
+            years,
+            clock.unix_timestamp,
+        )?;
+
+        update_symbol_on_renewal(token, new_expires_at);
+        token.exit(&crate::ID)?;
+
+        emit!(SymbolRenewed {
+            token_account: token.key(),
+            symbol: token.symbol.clone(),
+            renewed_by: ctx.accounts.payer.key(),
+            owner: token.owner,
+            years,
+            fee_paid: fee_lamports,
+            platform_fee: platform_amount,
+            sol_price_provider: Some(sol_price.provider),
+            old_expires_at,
+            new_expires_at,
+            renewed_at: clock.unix_timestamp,
+        });
+    }
+
+    emit!(BatchRenewed {
+        renewed_by: ctx.accounts.payer.key(),
+        count: tokens.len() as u8,
+        years,
+        total_fee_paid: total_fee,
+        total_platform_fee: total_platform,
+        sol_price_provider: Some(sol_price.provider),
+        renewed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
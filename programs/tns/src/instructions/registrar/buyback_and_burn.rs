@@ -0,0 +1,279 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use anchor_spl::token_interface::{
+    Mint, TokenAccount, TokenInterface, burn, Burn, sync_native, SyncNative,
+};
+use crate::{
+    Config, OracleConfig, PaymentAsset, PriceSource, RevenueVault, TnsPriceObservation, TnsBurned, TnsError,
+    TNS_MINT, WSOL_MINT, RAYDIUM_CPMM_PROGRAM_ID, RAYDIUM_CPMM_SWAP_BASE_INPUT,
+};
+use super::helpers::{get_sol_price_micro, get_asset_price_micro, AssetPriceAccounts};
+
+/// Swap part of a revenue vault's burn bucket to TNS and burn it (permissionless crank)
+/// The swap goes through the vault's configured Raydium CPMM pool and must return
+/// at least the TWAP-implied amount less the vault's slippage limit.
+#[derive(Accounts)]
+pub struct BuybackAndBurn<'info> {
+    /// Anyone can crank
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// Revenue vault whose burn bucket is spent (SOL or a registered payment asset other than TNS)
+    #[account(
+        mut,
+        seeds = [RevenueVault::SEED_PREFIX, revenue_vault.mint.as_ref()],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Box<Account<'info, RevenueVault>>,
+
+    #[account(
+        mut,
+        seeds = [RevenueVault::TOKEN_SEED_PREFIX, revenue_vault.mint.as_ref()],
+        bump = revenue_vault.token_bump,
+    )]
+    pub revenue_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = revenue_vault.mint @ TnsError::InvalidMint)]
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub input_token_program: Interface<'info, TokenInterface>,
+
+    /// TNS revenue vault, owner of the account receiving the bought TNS
+    #[account(
+        seeds = [RevenueVault::SEED_PREFIX, TNS_MINT.as_ref()],
+        bump = tns_revenue_vault.bump,
+    )]
+    pub tns_revenue_vault: Box<Account<'info, RevenueVault>>,
+
+    #[account(
+        mut,
+        seeds = [RevenueVault::TOKEN_SEED_PREFIX, TNS_MINT.as_ref()],
+        bump = tns_revenue_vault.token_bump,
+    )]
+    pub tns_revenue_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = TNS_MINT @ TnsError::InvalidMint,
+    )]
+    pub tns_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub tns_token_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        seeds = [TnsPriceObservation::SEED_PREFIX],
+        bump = tns_price_observation.bump,
+    )]
+    pub tns_price_observation: Box<Account<'info, TnsPriceObservation>>,
+
+    /// Registry entry pricing the input mint - required for every input except SOL
+    #[account(
+        seeds = [PaymentAsset::SEED_PREFIX, revenue_vault.mint.as_ref()],
+        bump = payment_asset.bump,
+    )]
+    pub payment_asset: Option<Box<Account<'info, PaymentAsset>>>,
+
+    /// CHECK: Pyth price update for the input mint (SOL/USD or the payment asset's feed); owner verified against OracleConfig
+    pub price_update: AccountInfo<'info>,
+
+    #[account(
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    // Raydium CPMM swap accounts (validated by the Raydium program)
+
+    /// CHECK: Raydium CPMM program
+    #[account(address = RAYDIUM_CPMM_PROGRAM_ID)]
+    pub raydium_program: AccountInfo<'info>,
+
+    /// CHECK: Raydium vault and LP mint authority
+    pub raydium_authority: AccountInfo<'info>,
+
+    /// CHECK: Pool's AMM config
+    pub amm_config: AccountInfo<'info>,
+
+    /// CHECK: Pool state - must be the vault's configured buyback pool
+    #[account(
+        mut,
+        address = revenue_vault.buyback_pool @ TnsError::InvalidBuybackSettings,
+    )]
+    pub pool_state: AccountInfo<'info>,
+
+    /// CHECK: Pool vault for the input mint
+    #[account(mut)]
+    pub pool_input_vault: AccountInfo<'info>,
+
+    /// CHECK: Pool vault for TNS
+    #[account(mut)]
+    pub pool_tns_vault: AccountInfo<'info>,
+
+    /// CHECK: Pool observation state
+    #[account(mut)]
+    pub observation_state: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<BuybackAndBurn>, amount_in: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.config;
+    let mint = ctx.accounts.revenue_vault.mint;
+
+    // Validate
+    require!(ctx.accounts.revenue_vault.buyback_pool != Pubkey::default(), TnsError::BuybackDisabled);
+    require!(
+        amount_in > 0 && amount_in <= ctx.accounts.revenue_vault.burn_balance,
+        TnsError::InvalidBuybackAmount
+    );
+
+    // Price the input and TNS in USD
+    let input_price_micro = if mint == WSOL_MINT {
+        get_sol_price_micro(&ctx.accounts.oracle_config, &ctx.accounts.price_update, clock.unix_timestamp)?
+    } else {
+        let payment_asset = ctx.accounts.payment_asset.as_deref().ok_or(TnsError::UnsupportedBuybackMint)?;
+        require!(payment_asset.price_source != PriceSource::TnsTwap, TnsError::UnsupportedBuybackMint);
+
+        get_asset_price_micro(
+            payment_asset,
+            &AssetPriceAccounts {
+                config,
+                oracle_config: &ctx.accounts.oracle_config,
+                price_update: Some(&ctx.accounts.price_update),
+                tns_price_observation: None,
+                tns_price_sources: None,
+                tns_usd_price_update: None,
+                pool_accounts: &[],
+                current_time: clock.unix_timestamp,
+            },
+        )?
+    };
+
+    let tns_twap = ctx.accounts.tns_price_observation
+        .twap(clock.unix_timestamp, config.get_tns_twap_window())
+        .ok_or(TnsError::TwapUnavailable)?;
+    require!(tns_twap > 0, TnsError::TwapUnavailable);
+
+    // min_out = amount_in * input_price / tns_twap, rescaled by decimals, less slippage
+    let expected_out = (amount_in as u128)
+        .checked_mul(input_price_micro as u128)
+        .and_then(|v| v.checked_mul(10u128.pow(ctx.accounts.tns_mint.decimals as u32)))
+        .ok_or(TnsError::MathOverflow)?
+        / (tns_twap as u128 * 10u128.pow(ctx.accounts.input_mint.decimals as u32));
+    let min_out = expected_out
+        * (10000 - ctx.accounts.revenue_vault.buyback_max_slippage_bps as u128)
+        / 10000;
+    let min_out = u64::try_from(min_out).map_err(|_| error!(TnsError::MathOverflow))?;
+
+    // SOL revenue is held as lamports on the vault; wrap the amount being spent
+    if mint == WSOL_MINT {
+        ctx.accounts.revenue_vault.sub_lamports(amount_in)?;
+        ctx.accounts.revenue_token_account.add_lamports(amount_in)?;
+
+        sync_native(CpiContext::new(
+            ctx.accounts.input_token_program.to_account_info(),
+            SyncNative {
+                account: ctx.accounts.revenue_token_account.to_account_info(),
+            },
+        ))?;
+    }
+
+    let tns_before = ctx.accounts.tns_revenue_token_account.amount;
+
+    // Swap through the configured pool, signed by the revenue vault
+    let vault_bump = ctx.accounts.revenue_vault.bump;
+    let vault_seeds: &[&[&[u8]]] = &[&[RevenueVault::SEED_PREFIX, mint.as_ref(), &[vault_bump]]];
+
+    let mut data = RAYDIUM_CPMM_SWAP_BASE_INPUT.to_vec();
+    data.extend_from_slice(&amount_in.to_le_bytes());
+    data.extend_from_slice(&min_out.to_le_bytes());
+
+    let swap = Instruction {
+        program_id: RAYDIUM_CPMM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(ctx.accounts.revenue_vault.key(), true),
+            AccountMeta::new_readonly(ctx.accounts.raydium_authority.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.amm_config.key(), false),
+            AccountMeta::new(ctx.accounts.pool_state.key(), false),
+            AccountMeta::new(ctx.accounts.revenue_token_account.key(), false),
+            AccountMeta::new(ctx.accounts.tns_revenue_token_account.key(), false),
+            AccountMeta::new(ctx.accounts.pool_input_vault.key(), false),
+            AccountMeta::new(ctx.accounts.pool_tns_vault.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.input_token_program.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.tns_token_program.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.input_mint.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.tns_mint.key(), false),
+            AccountMeta::new(ctx.accounts.observation_state.key(), false),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &swap,
+        &[
+            ctx.accounts.revenue_vault.to_account_info(),
+            ctx.accounts.raydium_authority.to_account_info(),
+            ctx.accounts.amm_config.to_account_info(),
+            ctx.accounts.pool_state.to_account_info(),
+            ctx.accounts.revenue_token_account.to_account_info(),
+            ctx.accounts.tns_revenue_token_account.to_account_info(),
+            ctx.accounts.pool_input_vault.to_account_info(),
+            ctx.accounts.pool_tns_vault.to_account_info(),
+            ctx.accounts.input_token_program.to_account_info(),
+            ctx.accounts.tns_token_program.to_account_info(),
+            ctx.accounts.input_mint.to_account_info(),
+            ctx.accounts.tns_mint.to_account_info(),
+            ctx.accounts.observation_state.to_account_info(),
+            ctx.accounts.raydium_program.to_account_info(),
+        ],
+        vault_seeds,
+    )?;
+
+    // Enforce the output independently of the pool's own check
+    ctx.accounts.tns_revenue_token_account.reload()?;
+    let tns_bought = ctx.accounts.tns_revenue_token_account.amount
+        .checked_sub(tns_before)
+        .ok_or(TnsError::MathOverflow)?;
+    require!(tns_bought >= min_out, TnsError::BuybackOutputTooLow);
+
+    // Burn the bought TNS, signed by the TNS revenue vault
+    let tns_vault_seeds: &[&[&[u8]]] = &[&[
+        RevenueVault::SEED_PREFIX,
+        TNS_MINT.as_ref(),
+        &[ctx.accounts.tns_revenue_vault.bump],
+    ]];
+
+    burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.tns_token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.tns_mint.to_account_info(),
+                from: ctx.accounts.tns_revenue_token_account.to_account_info(),
+                authority: ctx.accounts.tns_revenue_vault.to_account_info(),
+            },
+            tns_vault_seeds,
+        ),
+        tns_bought,
+    )?;
+
+    let revenue_vault = &mut ctx.accounts.revenue_vault;
+    revenue_vault.burn_balance -= amount_in;
+    revenue_vault.total_tns_burned = revenue_vault.total_tns_burned.saturating_add(tns_bought);
+
+    emit!(TnsBurned {
+        source_mint: mint,
+        amount_in,
+        tns_burned: tns_bought,
+        burned_by: ctx.accounts.cranker.key(),
+        burned_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
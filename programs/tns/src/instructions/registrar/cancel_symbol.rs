@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::{Config, Token, SymbolCanceled, TnsError, KEEPER_REWARD_LAMPORTS};
-use super::helpers::validate_not_paused;
+use crate::{BondVault, Config, KeeperVault, Token, SymbolCanceled, TnsError};
+use super::helpers::{validate_not_paused, validate_no_open_dispute, pay_keeper_reward, forfeit_bond};
 
 /// Cancel an abandoned symbol (1+ year past grace period)
 /// This closes the account entirely, returning rent to the caller
-/// Keeper also receives fixed reward from the Config PDA
+/// Keeper also receives the symbol's keeper deposit from the KeeperVault PDA
+/// Any bond is forfeited: the keeper receives its keeper share, the treasury the rest
 /// The symbol becomes available for fresh registration
 #[derive(Accounts)]
 pub struct CancelSymbol<'info> {
@@ -12,14 +13,28 @@ pub struct CancelSymbol<'info> {
     #[account(mut)]
     pub keeper: Signer<'info>,
 
-    /// Config PDA holds keeper reward lamports
     #[account(
-        mut,
         seeds = [Config::SEED_PREFIX],
         bump = config.bump,
     )]
     pub config: Account<'info, Config>,
 
+    /// Keeper vault pays out the symbol's keeper deposit
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Account<'info, KeeperVault>,
+
+    /// Bond vault forfeits the symbol's bond (required when the symbol has a bond)
+    #[account(
+        mut,
+        seeds = [BondVault::SEED_PREFIX],
+        bump = bond_vault.bump,
+    )]
+    pub bond_vault: Option<Account<'info, BondVault>>,
+
     #[account(
         mut,
         seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
@@ -35,6 +50,8 @@ pub fn handler(ctx: Context<CancelSymbol>) -> Result<()> {
     // Validate not paused
     validate_not_paused(&ctx.accounts.config)?;
 
+    validate_no_open_dispute(&ctx.accounts.token_account)?;
+
     // Verify the symbol is cancelable (1 year past grace period)
     require!(
         ctx.accounts.token_account.is_cancelable(clock.unix_timestamp),
@@ -44,22 +61,22 @@ pub fn handler(ctx: Context<CancelSymbol>) -> Result<()> {
     // Capture rent before any transfers (closure happens at end via Anchor constraint)
     let rent_returned = ctx.accounts.token_account.to_account_info().lamports();
 
-    // Pay keeper reward from Config PDA (fixed 0.05 SOL)
-    let keeper_reward = KEEPER_REWARD_LAMPORTS;
-    let config_info = ctx.accounts.config.to_account_info();
-    let config_balance = config_info.lamports();
-
-    // Get minimum rent for the config account
-    let rent = Rent::get()?;
-    let min_rent = rent.minimum_balance(8 + Config::INIT_SPACE);
-
-    // Only pay if config has sufficient balance (above rent-exempt minimum)
-    if config_balance > min_rent + keeper_reward {
-        let keeper_info = ctx.accounts.keeper.to_account_info();
+    // Pay the symbol's keeper deposit (or legacy reward) from the keeper vault
+    let keeper_reward = pay_keeper_reward(
+        &mut ctx.accounts.keeper_vault,
+        &ctx.accounts.keeper.to_account_info(),
+        ctx.accounts.token_account.keeper_deposit,
+        ctx.accounts.config.get_keeper_reward_lamports(),
+    )?;
 
-        **config_info.try_borrow_mut_lamports()? -= keeper_reward;
-        **keeper_info.try_borrow_mut_lamports()? += keeper_reward;
-    }
+    // Forfeit the bond posted in bond pricing mode
+    let bond_forfeited = ctx.accounts.token_account.bond_lamports;
+    let bond_keeper_reward = forfeit_bond(
+        ctx.accounts.bond_vault.as_mut(),
+        &ctx.accounts.keeper.to_account_info(),
+        bond_forfeited,
+        &ctx.accounts.config.bond_policy,
+    )?;
 
     emit!(SymbolCanceled {
         token_account: ctx.accounts.token_account.key(),
@@ -69,6 +86,9 @@ pub fn handler(ctx: Context<CancelSymbol>) -> Result<()> {
         canceled_by: ctx.accounts.keeper.key(),
         canceled_at: clock.unix_timestamp,
         rent_returned,
+        keeper_reward,
+        bond_forfeited,
+        bond_keeper_reward,
     });
 
     // Account closure is handled by Anchor's close = keeper constraint
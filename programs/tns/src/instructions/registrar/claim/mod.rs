@@ -1,14 +1,8 @@
 pub mod sol;
-pub mod tns;
-pub mod usdc;
-pub mod usdt;
+pub mod asset;
 
 pub use sol::ClaimExpiredSymbolSol;
-pub use tns::ClaimExpiredSymbolTns;
-pub use usdc::ClaimExpiredSymbolUsdc;
-pub use usdt::ClaimExpiredSymbolUsdt;
+pub use asset::ClaimExpiredSymbolAsset;
 
 pub(crate) use sol::__client_accounts_claim_expired_symbol_sol;
-pub(crate) use tns::__client_accounts_claim_expired_symbol_tns;
-pub(crate) use usdc::__client_accounts_claim_expired_symbol_usdc;
-pub(crate) use usdt::__client_accounts_claim_expired_symbol_usdt;
+pub(crate) use asset::__client_accounts_claim_expired_symbol_asset;
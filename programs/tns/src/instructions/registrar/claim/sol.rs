@@ -1,17 +1,20 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
-use crate::{Config, Token, SymbolClaimed};
+use crate::{
+    BondVault, Config, OracleConfig, Token, SymbolClaimed, Platform, KeeperVault, RevenueVault,
+    WSOL_MINT,
+};
 use super::super::helpers::{
-    validate_not_paused, validate_symbol_claimable, validate_mint_metadata,
-    validate_and_calculate_expiration, validate_slippage, validate_platform_fee_bps,
-    calculate_fees_sol, transfer_sol_fees_with_platform,
-    update_symbol_on_claim, SymbolClaimData,
+    validate_not_paused, validate_symbol_claimable, validate_no_open_dispute, validate_mint_metadata,
+    validate_eligibility, validate_and_calculate_expiration, validate_slippage, validate_platform_fee,
+    validate_bond_mode, record_platform_volume, calculate_fees_sol, transfer_sol_fees_with_platform,
+    SolFeeAccounts, deposit_bond, record_bond_forfeit, update_symbol_on_claim, SymbolClaimData,
 };
 
 /// Claim an expired symbol with SOL payment
 /// Anyone can claim a symbol that is past its grace period
 /// No keeper reward - the original registration already funded the keeper pool
+/// The previous owner's bond is forfeited; in bond mode the claimant posts a new one
 #[derive(Accounts)]
 pub struct ClaimExpiredSymbolSol<'info> {
     /// Shared Accounts
@@ -32,6 +35,15 @@ pub struct ClaimExpiredSymbolSol<'info> {
     )]
     pub token_account: Account<'info, Token>,
 
+    /// Bond vault forfeits the previous owner's bond and locks the new one in bond pricing mode
+    /// (required in bond mode or when the symbol has a bond)
+    #[account(
+        mut,
+        seeds = [BondVault::SEED_PREFIX],
+        bump = bond_vault.bump,
+    )]
+    pub bond_vault: Option<Box<Account<'info, BondVault>>>,
+
     pub system_program: Program<'info, System>,
 
     // Solana Payment Accounts
@@ -43,8 +55,14 @@ pub struct ClaimExpiredSymbolSol<'info> {
     )]
     pub fee_collector: AccountInfo<'info>,
 
-    /// Pyth pull oracle price update account (ownership verified by SDK)
-    pub price_update: Account<'info, PriceUpdateV2>,
+    /// CHECK: Pyth pull oracle price update account (owner verified against OracleConfig); a stale update falls back to a live fallback price
+    pub price_update: AccountInfo<'info>,
+
+    #[account(
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
 
     /// The new mint to register the symbol to (validated as a real mint)
     pub new_mint: InterfaceAccount<'info, Mint>,
@@ -52,10 +70,29 @@ pub struct ClaimExpiredSymbolSol<'info> {
     /// CHECK: Metaplex metadata account for new_mint - validated in handler
     pub new_mint_metadata: AccountInfo<'info>,
 
-    /// Optional platform fee account (for launchpad referrals)
-    /// CHECK: Validated by transfer if present
+    /// Registered platform taking a share of the fee (required when platform_fee_bps > 0)
+    #[account(mut)]
+    pub platform: Option<Box<Account<'info, Platform>>>,
+
+    /// CHECK: Platform fee recipient - must be the platform's payout wallet
     #[account(mut)]
     pub platform_fee_account: Option<AccountInfo<'info>>,
+
+    /// Keeper vault - required when the revenue split has a keeper share
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Option<Box<Account<'info, KeeperVault>>>,
+
+    /// WSOL revenue vault - required when the revenue split has burn or staker shares
+    #[account(
+        mut,
+        seeds = [RevenueVault::SEED_PREFIX, WSOL_MINT.as_ref()],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Option<Box<Account<'info, RevenueVault>>>,
 }
 
 pub fn handler(
@@ -73,19 +110,29 @@ pub fn handler(
     
     validate_symbol_claimable(&ctx.accounts.token_account, clock.unix_timestamp)?;
 
+    validate_no_open_dispute(&ctx.accounts.token_account)?;
+
     let expires_at = validate_and_calculate_expiration(
         clock.unix_timestamp,
         years,
         clock.unix_timestamp,
     )?;
 
-    validate_mint_metadata(
+    let metadata = validate_mint_metadata(
         &ctx.accounts.new_mint_metadata,
         &ctx.accounts.new_mint.to_account_info(),
         &ctx.accounts.token_account.symbol,
     )?;
 
-    validate_platform_fee_bps(platform_fee_bps)?;
+    validate_eligibility(
+        &ctx.accounts.config.eligibility_policy,
+        &ctx.accounts.new_mint_metadata,
+        &ctx.accounts.new_mint,
+    )?;
+
+    validate_platform_fee(ctx.accounts.platform.as_deref(), platform_fee_bps)?;
+
+    validate_bond_mode(config, true, platform_fee_bps)?;
 
     // New owner is the payer, not the mint's update_authority
     let new_owner = ctx.accounts.payer.key();
@@ -95,6 +142,7 @@ pub fn handler(
         config,
         clock.unix_timestamp,
         years,
+        &ctx.accounts.oracle_config,
         &ctx.accounts.price_update,
     )?;
 
@@ -105,15 +153,41 @@ pub fn handler(
     let previous_owner = ctx.accounts.token_account.owner;
     let previous_mint = ctx.accounts.token_account.mint;
 
-    // Transfer fees with platform split (no keeper reward - original registration funded keeper pool)
-    let platform_fee_paid = transfer_sol_fees_with_platform(
-        &ctx.accounts.payer.to_account_info(),
-        &ctx.accounts.fee_collector,
-        ctx.accounts.platform_fee_account.as_ref(),
-        &ctx.accounts.system_program.to_account_info(),
-        fees.fee_lamports,
-        platform_fee_bps,
-    )?;
+    // Forfeit the previous owner's bond to the treasury
+    let bond_forfeited = ctx.accounts.token_account.bond_lamports;
+    record_bond_forfeit(ctx.accounts.bond_vault.as_deref_mut(), bond_forfeited)?;
+
+    // In bond mode the claim price is locked as a refundable bond instead of paid as a fee
+    let (platform_fee_paid, bond_posted) = if config.bond_policy.enabled {
+        let bond_posted = deposit_bond(
+            &ctx.accounts.payer.to_account_info(),
+            ctx.accounts.bond_vault.as_deref_mut(),
+            &ctx.accounts.system_program.to_account_info(),
+            fees.fee_lamports,
+        )?;
+        (0, bond_posted)
+    } else {
+        // Transfer fees with platform split (no keeper reward - original registration funded keeper pool)
+        let platform_fee_paid = transfer_sol_fees_with_platform(
+            SolFeeAccounts {
+                payer: &ctx.accounts.payer.to_account_info(),
+                fee_collector: &ctx.accounts.fee_collector,
+                keeper_vault: ctx.accounts.keeper_vault.as_deref(),
+                revenue_vault: ctx.accounts.revenue_vault.as_deref_mut(),
+                platform: ctx.accounts.platform.as_deref(),
+                platform_fee_account: ctx.accounts.platform_fee_account.as_ref(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+            },
+            &config.get_revenue_split(),
+            fees.fee_lamports,
+            platform_fee_bps,
+        )?;
+        (platform_fee_paid, 0)
+    };
+
+    // Record the operation on the platform's counters
+    let fee_usd_micro = config.calculate_registration_price_usd(clock.unix_timestamp, years);
+    record_platform_volume(ctx.accounts.platform.as_deref_mut(), fee_usd_micro, platform_fee_bps);
 
     // Update symbol with new owner (payer) and mint
     update_symbol_on_claim(
@@ -122,6 +196,9 @@ pub fn handler(
             new_mint,
             new_owner,
             expires_at,
+            metadata_commitment: metadata.commitment(clock.unix_timestamp),
+            price_lock: ctx.accounts.config.new_price_lock(clock.unix_timestamp),
+            bond_lamports: bond_posted,
         },
     );
 
@@ -133,8 +210,11 @@ pub fn handler(
         new_owner,
         new_mint,
         years,
-        fee_paid: fees.fee_lamports,
+        fee_paid: fees.fee_lamports - bond_posted,
         platform_fee: platform_fee_paid,
+        sol_price_provider: Some(fees.price_provider),
+        bond_posted,
+        bond_forfeited,
         claimed_at: clock.unix_timestamp,
         expires_at,
     });
@@ -1,27 +1,41 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, TokenAccount};
-use mpl_token_metadata::accounts::Metadata;
-use crate::{Config, Token, OwnershipClaimed, TnsError};
-use super::helpers::validate_not_paused;
+use anchor_spl::token_interface::Mint;
+use crate::{
+    BondVault, Config, KeeperVault, Token, OwnershipClaimed, TnsError,
+    CLAIM_PRECEDENCE_MINT_AUTHORITY, CLAIM_PRECEDENCE_UPDATE_AUTHORITY,
+};
+use super::helpers::{
+    validate_not_paused, validate_no_open_dispute, validate_claim_precedence, extract_update_authority,
+    refund_owner_deposits,
+};
 
 /// Claim ownership of a TNS record by proving token authority.
 ///
 /// This allows the rightful owner of a token to claim the TNS record
-/// even if someone else registered it first. Three paths to claim:
+/// even if someone else registered it first. Two paths to claim:
 ///
 /// 1. **Mint authority**: If you control the mint authority, you control the token
 /// 2. **Metadata update authority**: If you control metadata, you control the brand
-/// 3. **Majority holder (>50%)**: If you hold majority supply, you have economic control
+///
+/// Majority holders (>50% of supply) claim via start_majority_claim /
+/// complete_majority_claim, which lock tokens in escrow for a hold period.
+///
+/// The displaced owner is refunded the symbol's keeper deposit and bond.
 ///
 /// This creates a clear ownership hierarchy:
 /// - Token authority = ultimate control (can always reclaim)
 /// - TNS owner = delegated control (can be claimed by authority)
 ///
+/// When authorities are held by different parties, precedence decides:
+/// update authority > mint authority > majority holder. A claim can't displace
+/// an owner who claimed with higher precedence, and a same-precedence claim
+/// must wait out the claim cooldown.
+///
 /// Note: If you transfer TNS ownership to someone, they should verify you've
 /// also transferred or burned your token authorities, otherwise you can reclaim.
 #[derive(Accounts)]
 pub struct ClaimOwnership<'info> {
-    /// The claimant - must be mint authority, update authority, or majority holder
+    /// The claimant - must be mint authority or update authority
     #[account(mut)]
     pub claimant: Signer<'info>,
 
@@ -35,25 +49,41 @@ pub struct ClaimOwnership<'info> {
         mut,
         seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
         bump = token_account.bump,
+        has_one = owner @ TnsError::UnauthorizedOwner,
     )]
     pub token_account: Account<'info, Token>,
 
+    /// CHECK: Current owner (validated by has_one), receives the symbol's keeper deposit and bond
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+
     /// The token mint that this symbol is registered to
     #[account(
         constraint = token_mint.key() == token_account.mint @ TnsError::InvalidMint
     )]
     pub token_mint: InterfaceAccount<'info, Mint>,
 
-    /// CHECK: Metaplex metadata account - validated in handler
+    /// The mint's metadata account
+    /// For classic SPL: pass Metaplex metadata PDA
+    /// For Token-2022: pass the mint (embedded metadata) or its MetadataPointer target
+    /// CHECK: Validated via extract_update_authority helper
     pub token_metadata: AccountInfo<'info>,
 
-    /// The claimant's token account for the mint (for majority holder check)
-    /// Optional - only needed if claiming via majority holder path
+    /// Keeper vault refunds the symbol's keeper deposit to the old owner
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Account<'info, KeeperVault>,
+
+    /// Bond vault refunds the symbol's bond to the old owner (required when the symbol has a bond)
     #[account(
-        token::mint = token_mint,
-        token::authority = claimant,
+        mut,
+        seeds = [BondVault::SEED_PREFIX],
+        bump = bond_vault.bump,
     )]
-    pub claimant_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub bond_vault: Option<Account<'info, BondVault>>,
 }
 
 pub fn handler(ctx: Context<ClaimOwnership>) -> Result<()> {
@@ -65,22 +95,19 @@ pub fn handler(ctx: Context<ClaimOwnership>) -> Result<()> {
     // Validate not paused
     validate_not_paused(&ctx.accounts.config)?;
 
+    validate_no_open_dispute(&ctx.accounts.token_account)?;
+
     // Can't claim if already owner
     require!(
         ctx.accounts.token_account.owner != claimant,
         TnsError::AlreadyOwner
     );
 
-    // Verify metadata account is the correct PDA for this mint
-    let (expected_metadata_key, _) = Metadata::find_pda(&token_mint.key());
-    require!(
-        token_metadata_info.key() == expected_metadata_key,
-        TnsError::InvalidMetadata
-    );
-
-    // Deserialize metadata to get update authority
-    let metadata = Metadata::safe_deserialize(&token_metadata_info.data.borrow())
-        .map_err(|_| TnsError::InvalidMetadata)?;
+    // Read update authority (Metaplex for classic SPL, metadata extension for Token-2022)
+    let update_authority = extract_update_authority(
+        token_metadata_info,
+        &token_mint.to_account_info(),
+    )?;
 
     // Check path 1: Mint authority
     let is_mint_authority = token_mint.mint_authority
@@ -88,42 +115,41 @@ pub fn handler(ctx: Context<ClaimOwnership>) -> Result<()> {
         .unwrap_or(false);
 
     // Check path 2: Metadata update authority
-    let is_update_authority = metadata.update_authority == claimant;
-
-    // Check path 3: Majority holder (>50% of supply)
-    let is_majority_holder = if let Some(claimant_token_account) = &ctx.accounts.claimant_token_account {
-        let total_supply = token_mint.supply;
-        if total_supply > 0 {
-            let claimant_balance = claimant_token_account.amount;
-            // Must hold MORE than 50%
-            claimant_balance > total_supply / 2
-        } else {
-            false
-        }
-    } else {
-        false
-    };
+    let is_update_authority = update_authority == Some(claimant);
 
     // Must satisfy at least one path
     require!(
-        is_mint_authority || is_update_authority || is_majority_holder,
+        is_mint_authority || is_update_authority,
         TnsError::NotTokenAuthority
     );
 
-    // Determine claim type for event
-    let claim_type = if is_mint_authority {
-        "mint_authority"
-    } else if is_update_authority {
-        "update_authority"
+    // Claim with the highest precedence path the claimant satisfies
+    let (claim_type, precedence) = if is_update_authority {
+        ("update_authority", CLAIM_PRECEDENCE_UPDATE_AUTHORITY)
     } else {
-        "majority_holder"
+        ("mint_authority", CLAIM_PRECEDENCE_MINT_AUTHORITY)
     };
 
+    validate_claim_precedence(
+        &ctx.accounts.token_account,
+        precedence,
+        clock.unix_timestamp,
+        ctx.accounts.config.get_claim_cooldown(),
+    )?;
+
     // Capture old owner before mutation
     let old_owner = ctx.accounts.token_account.owner;
 
     // Transfer ownership to claimant
     ctx.accounts.token_account.owner = claimant;
+    ctx.accounts.token_account.record_claim(precedence, clock.unix_timestamp);
+
+    let (deposit_refunded, bond_refunded) = refund_owner_deposits(
+        &mut ctx.accounts.token_account,
+        &mut ctx.accounts.keeper_vault,
+        ctx.accounts.bond_vault.as_mut(),
+        &ctx.accounts.owner,
+    )?;
 
     emit!(OwnershipClaimed {
         token_account: ctx.accounts.token_account.key(),
@@ -131,7 +157,10 @@ pub fn handler(ctx: Context<ClaimOwnership>) -> Result<()> {
         old_owner,
         new_owner: claimant,
         claim_type: claim_type.to_string(),
+        precedence,
         claimed_at: clock.unix_timestamp,
+        deposit_refunded,
+        bond_refunded,
     });
 
     Ok(())
@@ -1,9 +1,19 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use anchor_spl::token_interface::{
+    Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked, close_account, CloseAccount, burn, Burn,
+};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+    state::Mint as Token2022Mint,
+};
+use pyth_solana_receiver_sdk::error::GetPriceError;
+use pyth_solana_receiver_sdk::price_update::{Price, PriceUpdateV2};
+use super::validation::{validate_payment_mint_extensions, validate_slippage};
 use crate::{
-    Config, TnsError, MAX_PRICE_STALENESS_SECONDS, SOL_USD_FEED_ID,
-    PUMP_POOL_TNS_RESERVE, PUMP_POOL_SOL_RESERVE, SOL_DECIMALS, STABLECOIN_DECIMALS,
+    BondPolicy, BondVault, Config, KeeperVault, OracleConfig, PaymentAsset, Platform, PriceProvider, RevenueSplit, RevenueShares, RevenueVault, RevenueDistributed, TnsBurned, PriceSource, TnsPriceObservation, TnsPriceSources, TnsPoolSource,
+    TnsPoolKind, Token, TokenFeeCharged, TnsError,
+    STABLECOIN_MULTIPLIER,
+    TNS_MINT, WSOL_MINT, RAYDIUM_CPMM_PROGRAM_ID, SOL_DECIMALS, STABLECOIN_DECIMALS,
 };
 
 // ============================================================================
@@ -16,6 +26,8 @@ pub struct SolFeeBreakdown {
     pub fee_lamports: u64,
     /// Fixed keeper reward in lamports
     pub keeper_reward_lamports: u64,
+    /// Provider of the SOL/USD price used
+    pub price_provider: PriceProvider,
 }
 
 /// Calculate registration/renewal fees for SOL payments
@@ -23,55 +35,21 @@ pub fn calculate_fees_sol(
     config: &Config,
     current_time: i64,
     years: u8,
-    price_update: &Account<PriceUpdateV2>,
+    oracle_config: &OracleConfig,
+    price_update: &AccountInfo,
 ) -> Result<SolFeeBreakdown> {
-    let sol_price_micro = get_sol_price_micro(price_update)?;
+    let sol_price = get_sol_price(oracle_config, price_update, current_time)?;
 
-    let fee_lamports = config.calculate_registration_price_lamports(current_time, years, sol_price_micro);
+    let fee_lamports = config.calculate_registration_price_lamports(current_time, years, sol_price.price_micro);
     let keeper_reward_lamports = config.get_keeper_reward_lamports();
 
     Ok(SolFeeBreakdown {
         fee_lamports,
         keeper_reward_lamports,
+        price_provider: sol_price.provider,
     })
 }
 
-/// Transfer SOL fees for registration (includes keeper reward to config PDA)
-/// 100% of fee goes to fee collector, fixed keeper reward goes to Config PDA
-pub fn transfer_sol_registration_fees<'info>(
-    payer: &AccountInfo<'info>,
-    fee_collector: &AccountInfo<'info>,
-    config: &AccountInfo<'info>,
-    system_program: &AccountInfo<'info>,
-    fees: &SolFeeBreakdown,
-) -> Result<u64> {
-    // Transfer 100% to fee collector
-    anchor_lang::system_program::transfer(
-        CpiContext::new(
-            system_program.clone(),
-            anchor_lang::system_program::Transfer {
-                from: payer.clone(),
-                to: fee_collector.clone(),
-            },
-        ),
-        fees.fee_lamports,
-    )?;
-
-    // Transfer fixed keeper reward to Config PDA
-    anchor_lang::system_program::transfer(
-        CpiContext::new(
-            system_program.clone(),
-            anchor_lang::system_program::Transfer {
-                from: payer.clone(),
-                to: config.clone(),
-            },
-        ),
-        fees.keeper_reward_lamports,
-    )?;
-
-    Ok(fees.keeper_reward_lamports)
-}
-
 /// Transfer SOL fees for renewal or claim (no keeper reward)
 /// 100% of fee goes to fee collector
 pub fn transfer_sol_renewal_fees<'info>(
@@ -102,23 +80,26 @@ pub fn transfer_sol_renewal_fees<'info>(
 pub struct UpdateFeeBreakdown {
     pub fee_lamports: u64,
     pub fee_usd_micro: u64,
+    pub price_provider: PriceProvider,
 }
 
 /// Calculate update mint fee
 pub fn calculate_update_fee(
     config: &Config,
     current_time: i64,
-    price_update: &Account<PriceUpdateV2>,
+    oracle_config: &OracleConfig,
+    price_update: &AccountInfo,
 ) -> Result<UpdateFeeBreakdown> {
-    let sol_price_micro = get_sol_price_micro(price_update)?;
+    let sol_price = get_sol_price(oracle_config, price_update, current_time)?;
 
     let yearly_price_usd_micro = config.get_current_yearly_price_usd(current_time);
     let fee_usd_micro = yearly_price_usd_micro * config.update_fee_bps as u64 / 10000;
-    let fee_lamports = config.usd_to_lamports(fee_usd_micro, sol_price_micro);
+    let fee_lamports = config.usd_to_lamports(fee_usd_micro, sol_price.price_micro);
 
     Ok(UpdateFeeBreakdown {
         fee_lamports,
         fee_usd_micro,
+        price_provider: sol_price.provider,
     })
 }
 
@@ -143,229 +124,649 @@ pub fn transfer_sol_update_fee<'info>(
 }
 
 // ============================================================================
-// Stablecoin Fee Transfers
+// Platform Fee Support
 // ============================================================================
 
-/// Accounts needed for token-based fee transfers (TNS, USDC, USDT)
-pub struct TokenFeeTransferAccounts<'a, 'info> {
-    pub payer: &'a Signer<'info>,
-    pub payer_token_account: &'a InterfaceAccount<'info, TokenAccount>,
-    pub vault: &'a InterfaceAccount<'info, TokenAccount>,
-    pub mint: &'a InterfaceAccount<'info, Mint>,
-    pub token_program: &'a Interface<'info, TokenInterface>,
-    pub system_program: &'a AccountInfo<'info>,
+/// Calculate platform fee from total fee
+/// Returns (treasury_amount, platform_amount)
+pub fn calculate_platform_split(total_amount: u64, platform_fee_bps: u16) -> (u64, u64) {
+    if platform_fee_bps == 0 {
+        return (total_amount, 0);
+    }
+    let platform_amount = total_amount * platform_fee_bps as u64 / 10000;
+    let treasury_amount = total_amount - platform_amount;
+    (treasury_amount, platform_amount)
 }
 
-/// Fee breakdown for token payments (TNS, USDC, USDT)
-pub struct TokenFeeBreakdown {
-    /// Fee in USD micro-cents
-    pub fee_usd_micro: u64,
-    /// Fixed keeper reward in lamports
-    pub keeper_reward_lamports: u64,
+/// Add a fee-paying operation to the platform's counters, if one was passed
+pub fn record_platform_volume(platform: Option<&mut Account<Platform>>, volume_usd_micro: u64, platform_fee_bps: u16) {
+    if let Some(platform) = platform {
+        platform.record(volume_usd_micro, platform_fee_bps);
+    }
 }
 
-/// Transfer stablecoin (USDC/USDT) fees for registration (no discount, includes keeper reward)
-/// 100% of fee goes to treasury, fixed keeper reward in SOL to Config PDA
-pub fn transfer_stablecoin_registration_fees<'info>(
-    accounts: &TokenFeeTransferAccounts<'_, 'info>,
-    fees: &TokenFeeBreakdown,
-    config: &AccountInfo<'info>,
-) -> Result<u64> {
-    // Transfer 100% to vault (treasury)
-    // Note: fee_usd_micro equals token amount for 6-decimal stablecoins at $1 peg
-    transfer_checked(
-        CpiContext::new(
-            accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: accounts.payer_token_account.to_account_info(),
-                mint: accounts.mint.to_account_info(),
-                to: accounts.vault.to_account_info(),
-                authority: accounts.payer.to_account_info(),
-            },
-        ),
-        fees.fee_usd_micro,
-        accounts.mint.decimals,
-    )?;
+/// Move lamports from the payer
+/// Wallets pay with the system program; program-owned payers (auto-renew
+/// escrows) are debited directly, and their caller must keep them rent-exempt.
+fn transfer_lamports<'info>(
+    system_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if *from.owner == crate::ID {
+        **from.try_borrow_mut_lamports()? -= amount;
+        **to.try_borrow_mut_lamports()? += amount;
+
+        return Ok(());
+    }
 
-    // Fixed keeper reward in SOL to Config PDA
     anchor_lang::system_program::transfer(
         CpiContext::new(
-            accounts.system_program.clone(),
+            system_program.clone(),
             anchor_lang::system_program::Transfer {
-                from: accounts.payer.to_account_info(),
-                to: config.clone(),
+                from: from.clone(),
+                to: to.clone(),
             },
         ),
-        fees.keeper_reward_lamports,
-    )?;
+        amount,
+    )
+}
 
-    Ok(fees.keeper_reward_lamports)
+/// Accounts receiving a SOL fee
+pub struct SolFeeAccounts<'a, 'info> {
+    /// Signing wallet, or a program-owned escrow holding the fee
+    pub payer: &'a AccountInfo<'info>,
+    pub fee_collector: &'a AccountInfo<'info>,
+    /// Receives the keeper share of protocol revenue
+    pub keeper_vault: Option<&'a Account<'info, KeeperVault>>,
+    /// WSOL revenue vault, receives the burn and staker shares
+    pub revenue_vault: Option<&'a mut Account<'info, RevenueVault>>,
+    pub platform: Option<&'a Account<'info, Platform>>,
+    pub platform_fee_account: Option<&'a AccountInfo<'info>>,
+    pub system_program: &'a AccountInfo<'info>,
 }
 
-/// Transfer stablecoin (USDC/USDT) fees for renewal or claim (no discount, no keeper reward)
-/// 100% of fee goes to treasury
-pub fn transfer_stablecoin_renewal_fees<'info>(
-    accounts: &TokenFeeTransferAccounts<'_, 'info>,
-    fee_usd_micro: u64,
-) -> Result<()> {
-    // Transfer 100% to vault (treasury)
-    // Note: fee_usd_micro equals token amount for 6-decimal stablecoins at $1 peg
-    transfer_checked(
-        CpiContext::new(
-            accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: accounts.payer_token_account.to_account_info(),
-                mint: accounts.mint.to_account_info(),
-                to: accounts.vault.to_account_info(),
-                authority: accounts.payer.to_account_info(),
-            },
-        ),
-        fee_usd_micro,
-        accounts.mint.decimals,
-    )?;
+/// Transfer SOL fees with optional platform fee split
+/// Used for registration, renewal, claim operations
+pub fn transfer_sol_fees_with_platform(
+    accounts: SolFeeAccounts,
+    revenue_split: &RevenueSplit,
+    fee_lamports: u64,
+    platform_fee_bps: u16,
+) -> Result<u64> {
+    let (revenue_amount, platform_amount) = calculate_platform_split(fee_lamports, platform_fee_bps);
 
-    Ok(())
+    transfer_sol_split(accounts, revenue_split, revenue_amount, platform_amount)?;
+
+    Ok(platform_amount)
 }
 
-/// Transfer stablecoin fee for update_mint (no discount)
-pub fn transfer_stablecoin_update_fee<'info>(
-    owner: &Signer<'info>,
-    owner_token_account: &InterfaceAccount<'info, TokenAccount>,
-    vault: &InterfaceAccount<'info, TokenAccount>,
-    mint: &InterfaceAccount<'info, Mint>,
-    token_program: &Interface<'info, TokenInterface>,
-    fee: &UpdateFeeBreakdown,
+/// Transfer an already-split SOL fee to the protocol and optional platform
+/// Used directly by batch instructions that aggregate per-item splits.
+/// Protocol revenue is divided by the revenue split: the keeper share goes to the
+/// keeper vault, burn and staker shares to the WSOL revenue vault. The platform
+/// share must go to the registered platform's payout wallet.
+pub fn transfer_sol_split(
+    accounts: SolFeeAccounts,
+    revenue_split: &RevenueSplit,
+    revenue_amount: u64,
+    platform_amount: u64,
 ) -> Result<()> {
-    // Note: fee_usd_micro equals token amount for 6-decimal stablecoins at $1 peg
-    transfer_checked(
-        CpiContext::new(
-            token_program.to_account_info(),
-            TransferChecked {
-                from: owner_token_account.to_account_info(),
-                mint: mint.to_account_info(),
-                to: vault.to_account_info(),
-                authority: owner.to_account_info(),
-            },
-        ),
-        fee.fee_usd_micro,
-        mint.decimals,
-    )?;
-    Ok(())
-}
+    let shares = revenue_split.split(revenue_amount);
 
-// ============================================================================
-// Platform Fee Support
-// ============================================================================
+    // Transfer to treasury (fee collector)
+    transfer_lamports(accounts.system_program, accounts.payer, accounts.fee_collector, shares.treasury)?;
 
-/// Calculate platform fee from total fee
-/// Returns (treasury_amount, platform_amount)
-pub fn calculate_platform_split(total_amount: u64, platform_fee_bps: u16) -> (u64, u64) {
-    if platform_fee_bps == 0 {
-        return (total_amount, 0);
+    if shares.keeper > 0 {
+        let keeper_vault = accounts.keeper_vault.ok_or(TnsError::MissingRevenueAccount)?;
+        transfer_lamports(
+            accounts.system_program,
+            accounts.payer,
+            &keeper_vault.to_account_info(),
+            shares.keeper,
+        )?;
     }
-    let platform_amount = total_amount * platform_fee_bps as u64 / 10000;
-    let treasury_amount = total_amount - platform_amount;
-    (treasury_amount, platform_amount)
-}
 
-/// Transfer SOL fees with optional platform fee split
-/// Used for registration, renewal, claim operations
-pub fn transfer_sol_fees_with_platform<'info>(
-    payer: &AccountInfo<'info>,
-    fee_collector: &AccountInfo<'info>,
-    platform_fee_account: Option<&AccountInfo<'info>>,
-    system_program: &AccountInfo<'info>,
-    fee_lamports: u64,
-    platform_fee_bps: u16,
-) -> Result<u64> {
-    let (treasury_amount, platform_amount) = calculate_platform_split(fee_lamports, platform_fee_bps);
+    // Burn and staker shares are held as lamports until bought back or distributed
+    let held = shares.burn + shares.staker;
+    if held > 0 {
+        let revenue_vault = accounts.revenue_vault.ok_or(TnsError::MissingRevenueAccount)?;
+        require_keys_eq!(revenue_vault.mint, WSOL_MINT, TnsError::InvalidRevenueVault);
 
-    // Transfer to treasury (fee collector)
-    anchor_lang::system_program::transfer(
-        CpiContext::new(
-            system_program.clone(),
-            anchor_lang::system_program::Transfer {
-                from: payer.clone(),
-                to: fee_collector.clone(),
-            },
-        ),
-        treasury_amount,
-    )?;
+        transfer_lamports(
+            accounts.system_program,
+            accounts.payer,
+            &revenue_vault.to_account_info(),
+            held,
+        )?;
+        revenue_vault.record(&RevenueShares { keeper: 0, ..shares })?;
+    }
+
+    if shares.non_treasury() > 0 {
+        emit!(RevenueDistributed {
+            mint: WSOL_MINT,
+            treasury: shares.treasury,
+            keeper: shares.keeper,
+            burn: shares.burn,
+            staker: shares.staker,
+        });
+    }
 
     // Transfer platform fee if applicable
     if platform_amount > 0 {
-        if let Some(platform) = platform_fee_account {
-            anchor_lang::system_program::transfer(
-                CpiContext::new(
-                    system_program.clone(),
-                    anchor_lang::system_program::Transfer {
-                        from: payer.clone(),
-                        to: platform.clone(),
-                    },
-                ),
-                platform_amount,
-            )?;
-        }
+        let platform = accounts.platform.ok_or(TnsError::PlatformRequired)?;
+        let payout = accounts.platform_fee_account.ok_or(TnsError::InvalidPlatformPayout)?;
+        require_keys_eq!(payout.key(), platform.payout_wallet, TnsError::InvalidPlatformPayout);
+
+        transfer_lamports(accounts.system_program, accounts.payer, payout, platform_amount)?;
     }
 
-    Ok(platform_amount)
+    Ok(())
 }
 
-/// Accounts needed for token fee transfers with platform fee split
+/// Accounts needed for token fee transfers with platform fee and revenue split
 pub struct PlatformTokenFeeAccounts<'a, 'info> {
-    pub payer: &'a Signer<'info>,
+    /// Authority of payer_token_account: a signing wallet, or a PDA signing with payer_seeds
+    pub payer: &'a AccountInfo<'info>,
+    /// PDA seeds when the payer is a program account (empty for wallets)
+    pub payer_seeds: &'a [&'a [&'a [u8]]],
     pub payer_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    /// Treasury vault for the payment mint
     pub vault: &'a InterfaceAccount<'info, TokenAccount>,
-    pub platform_token_account: Option<&'a AccountInfo<'info>>,
+    pub platform: Option<&'a Account<'info, Platform>>,
+    pub platform_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    /// Revenue vault for the payment mint, receives the keeper, burn and staker shares
+    pub revenue_vault: Option<&'a mut Account<'info, RevenueVault>>,
+    /// Revenue vault token account (owned by revenue_vault)
+    pub revenue_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub revenue_split: RevenueSplit,
     pub mint: &'a InterfaceAccount<'info, Mint>,
     pub token_program: &'a Interface<'info, TokenInterface>,
 }
 
+/// Result of a token fee transfer
+pub struct TokenFeeTransfer {
+    /// Platform share of the quoted amount
+    pub platform_fee: u64,
+    /// Amount debited from the payer, including any Token-2022 transfer fees
+    pub charged_amount: u64,
+}
+
 /// Transfer token fees with optional platform fee split
 /// Used for USDC, USDT, TNS operations
-/// Note: platform_token_account is unchecked - if platform provides wrong account, they lose their fees
-pub fn transfer_token_fees_with_platform<'info>(
-    accounts: &PlatformTokenFeeAccounts<'_, 'info>,
+/// Protocol revenue is divided by the revenue split. The TNS burn share is burned
+/// from the payer directly; every other non-treasury share goes to the revenue
+/// vault for the mint. The platform share must go to a token account of the
+/// payment mint owned by the registered platform's payout wallet.
+/// Each transfer is grossed up for Token-2022 transfer fees so every recipient
+/// receives its full share of the quoted amount.
+pub fn transfer_token_fees_with_platform(
+    accounts: &mut PlatformTokenFeeAccounts,
     token_amount: u64,
     platform_fee_bps: u16,
-) -> Result<u64> {
-    let (treasury_amount, platform_amount) = calculate_platform_split(token_amount, platform_fee_bps);
+) -> Result<TokenFeeTransfer> {
+    validate_payment_mint_extensions(accounts.mint)?;
+
+    let (revenue_amount, platform_amount) = calculate_platform_split(token_amount, platform_fee_bps);
+    let shares = accounts.revenue_split.split(revenue_amount);
+    let mint = accounts.mint.key();
 
     // Transfer to treasury vault
+    let mut charged_amount = transfer_net_amount(accounts, &accounts.vault.to_account_info(), shares.treasury)?;
+
+    if shares.non_treasury() > 0 {
+        let (burned, held) = if mint == TNS_MINT {
+            (shares.burn, shares.keeper + shares.staker)
+        } else {
+            (0, shares.non_treasury())
+        };
+
+        let (revenue_vault_key, revenue_vault_mint) = accounts
+            .revenue_vault
+            .as_deref()
+            .map(|vault| (vault.key(), vault.mint))
+            .ok_or(TnsError::MissingRevenueAccount)?;
+        require_keys_eq!(revenue_vault_mint, mint, TnsError::InvalidRevenueVault);
+
+        if held > 0 {
+            let revenue_token_account = accounts.revenue_token_account.ok_or(TnsError::MissingRevenueAccount)?;
+            require_keys_eq!(revenue_token_account.owner, revenue_vault_key, TnsError::InvalidRevenueVault);
+
+            charged_amount += transfer_net_amount(accounts, &revenue_token_account.to_account_info(), held)?;
+        }
+
+        if burned > 0 {
+            burn(
+                CpiContext::new_with_signer(
+                    accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: accounts.mint.to_account_info(),
+                        from: accounts.payer_token_account.to_account_info(),
+                        authority: accounts.payer.to_account_info(),
+                    },
+                    accounts.payer_seeds,
+                ),
+                burned,
+            )?;
+
+            charged_amount += burned;
+
+            emit!(TnsBurned {
+                source_mint: mint,
+                amount_in: burned,
+                tns_burned: burned,
+                burned_by: accounts.payer.key(),
+                burned_at: Clock::get()?.unix_timestamp,
+            });
+        }
+
+        let revenue_vault = accounts.revenue_vault.as_deref_mut().ok_or(TnsError::MissingRevenueAccount)?;
+        revenue_vault.total_tns_burned = revenue_vault.total_tns_burned.saturating_add(burned);
+        revenue_vault.record(&RevenueShares { burn: shares.burn - burned, ..shares })?;
+
+        emit!(RevenueDistributed {
+            mint,
+            treasury: shares.treasury,
+            keeper: shares.keeper,
+            burn: shares.burn,
+            staker: shares.staker,
+        });
+    }
+
+    // Transfer platform fee if applicable
+    if platform_amount > 0 {
+        let platform = accounts.platform.ok_or(TnsError::PlatformRequired)?;
+        let payout = accounts.platform_token_account.ok_or(TnsError::InvalidPlatformPayout)?;
+        require_keys_eq!(payout.owner, platform.payout_wallet, TnsError::InvalidPlatformPayout);
+        require_keys_eq!(payout.mint, mint, TnsError::InvalidPlatformPayout);
+
+        charged_amount += transfer_net_amount(accounts, &payout.to_account_info(), platform_amount)?;
+    }
+
+    Ok(TokenFeeTransfer {
+        platform_fee: platform_amount,
+        charged_amount,
+    })
+}
+
+/// Transfer from the payer so that `destination` receives exactly `net_amount`
+/// Returns the amount debited, grossed up for any Token-2022 transfer fee.
+fn transfer_net_amount<'info>(
+    accounts: &PlatformTokenFeeAccounts<'_, 'info>,
+    destination: &AccountInfo<'info>,
+    net_amount: u64,
+) -> Result<u64> {
+    if net_amount == 0 {
+        return Ok(0);
+    }
+
+    let gross_amount = gross_up_for_transfer_fee(accounts.mint, net_amount)?;
+
     transfer_checked(
-        CpiContext::new(
+        CpiContext::new_with_signer(
             accounts.token_program.to_account_info(),
             TransferChecked {
                 from: accounts.payer_token_account.to_account_info(),
                 mint: accounts.mint.to_account_info(),
-                to: accounts.vault.to_account_info(),
+                to: destination.clone(),
                 authority: accounts.payer.to_account_info(),
             },
+            accounts.payer_seeds,
         ),
-        treasury_amount,
+        gross_amount,
         accounts.mint.decimals,
     )?;
 
-    // Transfer platform fee if applicable
-    if platform_amount > 0 {
-        if let Some(platform) = accounts.platform_token_account {
-            transfer_checked(
-                CpiContext::new(
-                    accounts.token_program.to_account_info(),
-                    TransferChecked {
-                        from: accounts.payer_token_account.to_account_info(),
-                        mint: accounts.mint.to_account_info(),
-                        to: platform.clone(),
-                        authority: accounts.payer.to_account_info(),
-                    },
-                ),
-                platform_amount,
-                accounts.mint.decimals,
-            )?;
+    Ok(gross_amount)
+}
+
+/// Amount to send so the recipient receives `net_amount` after the mint's
+/// Token-2022 transfer fee for the current epoch
+/// SPL Token mints and Token-2022 mints without a TransferFeeConfig return `net_amount`.
+pub fn gross_up_for_transfer_fee(mint: &InterfaceAccount<Mint>, net_amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    if mint_info.owner != &anchor_spl::token_2022::ID {
+        return Ok(net_amount);
+    }
+
+    let data = mint_info.data.borrow();
+    let mint_with_ext = StateWithExtensions::<Token2022Mint>::unpack(&data)
+        .map_err(|_| TnsError::InvalidMint)?;
+    let Ok(transfer_fee_config) = mint_with_ext.get_extension::<TransferFeeConfig>() else {
+        return Ok(net_amount);
+    };
+
+    transfer_fee_config
+        .get_epoch_fee(Clock::get()?.epoch)
+        .calculate_pre_fee_amount(net_amount)
+        .ok_or(error!(TnsError::MathOverflow))
+}
+
+/// Amount transfer_token_fees_with_platform will debit for a token fee
+/// Each transferred share is grossed up for Token-2022 transfer fees; a TNS
+/// burn share is burned from the payer as is.
+pub fn calculate_token_fee_charge(
+    mint: &InterfaceAccount<Mint>,
+    revenue_split: &RevenueSplit,
+    token_amount: u64,
+    platform_fee_bps: u16,
+) -> Result<u64> {
+    let (revenue_amount, platform_amount) = calculate_platform_split(token_amount, platform_fee_bps);
+    let shares = revenue_split.split(revenue_amount);
+    let (burned, held) = if mint.key() == TNS_MINT {
+        (shares.burn, shares.keeper + shares.staker)
+    } else {
+        (0, shares.non_treasury())
+    };
+
+    let mut charged_amount = burned;
+    for net_amount in [shares.treasury, held, platform_amount] {
+        if net_amount > 0 {
+            charged_amount = charged_amount
+                .checked_add(gross_up_for_transfer_fee(mint, net_amount)?)
+                .ok_or(TnsError::MathOverflow)?;
         }
     }
 
-    Ok(platform_amount)
+    Ok(charged_amount)
+}
+
+/// Charge a quoted token fee with slippage protection and optional platform split
+/// Rejects the quote, or the amount debited once grossed up for Token-2022
+/// transfer fees, if it exceeds the payer's max_token_amount, then emits
+/// TokenFeeCharged with the quoted amount and the amount actually debited.
+/// Returns the platform fee.
+pub fn charge_token_fee(
+    mut accounts: PlatformTokenFeeAccounts,
+    token_account: Pubkey,
+    quoted_amount: u64,
+    max_token_amount: u64,
+    platform_fee_bps: u16,
+) -> Result<u64> {
+    validate_slippage(quoted_amount, max_token_amount)?;
+
+    let transfer = transfer_token_fees_with_platform(&mut accounts, quoted_amount, platform_fee_bps)?;
+    validate_slippage(transfer.charged_amount, max_token_amount)?;
+
+    emit!(TokenFeeCharged {
+        token_account,
+        payer: accounts.payer.key(),
+        mint: accounts.mint.key(),
+        quoted_amount,
+        max_token_amount,
+        charged_amount: transfer.charged_amount,
+        transfer_fee: transfer.charged_amount.saturating_sub(quoted_amount),
+    });
+
+    Ok(transfer.platform_fee)
+}
+
+// ============================================================================
+// Keeper Vault
+// ============================================================================
+
+/// Deposit the keeper reward for a new registration into the keeper vault
+/// Returns the deposited amount, to be recorded on the Token account
+pub fn deposit_keeper_reward<'info>(
+    payer: &AccountInfo<'info>,
+    keeper_vault: &mut Account<'info, KeeperVault>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    if amount > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: keeper_vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    keeper_vault.record_deposit(amount)?;
+
+    Ok(amount)
+}
+
+/// Pay a symbol's recorded keeper deposit from the keeper vault
+/// Fails rather than underpaying if the vault cannot cover the deposit above rent
+pub fn pay_keeper_deposit<'info>(
+    keeper_vault: &mut Account<'info, KeeperVault>,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    keeper_vault.record_payout(amount)?;
+    transfer_from_keeper_vault(keeper_vault, recipient, amount)?;
+
+    Ok(amount)
+}
+
+/// Record the reward owed to the keeper closing a symbol
+/// A recorded deposit is owed in full; symbols without one (legacy and seeded)
+/// are owed legacy_reward from the keeper vault's legacy reserve while it lasts
+pub fn record_keeper_reward(
+    keeper_vault: &mut KeeperVault,
+    keeper_deposit: u64,
+    legacy_reward: u64,
+) -> Result<u64> {
+    if keeper_deposit > 0 {
+        keeper_vault.record_payout(keeper_deposit)?;
+        Ok(keeper_deposit)
+    } else {
+        Ok(keeper_vault.release_legacy_reward(legacy_reward))
+    }
+}
+
+/// Pay the keeper closing a symbol from the keeper vault (see record_keeper_reward)
+pub fn pay_keeper_reward<'info>(
+    keeper_vault: &mut Account<'info, KeeperVault>,
+    keeper: &AccountInfo<'info>,
+    keeper_deposit: u64,
+    legacy_reward: u64,
+) -> Result<u64> {
+    let amount = record_keeper_reward(keeper_vault, keeper_deposit, legacy_reward)?;
+    transfer_from_keeper_vault(keeper_vault, keeper, amount)?;
+
+    Ok(amount)
+}
+
+/// Move already-recorded lamports out of the keeper vault
+/// Fails rather than underpaying if the vault cannot cover them above rent
+pub fn transfer_from_keeper_vault<'info>(
+    keeper_vault: &Account<'info, KeeperVault>,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let vault_info = keeper_vault.to_account_info();
+    let min_rent = Rent::get()?.minimum_balance(vault_info.data_len());
+    let available = vault_info.lamports().saturating_sub(min_rent);
+    require!(available >= amount, TnsError::KeeperVaultInsufficient);
+
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    **recipient.try_borrow_mut_lamports()? += amount;
+
+    Ok(())
+}
+
+// ============================================================================
+// Bond Vault
+// ============================================================================
+
+/// Lock a registration bond in the bond vault
+/// Returns the bonded amount, to be recorded on the Token account
+pub fn deposit_bond<'info>(
+    payer: &AccountInfo<'info>,
+    bond_vault: Option<&mut Account<'info, BondVault>>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    let bond_vault = bond_vault.ok_or(TnsError::MissingBondVault)?;
+
+    if amount > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: bond_vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    bond_vault.record_bond(amount)?;
+
+    Ok(amount)
+}
+
+/// Refund a symbol's recorded bond from the bond vault
+pub fn refund_bond<'info>(
+    bond_vault: Option<&mut Account<'info, BondVault>>,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    let bond_vault = bond_vault.ok_or(TnsError::MissingBondVault)?;
+    bond_vault.record_refund(amount)?;
+    transfer_from_bond_vault(bond_vault, recipient, amount)?;
+
+    Ok(amount)
+}
+
+/// Refund a symbol's keeper deposit and bond to its outgoing owner on an ownership change
+/// Both were paid when the outgoing owner registered or claimed the symbol, so the
+/// new owner takes it over without them. Returns (deposit_refunded, bond_refunded).
+pub fn refund_owner_deposits<'info>(
+    token_account: &mut Token,
+    keeper_vault: &mut Account<'info, KeeperVault>,
+    bond_vault: Option<&mut Account<'info, BondVault>>,
+    old_owner: &AccountInfo<'info>,
+) -> Result<(u64, u64)> {
+    let deposit_refunded = pay_keeper_deposit(keeper_vault, old_owner, token_account.keeper_deposit)?;
+    let bond_refunded = refund_bond(bond_vault, old_owner, token_account.bond_lamports)?;
+
+    token_account.keeper_deposit = 0;
+    token_account.bond_lamports = 0;
+
+    Ok((deposit_refunded, bond_refunded))
+}
+
+/// Forfeit a symbol's recorded bond, paying the keeper share to `keeper`
+/// The rest stays in the vault as surplus for the treasury (see sweep_bond_vault).
+/// Returns the keeper share paid.
+pub fn forfeit_bond<'info>(
+    bond_vault: Option<&mut Account<'info, BondVault>>,
+    keeper: &AccountInfo<'info>,
+    amount: u64,
+    bond_policy: &BondPolicy,
+) -> Result<u64> {
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    let bond_vault = bond_vault.ok_or(TnsError::MissingBondVault)?;
+    bond_vault.record_forfeit(amount)?;
+
+    let keeper_share = bond_policy.keeper_share(amount);
+    transfer_from_bond_vault(bond_vault, keeper, keeper_share)?;
+
+    Ok(keeper_share)
+}
+
+/// Forfeit a symbol's recorded bond entirely to the treasury (no keeper share)
+/// The bond vault is only required when the symbol has a bond.
+pub fn record_bond_forfeit(bond_vault: Option<&mut Account<'_, BondVault>>, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    bond_vault.ok_or(TnsError::MissingBondVault)?.record_forfeit(amount)
+}
+
+/// Move already-recorded lamports out of the bond vault
+/// Fails rather than underpaying if the vault cannot cover the amount above rent
+pub fn transfer_from_bond_vault<'info>(
+    bond_vault: &Account<'info, BondVault>,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let vault_info = bond_vault.to_account_info();
+    let min_rent = Rent::get()?.minimum_balance(vault_info.data_len());
+    let available = vault_info.lamports().saturating_sub(min_rent);
+    require!(available >= amount, TnsError::BondVaultInsufficient);
+
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    **recipient.try_borrow_mut_lamports()? += amount;
+
+    Ok(())
+}
+
+// ============================================================================
+// Escrow Transfers
+// ============================================================================
+
+/// Accounts needed to release a PDA-owned escrow back to its depositor
+pub struct EscrowReleaseAccounts<'a, 'info> {
+    pub escrow: &'a InterfaceAccount<'info, TokenAccount>,
+    pub escrow_authority: &'a AccountInfo<'info>,
+    pub destination_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub rent_destination: &'a AccountInfo<'info>,
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+/// Transfer the full escrow balance to the depositor and close the escrow account
+/// escrow_authority must be the PDA described by signer_seeds
+pub fn release_escrow<'info>(
+    accounts: &EscrowReleaseAccounts<'_, 'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u64> {
+    let amount = accounts.escrow.amount;
+
+    if amount > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.escrow.to_account_info(),
+                    mint: accounts.mint.to_account_info(),
+                    to: accounts.destination_token_account.to_account_info(),
+                    authority: accounts.escrow_authority.clone(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            accounts.mint.decimals,
+        )?;
+    }
+
+    close_account(CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: accounts.escrow.to_account_info(),
+            destination: accounts.rent_destination.clone(),
+            authority: accounts.escrow_authority.clone(),
+        },
+        signer_seeds,
+    ))?;
+
+    Ok(amount)
 }
 
 // ============================================================================
@@ -373,71 +774,309 @@ pub fn transfer_token_fees_with_platform<'info>(
 // ============================================================================
 
 /// Get SOL/USD price from Pyth pull oracle in micro-cents (1 USD = 1_000_000 micro-cents)
-/// Uses PriceUpdateV2 from pyth-solana-receiver-sdk (ownership verified automatically)
-/// Accepts partial verification (min 3 guardian signatures) to fit in a single transaction
-pub fn get_sol_price_micro(price_update: &Account<PriceUpdateV2>) -> Result<u64> {
-    use pyth_solana_receiver_sdk::price_update::VerificationLevel;
+/// The feed ID, staleness and verification level come from OracleConfig, and the
+/// price must pass its circuit-breaker guards
+pub fn get_sol_price_micro(
+    oracle_config: &OracleConfig,
+    price_update: &AccountInfo,
+    current_time: i64,
+) -> Result<u64> {
+    require!(!oracle_config.sol_circuit_tripped, TnsError::SolPriceCircuitTripped);
+
+    let price_update = load_price_update(oracle_config, price_update)?;
+    let price = read_pyth_price(oracle_config, &price_update, &oracle_config.sol_usd_feed_id)?;
+
+    if let Some(violation) = sol_price_guard_violation(oracle_config, &price, current_time) {
+        return Err(error!(violation));
+    }
+
+    Ok(price_to_micro(&price))
+}
+
+/// A SOL/USD price and the provider it came from
+pub struct SolPrice {
+    pub price_micro: u64,
+    pub provider: PriceProvider,
+}
+
+/// Get SOL/USD price in micro-cents, falling back to the admin-posted price
+/// when the Pyth update is stale
+/// The update is always required, so a fresh Pyth price takes precedence. Any
+/// other bad update (wrong owner or feed, insufficient verification) and a
+/// readable Pyth price that violates a guard still fail; they never fall back,
+/// so a payer can't pick whichever price is cheaper.
+pub fn get_sol_price(
+    oracle_config: &OracleConfig,
+    price_update: &AccountInfo,
+    current_time: i64,
+) -> Result<SolPrice> {
+    require!(!oracle_config.sol_circuit_tripped, TnsError::SolPriceCircuitTripped);
+
+    let price_update = load_price_update(oracle_config, price_update)?;
+    let pyth_price = match read_pyth_price(oracle_config, &price_update, &oracle_config.sol_usd_feed_id) {
+        Err(err) if err != TnsError::StalePriceFeed.into() => return Err(err),
+        result => result,
+    };
+
+    let price = match pyth_price {
+        Ok(price) => price,
+        Err(err) => {
+            let price_micro = oracle_config.live_fallback_sol_price(current_time).ok_or(err)?;
+            return Ok(SolPrice {
+                price_micro,
+                provider: PriceProvider::Fallback,
+            });
+        }
+    };
+
+    if let Some(violation) = sol_price_guard_violation(oracle_config, &price, current_time) {
+        return Err(error!(violation));
+    }
+
+    Ok(SolPrice {
+        price_micro: price_to_micro(&price),
+        provider: PriceProvider::Pyth,
+    })
+}
+
+/// Check a SOL/USD price against the OracleConfig guards
+/// Returns the error of the first violated guard (confidence, bounds, deviation)
+pub fn sol_price_guard_violation(oracle_config: &OracleConfig, price: &Price, current_time: i64) -> Option<TnsError> {
+    if !confidence_within_limit(oracle_config, price) {
+        return Some(TnsError::PriceConfidenceTooWide);
+    }
+
+    sol_price_range_violation(oracle_config, price_to_micro(price), current_time)
+}
+
+/// Check a SOL/USD price in micro-cents against the bounds and deviation guards
+/// The deviation guard only applies while the last good price is recent enough
+pub fn sol_price_range_violation(oracle_config: &OracleConfig, price_micro: u64, current_time: i64) -> Option<TnsError> {
+    let below_min = oracle_config.min_sol_price_usd_micro > 0 && price_micro < oracle_config.min_sol_price_usd_micro;
+    let above_max = oracle_config.max_sol_price_usd_micro > 0 && price_micro > oracle_config.max_sol_price_usd_micro;
+    if below_min || above_max {
+        return Some(TnsError::SolPriceOutOfBounds);
+    }
+
+    let last_good = oracle_config.anchoring_sol_price(current_time).unwrap_or(0);
+    if oracle_config.max_deviation_bps > 0 && last_good > 0 {
+        let deviation_bps = price_micro.abs_diff(last_good) as u128 * 10000 / last_good as u128;
+        if deviation_bps > oracle_config.max_deviation_bps as u128 {
+            return Some(TnsError::SolPriceDeviationTooLarge);
+        }
+    }
+
+    None
+}
+
+/// Deserialize a Pyth PriceUpdateV2 account
+/// The account must be owned by OracleConfig.price_update_owner
+pub fn load_price_update(oracle_config: &OracleConfig, price_update: &AccountInfo) -> Result<PriceUpdateV2> {
+    require!(
+        *price_update.owner == oracle_config.price_update_owner,
+        TnsError::InvalidPriceUpdateOwner
+    );
+
+    let data = price_update.try_borrow_data()?;
+    PriceUpdateV2::try_deserialize(&mut &data[..]).map_err(|_| error!(TnsError::InvalidPriceFeed))
+}
+
+/// Get a USD price from a Pyth pull oracle feed in micro-cents
+/// Enforces OracleConfig staleness and minimum verification level
+pub fn get_pyth_price_micro(
+    oracle_config: &OracleConfig,
+    price_update: &AccountInfo,
+    feed_id: &[u8; 32],
+) -> Result<u64> {
+    let price_update = load_price_update(oracle_config, price_update)?;
+    read_pyth_price_micro(oracle_config, &price_update, feed_id)
+}
+
+/// Read a feed from an already loaded price update, in micro-cents
+/// Rejects prices whose confidence interval exceeds OracleConfig.max_confidence_bps
+fn read_pyth_price_micro(
+    oracle_config: &OracleConfig,
+    price_update: &PriceUpdateV2,
+    feed_id: &[u8; 32],
+) -> Result<u64> {
+    let price = read_pyth_price(oracle_config, price_update, feed_id)?;
+    require!(confidence_within_limit(oracle_config, &price), TnsError::PriceConfidenceTooWide);
+
+    Ok(price_to_micro(&price))
+}
+
+/// Read a positive price from a loaded update, enforcing staleness and verification level
+pub fn read_pyth_price(
+    oracle_config: &OracleConfig,
+    price_update: &PriceUpdateV2,
+    feed_id: &[u8; 32],
+) -> Result<Price> {
     let price = price_update.get_price_no_older_than_with_custom_verification_level(
         &Clock::get()?,
-        MAX_PRICE_STALENESS_SECONDS,
-        &SOL_USD_FEED_ID,
-        VerificationLevel::Partial { num_signatures: 3 },
-    ).map_err(|_| error!(TnsError::StalePriceFeed))?;
+        oracle_config.max_staleness_seconds,
+        feed_id,
+        oracle_config.min_verification.into(),
+    ).map_err(|err| match err {
+        GetPriceError::PriceTooOld => error!(TnsError::StalePriceFeed),
+        GetPriceError::MismatchedFeedId => error!(TnsError::PriceFeedMismatch),
+        GetPriceError::InsufficientVerificationLevel => error!(TnsError::InsufficientPriceVerification),
+        _ => error!(TnsError::InvalidPriceFeed),
+    })?;
 
     // Ensure price is positive
     require!(price.price > 0, TnsError::InvalidPriceFeed);
 
+    Ok(price)
+}
+
+/// Whether a price's confidence interval is within max_confidence_bps of the price
+fn confidence_within_limit(oracle_config: &OracleConfig, price: &Price) -> bool {
+    oracle_config.max_confidence_bps == 0
+        || price.conf as u128 * 10000 <= price.price as u128 * oracle_config.max_confidence_bps as u128
+}
+
+/// Convert a positive Pyth price to micro-cents
+pub fn price_to_micro(price: &Price) -> u64 {
     // Convert to micro-cents (6 decimal places)
     // Pyth SOL/USD typically has exponent -8, so price of $200 = 20000000000 * 10^-8
     // We want 200_000_000 micro-cents
     let price_value = price.price as i128;
     let target_exp = price.exponent + 6;
-    let sol_price_micro = if target_exp >= 0 {
+    if target_exp >= 0 {
         (price_value * 10i128.pow(target_exp as u32)) as u64
     } else {
         (price_value / 10i128.pow((-target_exp) as u32)) as u64
+    }
+}
+
+/// Get a USD stablecoin's price from its Pyth feed in micro-cents
+/// Rejects the stablecoin when it trades outside the configured band around $1
+pub fn get_stablecoin_price_micro(
+    config: &Config,
+    oracle_config: &OracleConfig,
+    price_update: &AccountInfo,
+    feed_id: &[u8; 32],
+) -> Result<u64> {
+    let price_micro = get_pyth_price_micro(oracle_config, price_update, feed_id)?;
+
+    let band = STABLECOIN_MULTIPLIER * config.get_stablecoin_depeg_band() as u64 / 10000;
+    require!(
+        price_micro.abs_diff(STABLECOIN_MULTIPLIER) <= band,
+        TnsError::StablecoinDepegged
+    );
+
+    Ok(price_micro)
+}
+
+/// Accounts needed to read the TNS/USD spot price
+pub struct TnsSpotPriceAccounts<'a, 'info> {
+    pub price_sources: &'a Account<'info, TnsPriceSources>,
+    pub oracle_config: &'a OracleConfig,
+    /// Pyth SOL/USD price update, required when pools are configured (pool sources are quoted in SOL)
+    pub price_update: Option<&'a AccountInfo<'info>>,
+    /// Pyth TNS/USD price update, required when Config.tns_usd_pyth_feed is set
+    pub tns_usd_price_update: Option<&'a AccountInfo<'info>>,
+    /// Accounts for each configured pool, in order
+    pub pool_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Get the TNS/USD spot price in micro-cents
+/// Median of every configured pool and the Pyth TNS/USD feed (when set);
+/// fails if any source cannot be read or fewer than min_sources price TNS
+pub fn get_tns_spot_price_micro(config: &Config, accounts: &TnsSpotPriceAccounts) -> Result<u64> {
+    let sources = accounts.price_sources;
+    require!(
+        accounts.pool_accounts.len() == sources.pool_account_count(),
+        TnsError::InvalidPoolReserve
+    );
+
+    let mut prices = Vec::with_capacity(sources.pools.len() + 1);
+
+    if let Some(feed) = config.tns_usd_pyth_feed {
+        let tns_usd_price_update = accounts.tns_usd_price_update.ok_or(TnsError::MissingPriceAccount)?;
+        require!(tns_usd_price_update.key() == feed, TnsError::InvalidPriceFeed);
+
+        // The account address is pinned by config, so its own feed id is trusted
+        let tns_usd_price_update = load_price_update(accounts.oracle_config, tns_usd_price_update)?;
+        let feed_id = tns_usd_price_update.price_message.feed_id;
+        prices.push(read_pyth_price_micro(accounts.oracle_config, &tns_usd_price_update, &feed_id)?);
+    }
+
+    if !sources.pools.is_empty() {
+        let price_update = accounts.price_update.ok_or(TnsError::MissingPriceAccount)?;
+        let sol_price_micro = get_sol_price(
+            accounts.oracle_config,
+            price_update,
+            Clock::get()?.unix_timestamp,
+        )?.price_micro;
+
+        let mut remaining = accounts.pool_accounts;
+        for pool in &sources.pools {
+            let (pool_accounts, rest) = remaining.split_at(pool.account_count());
+            remaining = rest;
+            prices.push(get_tns_price_from_pool(pool, pool_accounts, sol_price_micro)?);
+        }
+    }
+
+    require!(
+        !prices.is_empty() && prices.len() >= sources.min_sources as usize,
+        TnsError::InsufficientPriceSources
+    );
+
+    prices.sort_unstable();
+    let mid = prices.len() / 2;
+    let median = if prices.len() % 2 == 0 {
+        ((prices[mid - 1] as u128 + prices[mid] as u128) / 2) as u64
+    } else {
+        prices[mid]
     };
 
-    Ok(sol_price_micro)
+    require!(median > 0, TnsError::EmptyPoolReserves);
+
+    Ok(median)
 }
 
-/// Get TNS/USD price from DEX pool reserves combined with Pyth SOL/USD price
+/// Get TNS/USD price from a constant-product TNS/SOL pool combined with Pyth SOL/USD price
 /// Returns price in micro-cents (1 USD = 1_000_000 micro-cents)
 ///
 /// Formula:
 ///   tns_price_sol = sol_reserve / tns_reserve (adjusted for decimals)
 ///   tns_price_usd = tns_price_sol * sol_price_usd
 pub fn get_tns_price_from_pool(
-    pool_tns_reserve: &AccountInfo,
-    pool_sol_reserve: &AccountInfo,
-    price_update: &Account<PriceUpdateV2>,
+    pool: &TnsPoolSource,
+    pool_accounts: &[AccountInfo],
+    sol_price_usd: u64,
 ) -> Result<u64> {
-    // Verify accounts match expected constants
-    require!(
-        pool_tns_reserve.key() == PUMP_POOL_TNS_RESERVE,
-        TnsError::InvalidPoolReserve
-    );
-    require!(
-        pool_sol_reserve.key() == PUMP_POOL_SOL_RESERVE,
-        TnsError::InvalidPoolReserve
-    );
+    let (tns_reserve, sol_reserve) = match pool.kind {
+        TnsPoolKind::VaultPair => {
+            let [tns_vault, sol_vault] = pool_accounts else {
+                return err!(TnsError::InvalidPoolReserve);
+            };
+            require!(tns_vault.key() == pool.tns_vault, TnsError::InvalidPoolReserve);
+            require!(sol_vault.key() == pool.sol_vault, TnsError::InvalidPoolReserve);
 
-    // Read token account balances (SPL Token account layout: amount is at offset 64, 8 bytes)
-    let tns_data = pool_tns_reserve.try_borrow_data()?;
-    let sol_data = pool_sol_reserve.try_borrow_data()?;
+            (read_pool_reserve(tns_vault, &TNS_MINT)?, read_pool_reserve(sol_vault, &WSOL_MINT)?)
+        }
+        TnsPoolKind::RaydiumCpmm { pool_state } => {
+            let [state, tns_vault, sol_vault] = pool_accounts else {
+                return err!(TnsError::InvalidPoolReserve);
+            };
+            require!(state.key() == pool_state, TnsError::InvalidPoolReserve);
+            require!(tns_vault.key() == pool.tns_vault, TnsError::InvalidPoolReserve);
+            require!(sol_vault.key() == pool.sol_vault, TnsError::InvalidPoolReserve);
 
-    require!(tns_data.len() >= 72, TnsError::InvalidPoolReserve);
-    require!(sol_data.len() >= 72, TnsError::InvalidPoolReserve);
+            let (tns_fees, sol_fees) = read_raydium_cpmm_fees(state, &pool.tns_vault, &pool.sol_vault)?;
 
-    let tns_reserve = u64::from_le_bytes(tns_data[64..72].try_into().unwrap());
-    let sol_reserve = u64::from_le_bytes(sol_data[64..72].try_into().unwrap());
+            (
+                read_pool_reserve(tns_vault, &TNS_MINT)?.saturating_sub(tns_fees),
+                read_pool_reserve(sol_vault, &WSOL_MINT)?.saturating_sub(sol_fees),
+            )
+        }
+    };
 
     // Ensure non-zero reserves
     require!(tns_reserve > 0 && sol_reserve > 0, TnsError::EmptyPoolReserves);
 
-    // Get SOL/USD price from Pyth (returns micro USD, 6 decimals)
-    let sol_price_usd = get_sol_price_micro(price_update)?;
-
     // Calculate TNS price in USD micro
     // tns_price_usd = (sol_reserve / tns_reserve) * sol_price_usd
     //
@@ -460,38 +1099,165 @@ pub fn get_tns_price_from_pool(
         .checked_div(decimal_adjustment as u128)
         .ok_or(TnsError::MathOverflow)?;
 
-    Ok(tns_price_usd as u64)
+    u64::try_from(tns_price_usd).map_err(|_| error!(TnsError::MathOverflow))
 }
 
-/// Calculate TNS amount needed for a given USD amount using pool price
-///
-/// usd_amount: Amount in micro USD (6 decimals), e.g., 1_000_000 = $1
-/// Returns: TNS amount in smallest units (6 decimals)
-pub fn calculate_tns_for_usd(
-    usd_amount: u64,
-    pool_tns_reserve: &AccountInfo,
-    pool_sol_reserve: &AccountInfo,
-    price_update: &Account<PriceUpdateV2>,
+/// Accounts needed to price TNS-denominated fees
+pub struct TnsPriceAccounts<'a, 'info> {
+    pub price_observation: &'a Account<'info, TnsPriceObservation>,
+    pub spot: TnsSpotPriceAccounts<'a, 'info>,
+}
+
+/// Get the TNS/USD price used for TNS-denominated fees in micro-cents
+/// Returns the TWAP over the configured window; rejected while the spot
+/// price is further from it than the configured deviation
+pub fn get_tns_twap_price_micro(
+    config: &Config,
+    accounts: &TnsPriceAccounts,
+    current_time: i64,
 ) -> Result<u64> {
-    let tns_price_usd = get_tns_price_from_pool(
-        pool_tns_reserve,
-        pool_sol_reserve,
-        price_update,
-    )?;
+    let twap = accounts.price_observation
+        .twap(current_time, config.get_tns_twap_window())
+        .ok_or(TnsError::TwapUnavailable)?;
+    require!(twap > 0, TnsError::TwapUnavailable);
 
-    // Ensure we don't divide by zero
-    require!(tns_price_usd > 0, TnsError::EmptyPoolReserves);
+    let spot = get_tns_spot_price_micro(config, &accounts.spot)?;
 
-    // tns_needed = usd_amount * STABLECOIN_MULTIPLIER / tns_price_usd
-    // Both usd_amount and tns_price_usd are in micro (6 decimals)
-    // We multiply by 10^6 to get the token amount in 6 decimal units
-    let stablecoin_multiplier = 10u64.pow(STABLECOIN_DECIMALS as u32); // 10^6
+    let max_deviation = twap as u128 * config.get_tns_max_spot_deviation() as u128 / 10000;
+    require!(
+        spot.abs_diff(twap) as u128 <= max_deviation,
+        TnsError::SpotPriceDeviation
+    );
 
-    let tns_needed = (usd_amount as u128)
-        .checked_mul(stablecoin_multiplier as u128)
-        .ok_or(TnsError::MathOverflow)?
-        .checked_div(tns_price_usd as u128)
-        .ok_or(TnsError::MathOverflow)?;
+    Ok(twap)
+}
+
+/// Read a pool reserve token account balance
+/// The account must be an SPL Token or Token-2022 account for the expected mint
+fn read_pool_reserve(reserve: &AccountInfo, expected_mint: &Pubkey) -> Result<u64> {
+    require!(
+        *reserve.owner == anchor_spl::token::ID || *reserve.owner == anchor_spl::token_2022::ID,
+        TnsError::InvalidPoolReserve
+    );
+
+    let data = reserve.try_borrow_data()?;
+    let account = TokenAccount::try_deserialize(&mut &data[..])
+        .map_err(|_| error!(TnsError::InvalidPoolReserve))?;
+    require!(account.mint == *expected_mint, TnsError::InvalidPoolReserve);
+
+    Ok(account.amount)
+}
+
+/// Raydium CPMM PoolState field offsets (packed layout, after the 8-byte discriminator)
+const RAYDIUM_CPMM_TOKEN_0_VAULT_OFFSET: usize = 72;
+const RAYDIUM_CPMM_TOKEN_1_VAULT_OFFSET: usize = 104;
+const RAYDIUM_CPMM_PROTOCOL_FEES_OFFSET: usize = 341;
+const RAYDIUM_CPMM_FUND_FEES_OFFSET: usize = 357;
+
+/// Read the protocol and fund fees a Raydium CPMM pool has accrued in its vaults
+/// Returns (tns_fees, sol_fees); the pool's vaults must match the configured pair
+fn read_raydium_cpmm_fees(
+    pool_state: &AccountInfo,
+    tns_vault: &Pubkey,
+    sol_vault: &Pubkey,
+) -> Result<(u64, u64)> {
+    require!(*pool_state.owner == RAYDIUM_CPMM_PROGRAM_ID, TnsError::InvalidPoolReserve);
+
+    let data = pool_state.try_borrow_data()?;
+    require!(data.len() >= RAYDIUM_CPMM_FUND_FEES_OFFSET + 16, TnsError::InvalidPoolReserve);
 
-    Ok(tns_needed as u64)
-}
\ No newline at end of file
+    let read_pubkey = |offset: usize| Pubkey::try_from(&data[offset..offset + 32]).unwrap();
+    let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+    let vault_0 = read_pubkey(RAYDIUM_CPMM_TOKEN_0_VAULT_OFFSET);
+    let vault_1 = read_pubkey(RAYDIUM_CPMM_TOKEN_1_VAULT_OFFSET);
+
+    let fees_0 = read_u64(RAYDIUM_CPMM_PROTOCOL_FEES_OFFSET)
+        .saturating_add(read_u64(RAYDIUM_CPMM_FUND_FEES_OFFSET));
+    let fees_1 = read_u64(RAYDIUM_CPMM_PROTOCOL_FEES_OFFSET + 8)
+        .saturating_add(read_u64(RAYDIUM_CPMM_FUND_FEES_OFFSET + 8));
+
+    if vault_0 == *tns_vault && vault_1 == *sol_vault {
+        Ok((fees_0, fees_1))
+    } else if vault_0 == *sol_vault && vault_1 == *tns_vault {
+        Ok((fees_1, fees_0))
+    } else {
+        err!(TnsError::InvalidPoolReserve)
+    }
+}
+
+// ============================================================================
+// Payment Asset Pricing
+// ============================================================================
+
+/// Optional price accounts for a payment asset, as required by its price source
+pub struct AssetPriceAccounts<'a, 'info> {
+    pub config: &'a Config,
+    pub oracle_config: &'a OracleConfig,
+    /// Pyth feed for the asset (Pyth and Stablecoin sources) or SOL/USD (TnsTwap pools)
+    pub price_update: Option<&'a AccountInfo<'info>>,
+    /// TnsTwap source: TWAP samples
+    pub tns_price_observation: Option<&'a Account<'info, TnsPriceObservation>>,
+    /// TnsTwap source: configured spot price sources
+    pub tns_price_sources: Option<&'a Account<'info, TnsPriceSources>>,
+    /// TnsTwap source: Pyth TNS/USD update, required when Config.tns_usd_pyth_feed is set
+    pub tns_usd_price_update: Option<&'a AccountInfo<'info>>,
+    /// TnsTwap source: accounts for each configured pool, in order
+    pub pool_accounts: &'a [AccountInfo<'info>],
+    pub current_time: i64,
+}
+
+/// Get a payment asset's USD price per whole token in micro-cents
+pub fn get_asset_price_micro(
+    asset: &PaymentAsset,
+    accounts: &AssetPriceAccounts,
+) -> Result<u64> {
+    let price_micro = match asset.price_source {
+        PriceSource::FixedPeg { usd_micro } => usd_micro,
+        PriceSource::Pyth { feed_id } => {
+            let price_update = accounts.price_update.ok_or(TnsError::MissingPriceAccount)?;
+            get_pyth_price_micro(accounts.oracle_config, price_update, &feed_id)?
+        }
+        PriceSource::Stablecoin { feed_id } => {
+            let price_update = accounts.price_update.ok_or(TnsError::MissingPriceAccount)?;
+            get_stablecoin_price_micro(accounts.config, accounts.oracle_config, price_update, &feed_id)?
+        }
+        PriceSource::TnsTwap => {
+            let price_observation = accounts.tns_price_observation.ok_or(TnsError::MissingPriceAccount)?;
+            let price_sources = accounts.tns_price_sources.ok_or(TnsError::MissingPriceAccount)?;
+
+            get_tns_twap_price_micro(
+                accounts.config,
+                &TnsPriceAccounts {
+                    price_observation,
+                    spot: TnsSpotPriceAccounts {
+                        price_sources,
+                        oracle_config: accounts.oracle_config,
+                        price_update: accounts.price_update,
+                        tns_usd_price_update: accounts.tns_usd_price_update,
+                        pool_accounts: accounts.pool_accounts,
+                    },
+                },
+                accounts.current_time,
+            )?
+        }
+    };
+
+    require!(price_micro > 0, TnsError::InvalidPriceFeed);
+
+    Ok(price_micro)
+}
+
+/// Calculate the token amount owed in a payment asset for a USD fee
+/// Applies the asset's discount before converting at the asset's price
+pub fn calculate_asset_amount(
+    asset: &PaymentAsset,
+    fee_usd_micro: u64,
+    accounts: &AssetPriceAccounts,
+) -> Result<u64> {
+    let price_micro = get_asset_price_micro(asset, accounts)?;
+
+    asset
+        .usd_to_amount(asset.discounted_usd(fee_usd_micro), price_micro)
+        .ok_or(error!(TnsError::MathOverflow))
+}
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::Token;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use crate::{Token, MetadataCommitment, PriceLock, TnsError, MAX_BATCH_SIZE};
 
 /// Data needed to initialize a new symbol account
 pub struct SymbolInitData {
@@ -9,6 +12,10 @@ pub struct SymbolInitData {
     pub current_time: i64,
     pub expires_at: i64,
     pub bump: u8,
+    pub metadata_commitment: MetadataCommitment,
+    pub keeper_deposit: u64,
+    pub price_lock: PriceLock,
+    pub bond_lamports: u64,
 }
 
 /// Initialize a new symbol account
@@ -19,7 +26,80 @@ pub fn initialize_token_account(token_account: &mut Token, data: SymbolInitData)
     token_account.registered_at = data.current_time;
     token_account.expires_at = data.expires_at;
     token_account.bump = data.bump;
-    token_account._reserved = [0u8; 64];
+    token_account.dispute_open = false;
+    token_account.clear_claim();
+    token_account.drift_flagged_at = 0;
+    token_account.metadata_commitment = data.metadata_commitment;
+    token_account.keeper_deposit = data.keeper_deposit;
+    token_account.price_lock = data.price_lock;
+    token_account.bond_lamports = data.bond_lamports;
+    token_account._reserved = [0u8; 46];
+}
+
+/// Create a Token PDA from the handler, the way Anchor's `init` does
+/// Lamports already sent to the address are kept and only the rent shortfall is
+/// paid, so a dust transfer can't block the symbol. Fails if the account is
+/// already allocated (the symbol is registered). Returns the lamports paid.
+pub fn create_token_pda<'info>(
+    payer: &AccountInfo<'info>,
+    token_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+) -> Result<u64> {
+    let space = 8 + Token::INIT_SPACE;
+    let rent_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = token_info.lamports();
+
+    if current_lamports == 0 {
+        create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: token_info.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent_lamports,
+            space as u64,
+            &crate::ID,
+        )?;
+
+        return Ok(rent_lamports);
+    }
+
+    let shortfall = rent_lamports.saturating_sub(current_lamports);
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: token_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate { account_to_allocate: token_info.clone() },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign { account_to_assign: token_info.clone() },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )?;
+
+    Ok(shortfall)
 }
 
 /// Update symbol expiration on renewal
@@ -31,16 +111,32 @@ pub fn update_symbol_on_renewal(
 }
 
 /// Update symbol mint
+/// The new mint's metadata has been validated, so any drift flag is cleared
 pub fn update_symbol_mint(
     token_account: &mut Token,
     new_mint: Pubkey,
+    metadata_commitment: MetadataCommitment,
 ) {
     token_account.mint = new_mint;
+    token_account.drift_flagged_at = 0;
+    token_account.metadata_commitment = metadata_commitment;
+}
+
+/// Re-commit symbol to its mint's current metadata
+/// The owner accepted the current metadata, so any drift flag is cleared
+pub fn recommit_symbol_metadata(
+    token_account: &mut Token,
+    metadata_commitment: MetadataCommitment,
+) {
+    token_account.metadata_commitment = metadata_commitment;
+    token_account.drift_flagged_at = 0;
 }
 
 /// Update symbol owner
+/// Clears claim precedence - the new owner didn't claim via token authority
 pub fn update_symbol_owner(token_account: &mut Token, new_owner: Pubkey) {
     token_account.owner = new_owner;
+    token_account.clear_claim();
 }
 
 /// Data needed to claim an expired symbol
@@ -48,13 +144,50 @@ pub struct SymbolClaimData {
     pub new_mint: Pubkey,
     pub new_owner: Pubkey,
     pub expires_at: i64,
+    pub metadata_commitment: MetadataCommitment,
+    pub price_lock: PriceLock,
+    pub bond_lamports: u64,
 }
 
 /// Update symbol when claimed by new owner
-/// Resets mint, owner, and expiration but preserves symbol string, registered_at, and bump
+/// Resets mint, owner, expiration, price lock and bond but preserves symbol string, registered_at, and bump
 pub fn update_symbol_on_claim(token_account: &mut Token, data: SymbolClaimData) {
     token_account.mint = data.new_mint;
     token_account.owner = data.new_owner;
     token_account.expires_at = data.expires_at;
+    token_account.clear_claim();
+    token_account.drift_flagged_at = 0;
+    token_account.metadata_commitment = data.metadata_commitment;
+    token_account.price_lock = data.price_lock;
+    token_account.bond_lamports = data.bond_lamports;
     // registered_at, symbol, bump, and _reserved are preserved
 }
+
+/// Validate a batch has between 1 and MAX_BATCH_SIZE items
+pub fn validate_batch_size(len: usize) -> Result<()> {
+    require!(len > 0 && len <= MAX_BATCH_SIZE, TnsError::InvalidBatchSize);
+
+    Ok(())
+}
+
+/// Load the Token accounts passed as remaining_accounts for a batch instruction
+/// Each must be a writable Token account owned by this program, and appear only once
+pub fn load_batch_token_accounts<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<Account<'info, Token>>> {
+    validate_batch_size(remaining_accounts.len())?;
+
+    let mut tokens: Vec<Account<'info, Token>> = Vec::with_capacity(remaining_accounts.len());
+
+    for info in remaining_accounts {
+        require!(info.is_writable, TnsError::InvalidBatchAccounts);
+        require!(
+            tokens.iter().all(|token| token.key() != info.key()),
+            TnsError::DuplicateBatchItem
+        );
+
+        tokens.push(Account::<Token>::try_from(info)?);
+    }
+
+    Ok(tokens)
+}
@@ -1,14 +1,21 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 use anchor_spl::token_2022::spl_token_2022::{
-    extension::{BaseStateWithExtensions, StateWithExtensions},
+    extension::{
+        metadata_pointer::MetadataPointer, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
     state::Mint as Token2022Mint,
 };
 use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::TokenMetadata;
+use spl_type_length_value::state::{TlvState, TlvStateBorrowed};
 use mpl_token_metadata::accounts::Metadata;
+use super::payment::sol_price_range_violation;
 use crate::{
-    Config, Token, TnsError,
-    MAX_SYMBOL_LENGTH, MAX_REGISTRATION_YEARS, SECONDS_PER_YEAR,
+    AutoRenew, Config, Platform, Token, TnsError, EligibilityPolicy, MetadataCommitment, PriceSource, TnsPoolSource,
+    TnsPoolKind, OracleConfig, OracleVerificationLevel,
+    MAX_SYMBOL_LENGTH, MAX_REGISTRATION_YEARS, SECONDS_PER_YEAR, MAX_ARBITERS, MAX_TNS_POOL_SOURCES,
+    MAX_BUYBACK_SLIPPAGE_BPS, MAX_TREASURY_DESTINATIONS, MAX_ORACLE_STALENESS_SECONDS,
+    MAX_FALLBACK_PRICE_TTL_SECONDS, TNS_MINT,
     symbol_status::{get_symbol_status, SymbolStatus},
 };
 
@@ -62,7 +69,7 @@ pub fn validate_mint_different(current_mint: &Pubkey, new_mint: &Pubkey) -> Resu
     Ok(())
 }
 
-/// Validate SOL cost doesn't exceed max specified (slippage protection)
+/// Validate a SOL or token cost doesn't exceed max specified (slippage protection)
 pub fn validate_slippage(actual_cost: u64, max_cost: u64) -> Result<()> {
     require!(actual_cost <= max_cost, TnsError::SlippageExceeded);
     
@@ -76,6 +83,132 @@ pub fn validate_symbol_claimable(token: &Token, current_time: i64) -> Result<()>
     Ok(())
 }
 
+/// Validate symbol can be released by its owner
+/// Expired, in-grace and drift-flagged symbols are left to the keeper and claim
+/// paths so the owner can't front-run them for the keeper deposit and bond
+pub fn validate_symbol_releasable(token: &Token, current_time: i64) -> Result<()> {
+    require!(token.is_active(current_time), TnsError::SymbolNotActive);
+    require!(!token.is_drift_flagged(), TnsError::SymbolDriftFlagged);
+
+    Ok(())
+}
+
+/// Validate symbol has no open dispute (ownership changes are frozen while disputed)
+pub fn validate_no_open_dispute(token: &Token) -> Result<()> {
+    require!(!token.dispute_open, TnsError::DisputeOpen);
+
+    Ok(())
+}
+
+/// Validate a claim at the given precedence can displace the current owner
+/// Lower precedence is always rejected; equal precedence must wait out the cooldown
+pub fn validate_claim_precedence(
+    token: &Token,
+    precedence: u8,
+    current_time: i64,
+    cooldown_seconds: i64,
+) -> Result<()> {
+    require!(
+        precedence >= token.claim_precedence,
+        TnsError::LowerClaimPrecedence
+    );
+
+    if precedence == token.claim_precedence {
+        require!(
+            current_time >= token.last_claimed_at.saturating_add(cooldown_seconds),
+            TnsError::ClaimCooldownActive
+        );
+    }
+
+    Ok(())
+}
+
+/// Validate owner-chosen auto-renew settings
+pub fn validate_auto_renew_settings(
+    years_per_renewal: u8,
+    max_years: u16,
+    max_fee_per_renewal: u64,
+) -> Result<()> {
+    require!(
+        (1..=MAX_REGISTRATION_YEARS).contains(&years_per_renewal)
+            && max_years >= years_per_renewal as u16
+            && max_fee_per_renewal > 0,
+        TnsError::InvalidAutoRenewConfig
+    );
+
+    Ok(())
+}
+
+/// Validate admin-chosen payment asset settings
+pub fn validate_payment_asset_settings(
+    price_source: &PriceSource,
+    discount_bps: u16,
+    mint: &Pubkey,
+) -> Result<()> {
+    require!(discount_bps < 10000, TnsError::InvalidPaymentAsset);
+
+    let valid_source = match price_source {
+        PriceSource::FixedPeg { usd_micro } => *usd_micro > 0,
+        PriceSource::Pyth { feed_id } | PriceSource::Stablecoin { feed_id } => *feed_id != [0u8; 32],
+        PriceSource::TnsTwap => *mint == TNS_MINT,
+    };
+    require!(valid_source, TnsError::InvalidPaymentAsset);
+
+    Ok(())
+}
+
+/// Validate admin-chosen revenue vault buyback settings
+pub fn validate_buyback_settings(buyback_max_slippage_bps: u16) -> Result<()> {
+    require!(
+        buyback_max_slippage_bps <= MAX_BUYBACK_SLIPPAGE_BPS,
+        TnsError::InvalidBuybackSettings
+    );
+
+    Ok(())
+}
+
+/// Validate admin-chosen TNS price sources
+/// min_sources counts pools plus the optional Pyth TNS/USD feed
+pub fn validate_tns_price_sources(pools: &[TnsPoolSource], min_sources: u8) -> Result<()> {
+    require!(pools.len() <= MAX_TNS_POOL_SOURCES, TnsError::InvalidPriceSources);
+    require!(
+        min_sources >= 1 && min_sources as usize <= pools.len() + 1,
+        TnsError::InvalidPriceSources
+    );
+
+    for (i, pool) in pools.iter().enumerate() {
+        require!(
+            pool.tns_vault != Pubkey::default()
+                && pool.sol_vault != Pubkey::default()
+                && pool.tns_vault != pool.sol_vault,
+            TnsError::InvalidPriceSources
+        );
+        if let TnsPoolKind::RaydiumCpmm { pool_state } = pool.kind {
+            require!(pool_state != Pubkey::default(), TnsError::InvalidPriceSources);
+        }
+        require!(
+            !pools[..i].iter().any(|other| other.tns_vault == pool.tns_vault),
+            TnsError::InvalidPriceSources
+        );
+    }
+
+    Ok(())
+}
+
+/// Validate a crank may renew the symbol now
+/// Returns the new expiration timestamp
+pub fn validate_auto_renewal(auto_renew: &AutoRenew, token: &Token, current_time: i64) -> Result<i64> {
+    require!(auto_renew.owner == token.owner, TnsError::AutoRenewOwnerMismatch);
+    validate_symbol_not_expired(token, current_time)?;
+    require!(AutoRenew::is_due(token, current_time), TnsError::AutoRenewNotDue);
+    require!(
+        auto_renew.years_remaining >= auto_renew.years_per_renewal as u16,
+        TnsError::AutoRenewYearsExhausted
+    );
+
+    validate_and_calculate_expiration(token.expires_at, auto_renew.years_per_renewal, current_time)
+}
+
 /// Validate symbol is cancelable (1 year past grace period)
 pub fn validate_symbol_cancelable(token: &Token, current_time: i64) -> Result<()> {
     require!(token.is_cancelable(current_time), TnsError::NotYetCancelable);
@@ -93,6 +226,135 @@ pub fn validate_platform_fee_bps(platform_fee_bps: u16) -> Result<()> {
     Ok(())
 }
 
+/// Validate a platform fee split against the platform registry
+/// A non-zero split needs a registered platform and may not exceed its max_fee_bps.
+/// A platform passed without a split (attribution only) must still be active.
+pub fn validate_platform_fee(platform: Option<&Account<Platform>>, platform_fee_bps: u16) -> Result<()> {
+    match platform {
+        Some(platform) => {
+            require!(platform.active, TnsError::PlatformInactive);
+            require!(
+                platform_fee_bps <= platform.max_fee_bps,
+                TnsError::PlatformFeeAboveLimit
+            );
+        }
+        None => require!(platform_fee_bps == 0, TnsError::PlatformRequired),
+    }
+
+    Ok(())
+}
+
+/// Validate a registration or expired claim against the bond pricing mode
+/// Bond mode is SOL-only: bonds are held in lamports, so token payments
+/// (bondable = false) are rejected, as are platform fees since the whole
+/// price is refundable.
+pub fn validate_bond_mode(config: &Config, bondable: bool, platform_fee_bps: u16) -> Result<()> {
+    if config.bond_policy.enabled {
+        require!(bondable && platform_fee_bps == 0, TnsError::BondModeUnsupported);
+    }
+
+    Ok(())
+}
+
+/// Validate arbiter set (1-10 unique keys) and quorum (strict majority, at most the set size)
+pub fn validate_dispute_config(arbiters: &[Pubkey], quorum: u8) -> Result<()> {
+    require!(
+        !arbiters.is_empty() && arbiters.len() <= MAX_ARBITERS,
+        TnsError::InvalidDisputeConfig
+    );
+
+    for (i, arbiter) in arbiters.iter().enumerate() {
+        require!(!arbiters[..i].contains(arbiter), TnsError::InvalidDisputeConfig);
+    }
+
+    let quorum = quorum as usize;
+    require!(
+        quorum > arbiters.len() / 2 && quorum <= arbiters.len(),
+        TnsError::InvalidDisputeConfig
+    );
+
+    Ok(())
+}
+
+/// Validate treasury withdrawal allowlist (1-8 unique, non-default wallets)
+pub fn validate_treasury_allowlist(allowlist: &[Pubkey]) -> Result<()> {
+    require!(
+        !allowlist.is_empty() && allowlist.len() <= MAX_TREASURY_DESTINATIONS,
+        TnsError::InvalidTreasuryAllowlist
+    );
+
+    for (i, wallet) in allowlist.iter().enumerate() {
+        require!(
+            *wallet != Pubkey::default() && !allowlist[..i].contains(wallet),
+            TnsError::InvalidTreasuryAllowlist
+        );
+    }
+
+    Ok(())
+}
+
+/// Validate oracle config: a non-zero SOL/USD feed, staleness of 1s-1h, at least one
+/// guardian signature, a non-default price update owner and guards within range
+pub fn validate_oracle_config(oracle_config: &OracleConfig) -> Result<()> {
+    require!(oracle_config.sol_usd_feed_id != [0u8; 32], TnsError::InvalidOracleConfig);
+
+    require!(
+        oracle_config.max_staleness_seconds > 0
+            && oracle_config.max_staleness_seconds <= MAX_ORACLE_STALENESS_SECONDS,
+        TnsError::InvalidOracleConfig
+    );
+
+    if let OracleVerificationLevel::Partial { num_signatures } = oracle_config.min_verification {
+        require!(num_signatures > 0, TnsError::InvalidOracleConfig);
+    }
+
+    require!(
+        oracle_config.price_update_owner != Pubkey::default(),
+        TnsError::InvalidPriceUpdateOwner
+    );
+
+    require!(
+        oracle_config.max_confidence_bps <= 10000 && oracle_config.max_deviation_bps <= 10000,
+        TnsError::InvalidOracleConfig
+    );
+
+    require!(oracle_config.max_last_good_age_seconds >= 0, TnsError::InvalidOracleConfig);
+
+    require!(
+        oracle_config.min_sol_price_usd_micro == 0
+            || oracle_config.max_sol_price_usd_micro == 0
+            || oracle_config.min_sol_price_usd_micro < oracle_config.max_sol_price_usd_micro,
+        TnsError::InvalidOracleConfig
+    );
+
+    Ok(())
+}
+
+/// Validate an admin-posted fallback SOL/USD price
+/// A zero TTL clears the fallback; otherwise the price must be positive, pass the
+/// bounds and deviation guards and live for at most 15 minutes
+pub fn validate_fallback_sol_price(
+    oracle_config: &OracleConfig,
+    price_usd_micro: u64,
+    ttl_seconds: i64,
+    current_time: i64,
+) -> Result<()> {
+    require!(
+        (0..=MAX_FALLBACK_PRICE_TTL_SECONDS).contains(&ttl_seconds),
+        TnsError::InvalidFallbackPrice
+    );
+
+    if ttl_seconds > 0 {
+        require!(price_usd_micro > 0, TnsError::InvalidFallbackPrice);
+        require!(
+            sol_price_range_violation(oracle_config, price_usd_micro, current_time).is_none(),
+            TnsError::InvalidFallbackPrice
+        );
+    }
+
+    Ok(())
+}
+
 /// Validate phase access for registration
 /// Returns Ok(()) if the payer is allowed to register this symbol
 ///
@@ -126,18 +388,62 @@ pub fn validate_registration_access(
 /// Token-2022 program ID
 use anchor_spl::token_2022::ID as TOKEN_2022_PROGRAM_ID;
 
-/// Try to extract symbol from Token-2022 metadata extension.
-/// Returns Some(symbol) if successful, None if no metadata extension.
-fn try_get_token2022_symbol(mint_info: &AccountInfo) -> Option<String> {
-    let data = mint_info.data.borrow();
+/// Load Token-2022 metadata for a mint.
+/// Reads the embedded TokenMetadata extension when metadata_info is the mint itself,
+/// otherwise metadata_info must be the account the mint's MetadataPointer targets.
+fn load_token2022_metadata(
+    metadata_info: &AccountInfo,
+    mint_info: &AccountInfo,
+) -> Result<TokenMetadata> {
+    let mint_data = mint_info.data.borrow();
+    let mint_with_ext = StateWithExtensions::<Token2022Mint>::unpack(&mint_data)
+        .map_err(|_| TnsError::InvalidMint)?;
+
+    if metadata_info.key() == mint_info.key() {
+        return mint_with_ext
+            .get_variable_len_extension::<TokenMetadata>()
+            .map_err(|_| TnsError::InvalidMetadata.into());
+    }
+
+    // External metadata: must be the account the mint's MetadataPointer designates
+    let pointer = mint_with_ext
+        .get_extension::<MetadataPointer>()
+        .map_err(|_| TnsError::InvalidMetadata)?;
+    let pointer_address: Option<Pubkey> = pointer.metadata_address.into();
+    require!(
+        pointer_address == Some(metadata_info.key()),
+        TnsError::InvalidMetadata
+    );
 
-    // Try to parse as Token-2022 mint with extensions
-    let mint_with_ext = StateWithExtensions::<Token2022Mint>::unpack(&data).ok()?;
+    let metadata_data = metadata_info.data.borrow();
+    let metadata = TlvStateBorrowed::unpack(&metadata_data)
+        .and_then(|tlv| tlv.get_first_variable_len_value::<TokenMetadata>())
+        .map_err(|_| TnsError::InvalidMetadata)?;
 
-    // Try to get the TokenMetadata extension
-    let metadata = mint_with_ext.get_variable_len_extension::<TokenMetadata>().ok()?;
+    // Counter spoofing: the metadata must name this mint
+    require!(metadata.mint == mint_info.key(), TnsError::InvalidMetadata);
 
-    Some(metadata.symbol.clone())
+    Ok(metadata)
+}
+
+/// Extract the metadata update authority for a mint.
+/// Supports Metaplex metadata (classic SPL) and Token-2022 metadata, either
+/// embedded in the mint or held in the account its MetadataPointer targets.
+///
+/// Returns None if the update authority has been revoked.
+pub fn extract_update_authority(
+    metadata_info: &AccountInfo,
+    mint_info: &AccountInfo,
+) -> Result<Option<Pubkey>> {
+    if mint_info.owner == &TOKEN_2022_PROGRAM_ID {
+        let metadata = load_token2022_metadata(metadata_info, mint_info)?;
+
+        Ok(metadata.update_authority.into())
+    } else {
+        let metadata = parse_metadata(metadata_info, &mint_info.key())?;
+
+        Ok(Some(metadata.update_authority))
+    }
 }
 
 /// Parse and validate a mint's Metaplex metadata account.
@@ -154,18 +460,31 @@ pub fn parse_metadata(
         .map_err(|_| TnsError::InvalidMetadata.into())
 }
 
-/// Extract the symbol from a mint's metadata.
+/// Metadata fields TNS verifies against a registration
+pub struct MetadataFields {
+    pub symbol: String,
+    pub name: String,
+    pub uri: String,
+    pub update_authority: Option<Pubkey>,
+}
+
+impl MetadataFields {
+    /// Commitment to the name, URI and update authority
+    pub fn commitment(&self, current_time: i64) -> MetadataCommitment {
+        MetadataCommitment::new(&self.name, &self.uri, self.update_authority, current_time)
+    }
+}
+
+/// Extract the verified metadata fields for a mint.
 /// Supports both Metaplex metadata and Token-2022 metadata extensions.
 ///
 /// SECURITY: Enforces correct metadata type based on mint program:
 /// - Token-2022 mints MUST pass mint as metadata_info (embedded metadata)
 /// - Classic SPL mints MUST pass Metaplex metadata PDA as metadata_info
-///
-/// Returns the symbol string if successful.
-pub fn extract_metadata_symbol(
+pub fn extract_metadata_fields(
     metadata_info: &AccountInfo,
     mint_info: &AccountInfo,
-) -> Result<String> {
+) -> Result<MetadataFields> {
     let is_token_2022 = mint_info.owner == &TOKEN_2022_PROGRAM_ID;
 
     if is_token_2022 {
@@ -175,10 +494,15 @@ pub fn extract_metadata_symbol(
             TnsError::InvalidMetadata
         );
 
-        let symbol = try_get_token2022_symbol(metadata_info)
-            .ok_or(TnsError::InvalidMetadata)?;
+        let metadata = load_token2022_metadata(metadata_info, mint_info)
+            .map_err(|_| TnsError::InvalidMetadata)?;
 
-        Ok(symbol)
+        Ok(MetadataFields {
+            symbol: metadata.symbol,
+            name: metadata.name,
+            uri: metadata.uri,
+            update_authority: metadata.update_authority.into(),
+        })
     } else {
         // Classic SPL Token: metadata MUST be Metaplex metadata PDA
         require!(
@@ -187,25 +511,173 @@ pub fn extract_metadata_symbol(
         );
 
         let metadata = parse_metadata(metadata_info, &mint_info.key())?;
-        let metadata_symbol = metadata.symbol.trim_matches('\0').to_string();
 
-        Ok(metadata_symbol)
+        Ok(MetadataFields {
+            symbol: metadata.symbol.trim_matches('\0').to_string(),
+            name: metadata.name.trim_matches('\0').to_string(),
+            uri: metadata.uri.trim_matches('\0').to_string(),
+            update_authority: Some(metadata.update_authority),
+        })
+    }
+}
+
+/// Extract the symbol from a mint's metadata.
+/// Same account rules as extract_metadata_fields.
+///
+/// Returns the symbol string if successful.
+pub fn extract_metadata_symbol(
+    metadata_info: &AccountInfo,
+    mint_info: &AccountInfo,
+) -> Result<String> {
+    Ok(extract_metadata_fields(metadata_info, mint_info)?.symbol)
+}
+
+/// Which metadata fields no longer match a registration
+#[derive(Clone, Copy, Default, Debug)]
+pub struct MetadataDrift {
+    pub symbol_changed: bool,
+    pub name_changed: bool,
+    pub uri_changed: bool,
+    pub update_authority_changed: bool,
+}
+
+impl MetadataDrift {
+    pub fn any(&self) -> bool {
+        self.symbol_changed || self.name_changed || self.uri_changed || self.update_authority_changed
+    }
+}
+
+/// Compare live metadata against the registered symbol and metadata commitment.
+/// Symbols registered before commitments existed only have their symbol checked.
+pub fn detect_metadata_drift(token: &Token, fields: &MetadataFields) -> MetadataDrift {
+    let commitment = &token.metadata_commitment;
+    let committed = commitment.is_set();
+
+    MetadataDrift {
+        symbol_changed: fields.symbol != token.symbol,
+        name_changed: committed && !commitment.name_matches(&fields.name),
+        uri_changed: committed && !commitment.uri_matches(&fields.uri),
+        update_authority_changed: committed && !commitment.update_authority_matches(fields.update_authority),
     }
 }
 
 /// Validate mint's metadata symbol matches expected symbol exactly (case-sensitive).
+/// Returns the metadata fields so callers can commit to them.
 pub fn validate_mint_metadata(
     metadata_info: &AccountInfo,
     mint_info: &AccountInfo,
     expected_symbol: &str,
-) -> Result<()> {
-    let symbol = extract_metadata_symbol(metadata_info, mint_info)?;
+) -> Result<MetadataFields> {
+    let fields = extract_metadata_fields(metadata_info, mint_info)?;
 
     require!(
-        symbol == expected_symbol,
+        fields.symbol == expected_symbol,
         TnsError::MetadataSymbolMismatch
     );
 
+    Ok(fields)
+}
+
+/// Token-2022 extensions that let an authority seize, block or hook holder transfers
+const DANGEROUS_MINT_EXTENSIONS: [ExtensionType; 4] = [
+    ExtensionType::PermanentDelegate,
+    ExtensionType::TransferHook,
+    ExtensionType::MintCloseAuthority,
+    ExtensionType::Pausable,
+];
+
+/// Token-2022 mint extensions a payment mint may carry
+/// Transfer fees are grossed up by the payment helpers; the rest do not affect
+/// amounts or transfers. Anything else (hooks, delegates, interest, pausing,
+/// default-frozen accounts...) is rejected.
+const SUPPORTED_PAYMENT_MINT_EXTENSIONS: [ExtensionType; 7] = [
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+    ExtensionType::GroupPointer,
+    ExtensionType::TokenGroup,
+    ExtensionType::GroupMemberPointer,
+    ExtensionType::TokenGroupMember,
+];
+
+/// Validate that a payment mint only carries extensions the protocol can account for
+/// SPL Token mints always pass.
+pub fn validate_payment_mint_extensions(mint: &InterfaceAccount<Mint>) -> Result<()> {
+    let mint_info = mint.to_account_info();
+    if mint_info.owner != &TOKEN_2022_PROGRAM_ID {
+        return Ok(());
+    }
+
+    let data = mint_info.data.borrow();
+    let mint_with_ext = StateWithExtensions::<Token2022Mint>::unpack(&data)
+        .map_err(|_| TnsError::InvalidMint)?;
+    let extensions = mint_with_ext
+        .get_extension_types()
+        .map_err(|_| TnsError::InvalidMint)?;
+
+    require!(
+        extensions.iter().all(|ext| SUPPORTED_PAYMENT_MINT_EXTENSIONS.contains(ext)),
+        TnsError::UnsupportedPaymentMintExtension
+    );
+
     Ok(())
 }
 
+/// Validate a mint against the configured eligibility policy.
+/// Each rule that is enabled and violated fails with its own error.
+pub fn validate_eligibility(
+    policy: &EligibilityPolicy,
+    metadata_info: &AccountInfo,
+    token_mint: &InterfaceAccount<Mint>,
+) -> Result<()> {
+    let mint_info = token_mint.to_account_info();
+    let is_token_2022 = mint_info.owner == &TOKEN_2022_PROGRAM_ID;
+
+    if policy.require_immutable_metadata {
+        let is_immutable = if is_token_2022 {
+            // Token-2022 metadata is immutable once its update authority is revoked
+            extract_update_authority(metadata_info, &mint_info)?.is_none()
+        } else {
+            !parse_metadata(metadata_info, &mint_info.key())?.is_mutable
+        };
+
+        require!(is_immutable, TnsError::MetadataMustBeImmutable);
+    }
+
+    if policy.require_mint_authority_revoked {
+        require!(
+            token_mint.mint_authority.is_none(),
+            TnsError::MintAuthorityNotRevoked
+        );
+    }
+
+    if policy.require_no_freeze_authority {
+        require!(
+            token_mint.freeze_authority.is_none(),
+            TnsError::FreezeAuthorityPresent
+        );
+    }
+
+    if policy.min_supply > 0 {
+        require!(
+            token_mint.supply >= policy.min_supply,
+            TnsError::SupplyBelowMinimum
+        );
+    }
+
+    if policy.reject_dangerous_extensions && is_token_2022 {
+        let data = mint_info.data.borrow();
+        let mint_with_ext = StateWithExtensions::<Token2022Mint>::unpack(&data)
+            .map_err(|_| TnsError::InvalidMint)?;
+        let extensions = mint_with_ext
+            .get_extension_types()
+            .map_err(|_| TnsError::InvalidMint)?;
+
+        require!(
+            !extensions.iter().any(|ext| DANGEROUS_MINT_EXTENSIONS.contains(ext)),
+            TnsError::DangerousMintExtension
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{MajorityClaim, MajorityClaimEnded, TnsError};
+use super::super::helpers::{release_escrow, EscrowReleaseAccounts};
+
+/// Abandon a majority-holder claim and release the escrowed tokens.
+/// Available at any time, even if the symbol has since been closed,
+/// so tokens can never be stranded in escrow.
+#[derive(Accounts)]
+pub struct CancelMajorityClaim<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// The mint the tokens were escrowed for
+    #[account(address = majority_claim.mint @ TnsError::InvalidMint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = claimant,
+    )]
+    pub claimant_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [MajorityClaim::SEED_PREFIX, majority_claim.token_account.as_ref(), claimant.key().as_ref()],
+        bump = majority_claim.bump,
+        has_one = claimant,
+        close = claimant,
+    )]
+    pub majority_claim: Account<'info, MajorityClaim>,
+
+    #[account(
+        mut,
+        seeds = [MajorityClaim::ESCROW_SEED_PREFIX, majority_claim.key().as_ref()],
+        bump = majority_claim.escrow_bump,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<CancelMajorityClaim>) -> Result<()> {
+    let clock = Clock::get()?;
+    let claimant = ctx.accounts.claimant.key();
+    let majority_claim = &ctx.accounts.majority_claim;
+    let token_account_key = majority_claim.token_account;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        MajorityClaim::SEED_PREFIX,
+        token_account_key.as_ref(),
+        claimant.as_ref(),
+        &[majority_claim.bump],
+    ]];
+
+    let released = release_escrow(
+        &EscrowReleaseAccounts {
+            escrow: &ctx.accounts.escrow,
+            escrow_authority: &ctx.accounts.majority_claim.to_account_info(),
+            destination_token_account: &ctx.accounts.claimant_token_account,
+            rent_destination: &ctx.accounts.claimant.to_account_info(),
+            mint: &ctx.accounts.token_mint,
+            token_program: &ctx.accounts.token_program,
+        },
+        signer_seeds,
+    )?;
+
+    emit!(MajorityClaimEnded {
+        majority_claim: ctx.accounts.majority_claim.key(),
+        token_account: token_account_key,
+        claimant,
+        amount: released,
+        ownership_transferred: false,
+        ended_at: clock.unix_timestamp,
+    });
+
+    // Majority claim closure is handled by Anchor's close = claimant constraint
+    Ok(())
+}
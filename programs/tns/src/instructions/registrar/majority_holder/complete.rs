@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{
+    BondVault, Config, KeeperVault, Token, MajorityClaim, MajorityClaimEnded, OwnershipClaimed, TnsError,
+    CLAIM_PRECEDENCE_MAJORITY_HOLDER,
+};
+use super::super::helpers::{
+    validate_not_paused, validate_no_open_dispute, validate_claim_precedence,
+    release_escrow, EscrowReleaseAccounts, refund_owner_deposits,
+};
+
+/// Complete a majority-holder claim after the hold period.
+///
+/// The escrowed amount must still be more than 50% of the current supply
+/// (minting during the hold period can dilute a claim). On success the
+/// claimant becomes owner and the escrowed tokens are released back to them.
+/// The displaced owner is refunded the symbol's keeper deposit and bond.
+#[derive(Accounts)]
+pub struct CompleteMajorityClaim<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
+        bump = token_account.bump,
+        has_one = owner @ TnsError::UnauthorizedOwner,
+    )]
+    pub token_account: Box<Account<'info, Token>>,
+
+    /// CHECK: Current owner (validated by has_one), receives the symbol's keeper deposit and bond
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+
+    /// The mint the tokens were escrowed for
+    #[account(address = majority_claim.mint @ TnsError::InvalidMint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = claimant,
+    )]
+    pub claimant_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [MajorityClaim::SEED_PREFIX, token_account.key().as_ref(), claimant.key().as_ref()],
+        bump = majority_claim.bump,
+        has_one = claimant,
+        close = claimant,
+    )]
+    pub majority_claim: Box<Account<'info, MajorityClaim>>,
+
+    #[account(
+        mut,
+        seeds = [MajorityClaim::ESCROW_SEED_PREFIX, majority_claim.key().as_ref()],
+        bump = majority_claim.escrow_bump,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Keeper vault refunds the symbol's keeper deposit to the old owner
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Box<Account<'info, KeeperVault>>,
+
+    /// Bond vault refunds the symbol's bond to the old owner (required when the symbol has a bond)
+    #[account(
+        mut,
+        seeds = [BondVault::SEED_PREFIX],
+        bump = bond_vault.bump,
+    )]
+    pub bond_vault: Option<Box<Account<'info, BondVault>>>,
+}
+
+pub fn handler(ctx: Context<CompleteMajorityClaim>) -> Result<()> {
+    let clock = Clock::get()?;
+    let claimant = ctx.accounts.claimant.key();
+    let majority_claim = &ctx.accounts.majority_claim;
+
+    // Validate
+    validate_not_paused(&ctx.accounts.config)?;
+
+    validate_no_open_dispute(&ctx.accounts.token_account)?;
+
+    require!(
+        clock.unix_timestamp >= majority_claim.unlocks_at,
+        TnsError::HoldPeriodNotElapsed
+    );
+
+    // Symbol must still point at the mint the tokens were escrowed for
+    require!(
+        ctx.accounts.token_account.mint == majority_claim.mint,
+        TnsError::InvalidMint
+    );
+
+    require!(
+        ctx.accounts.token_account.owner != claimant,
+        TnsError::AlreadyOwner
+    );
+
+    validate_claim_precedence(
+        &ctx.accounts.token_account,
+        CLAIM_PRECEDENCE_MAJORITY_HOLDER,
+        clock.unix_timestamp,
+        ctx.accounts.config.get_claim_cooldown(),
+    )?;
+
+    // Balance held through the whole period; re-check against current supply
+    require!(
+        MajorityClaim::is_majority(ctx.accounts.escrow.amount, ctx.accounts.token_mint.supply),
+        TnsError::NotMajorityHolder
+    );
+
+    // Capture old owner before mutation
+    let old_owner = ctx.accounts.token_account.owner;
+
+    // Transfer ownership to claimant
+    ctx.accounts.token_account.owner = claimant;
+    ctx.accounts.token_account.record_claim(CLAIM_PRECEDENCE_MAJORITY_HOLDER, clock.unix_timestamp);
+
+    let (deposit_refunded, bond_refunded) = refund_owner_deposits(
+        &mut ctx.accounts.token_account,
+        &mut ctx.accounts.keeper_vault,
+        ctx.accounts.bond_vault.as_deref_mut(),
+        &ctx.accounts.owner,
+    )?;
+
+    // Release escrowed tokens back to the claimant
+    let token_account_key = ctx.accounts.token_account.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        MajorityClaim::SEED_PREFIX,
+        token_account_key.as_ref(),
+        claimant.as_ref(),
+        &[majority_claim.bump],
+    ]];
+
+    let released = release_escrow(
+        &EscrowReleaseAccounts {
+            escrow: &ctx.accounts.escrow,
+            escrow_authority: &ctx.accounts.majority_claim.to_account_info(),
+            destination_token_account: &ctx.accounts.claimant_token_account,
+            rent_destination: &ctx.accounts.claimant.to_account_info(),
+            mint: &ctx.accounts.token_mint,
+            token_program: &ctx.accounts.token_program,
+        },
+        signer_seeds,
+    )?;
+
+    emit!(OwnershipClaimed {
+        token_account: token_account_key,
+        symbol: ctx.accounts.token_account.symbol.clone(),
+        old_owner,
+        new_owner: claimant,
+        claim_type: "majority_holder".to_string(),
+        precedence: CLAIM_PRECEDENCE_MAJORITY_HOLDER,
+        claimed_at: clock.unix_timestamp,
+        deposit_refunded,
+        bond_refunded,
+    });
+
+    emit!(MajorityClaimEnded {
+        majority_claim: ctx.accounts.majority_claim.key(),
+        token_account: token_account_key,
+        claimant,
+        amount: released,
+        ownership_transferred: true,
+        ended_at: clock.unix_timestamp,
+    });
+
+    // Majority claim closure is handled by Anchor's close = claimant constraint
+    Ok(())
+}
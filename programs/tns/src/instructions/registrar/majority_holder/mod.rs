@@ -0,0 +1,11 @@
+pub mod start;
+pub mod complete;
+pub mod cancel;
+
+pub use start::StartMajorityClaim;
+pub use complete::CompleteMajorityClaim;
+pub use cancel::CancelMajorityClaim;
+
+pub(crate) use start::__client_accounts_start_majority_claim;
+pub(crate) use complete::__client_accounts_complete_majority_claim;
+pub(crate) use cancel::__client_accounts_cancel_majority_claim;
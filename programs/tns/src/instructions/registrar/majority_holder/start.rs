@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, transfer_checked, TransferChecked};
+use crate::{Config, Token, MajorityClaim, MajorityClaimStarted, TnsError, CLAIM_PRECEDENCE_MAJORITY_HOLDER};
+use super::super::helpers::{validate_not_paused, validate_no_open_dispute};
+
+/// Start a majority-holder claim by locking >50% of supply in program escrow.
+///
+/// Ownership only passes via complete_majority_claim after the configured
+/// hold period, so supply that is borrowed or briefly concentrated within a
+/// single transaction can't be used to take a symbol.
+#[derive(Accounts)]
+pub struct StartMajorityClaim<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
+        bump = token_account.bump,
+    )]
+    pub token_account: Box<Account<'info, Token>>,
+
+    /// The token mint that this symbol is registered to
+    #[account(
+        constraint = token_mint.key() == token_account.mint @ TnsError::InvalidMint
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = claimant,
+    )]
+    pub claimant_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + MajorityClaim::INIT_SPACE,
+        seeds = [MajorityClaim::SEED_PREFIX, token_account.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub majority_claim: Box<Account<'info, MajorityClaim>>,
+
+    /// Escrow holding the claimant's tokens, owned by the majority claim PDA
+    #[account(
+        init,
+        payer = claimant,
+        seeds = [MajorityClaim::ESCROW_SEED_PREFIX, majority_claim.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = majority_claim,
+        token::token_program = token_program,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<StartMajorityClaim>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let claimant = ctx.accounts.claimant.key();
+    let supply = ctx.accounts.token_mint.supply;
+
+    // Validate
+    validate_not_paused(&ctx.accounts.config)?;
+
+    validate_no_open_dispute(&ctx.accounts.token_account)?;
+
+    require!(
+        ctx.accounts.token_account.owner != claimant,
+        TnsError::AlreadyOwner
+    );
+
+    // Don't lock tokens for a claim that can never displace the current owner
+    require!(
+        ctx.accounts.token_account.claim_precedence <= CLAIM_PRECEDENCE_MAJORITY_HOLDER,
+        TnsError::LowerClaimPrecedence
+    );
+
+    // Must escrow MORE than 50% of supply
+    require!(
+        MajorityClaim::is_majority(amount, supply),
+        TnsError::NotMajorityHolder
+    );
+
+    // Lock tokens in escrow
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.claimant_token_account.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.claimant.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    let unlocks_at = clock.unix_timestamp + ctx.accounts.config.get_majority_hold_period();
+
+    let majority_claim = &mut ctx.accounts.majority_claim;
+    majority_claim.token_account = ctx.accounts.token_account.key();
+    majority_claim.claimant = claimant;
+    majority_claim.mint = ctx.accounts.token_mint.key();
+    majority_claim.amount = amount;
+    majority_claim.started_at = clock.unix_timestamp;
+    majority_claim.unlocks_at = unlocks_at;
+    majority_claim.bump = ctx.bumps.majority_claim;
+    majority_claim.escrow_bump = ctx.bumps.escrow;
+
+    emit!(MajorityClaimStarted {
+        majority_claim: majority_claim.key(),
+        token_account: ctx.accounts.token_account.key(),
+        symbol: ctx.accounts.token_account.symbol.clone(),
+        claimant,
+        mint: majority_claim.mint,
+        amount,
+        supply,
+        unlocks_at,
+        started_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
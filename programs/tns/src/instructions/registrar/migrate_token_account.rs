@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::{Token, TokenAccountMigrated, TnsError, MAX_SYMBOL_LENGTH};
+
+/// Grow a Token account created under an older, shorter layout (permissionless)
+/// The account is resized to the current Token layout with the new fields
+/// zeroed (no price lock, no bond); the payer tops up the rent difference.
+#[derive(Accounts)]
+pub struct MigrateTokenAccount<'info> {
+    /// Anyone can migrate - pays the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Can't be deserialized until migrated - owner, discriminator and PDA seeds verified in handler
+    #[account(mut)]
+    pub token_account: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateTokenAccount>) -> Result<()> {
+    let clock = Clock::get()?;
+    let token_info = ctx.accounts.token_account.to_account_info();
+
+    // Validate
+    require_keys_eq!(*token_info.owner, crate::ID, TnsError::InvalidTokenAccount);
+
+    let symbol = read_symbol(&token_info.try_borrow_data()?)?;
+    let (expected, _) = Pubkey::find_program_address(&[Token::SEED_PREFIX, symbol.as_bytes()], &crate::ID);
+    require_keys_eq!(token_info.key(), expected, TnsError::InvalidTokenAccount);
+
+    let old_len = token_info.data_len();
+    let new_len = 8 + Token::INIT_SPACE;
+    require!(old_len < new_len, TnsError::TokenAccountUpToDate);
+
+    // Top up rent for the larger account, then grow it (new bytes are zeroed)
+    let rent_paid = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(token_info.lamports());
+
+    if rent_paid > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: token_info.clone(),
+                },
+            ),
+            rent_paid,
+        )?;
+    }
+
+    token_info.resize(new_len)?;
+
+    emit!(TokenAccountMigrated {
+        token_account: token_info.key(),
+        symbol,
+        old_len: old_len as u64,
+        new_len: new_len as u64,
+        rent_paid,
+        payer: ctx.accounts.payer.key(),
+        migrated_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Read the symbol from raw Token account data (discriminator, then a
+/// length-prefixed string), which every layout version starts with
+fn read_symbol(data: &[u8]) -> Result<String> {
+    require!(
+        data.len() >= 12 && data[..8] == *Token::DISCRIMINATOR,
+        TnsError::InvalidTokenAccount
+    );
+
+    let len = u32::from_le_bytes([data[8], data[9], data[10], data[11]]) as usize;
+    require!(len <= MAX_SYMBOL_LENGTH && data.len() >= 12 + len, TnsError::InvalidTokenAccount);
+
+    String::from_utf8(data[12..12 + len].to_vec()).map_err(|_| error!(TnsError::InvalidTokenAccount))
+}
@@ -2,39 +2,76 @@ pub mod helpers;
 pub mod register;
 pub mod renew;
 pub mod update_mint;
+pub mod recommit_metadata;
 pub mod claim;
 pub mod cancel_symbol;
+pub mod release_symbol;
 pub mod transfer_ownership;
 pub mod claim_ownership;
 pub mod verify_or_close;
+pub mod majority_holder;
+pub mod batch;
+pub mod auto_renewal;
+pub mod update_tns_price_observation;
+pub mod update_platform_payout;
+pub mod buyback_and_burn;
+pub mod record_sol_price;
+pub mod migrate_token_account;
 
-pub use register::{RegisterSymbolSol, RegisterSymbolTns, RegisterSymbolUsdc, RegisterSymbolUsdt};
-pub use renew::{RenewSymbolSol, RenewSymbolTns, RenewSymbolUsdc, RenewSymbolUsdt};
-pub use update_mint::{UpdateMintSol, UpdateMintTns, UpdateMintUsdc, UpdateMintUsdt};
-pub use claim::{ClaimExpiredSymbolSol, ClaimExpiredSymbolTns, ClaimExpiredSymbolUsdc, ClaimExpiredSymbolUsdt};
+pub use register::{RegisterSymbolSol, RegisterSymbolAsset};
+pub use renew::{RenewSymbolSol, RenewSymbolAsset};
+pub use update_mint::{UpdateMintSol, UpdateMintAsset};
+pub use recommit_metadata::{RecommitMetadataSol, RecommitMetadataAsset};
+pub use claim::{ClaimExpiredSymbolSol, ClaimExpiredSymbolAsset};
 pub use cancel_symbol::CancelSymbol;
+pub use release_symbol::ReleaseSymbol;
 pub use transfer_ownership::TransferOwnership;
 pub use claim_ownership::ClaimOwnership;
 pub use verify_or_close::VerifyOrClose;
-pub use helpers::{get_sol_price_micro, get_tns_price_from_pool, calculate_tns_for_usd};
+pub use update_tns_price_observation::UpdateTnsPriceObservation;
+pub use update_platform_payout::UpdatePlatformPayout;
+pub use buyback_and_burn::BuybackAndBurn;
+pub use record_sol_price::RecordSolPrice;
+pub use migrate_token_account::MigrateTokenAccount;
+pub use majority_holder::{StartMajorityClaim, CompleteMajorityClaim, CancelMajorityClaim};
+pub use batch::{BatchRenewSymbolsSol, BatchCancelSymbols};
+pub use auto_renewal::{
+    EnableAutoRenewSol, EnableAutoRenewStablecoin, CrankAutoRenewSol, CrankAutoRenewStablecoin,
+    WithdrawAutoRenewSol, WithdrawAutoRenewStablecoin,
+};
+pub use helpers::{
+    get_sol_price_micro, get_tns_spot_price_micro, get_tns_price_from_pool, get_tns_twap_price_micro,
+    TnsPriceAccounts, TnsSpotPriceAccounts,
+};
 
 pub(crate) use register::__client_accounts_register_symbol_sol;
-pub(crate) use register::__client_accounts_register_symbol_tns;
-pub(crate) use register::__client_accounts_register_symbol_usdc;
-pub(crate) use register::__client_accounts_register_symbol_usdt;
+pub(crate) use register::__client_accounts_register_symbol_asset;
 pub(crate) use renew::__client_accounts_renew_symbol_sol;
-pub(crate) use renew::__client_accounts_renew_symbol_tns;
-pub(crate) use renew::__client_accounts_renew_symbol_usdc;
-pub(crate) use renew::__client_accounts_renew_symbol_usdt;
+pub(crate) use renew::__client_accounts_renew_symbol_asset;
 pub(crate) use update_mint::__client_accounts_update_mint_sol;
-pub(crate) use update_mint::__client_accounts_update_mint_tns;
-pub(crate) use update_mint::__client_accounts_update_mint_usdc;
-pub(crate) use update_mint::__client_accounts_update_mint_usdt;
+pub(crate) use update_mint::__client_accounts_update_mint_asset;
+pub(crate) use recommit_metadata::__client_accounts_recommit_metadata_sol;
+pub(crate) use recommit_metadata::__client_accounts_recommit_metadata_asset;
 pub(crate) use claim::__client_accounts_claim_expired_symbol_sol;
-pub(crate) use claim::__client_accounts_claim_expired_symbol_tns;
-pub(crate) use claim::__client_accounts_claim_expired_symbol_usdc;
-pub(crate) use claim::__client_accounts_claim_expired_symbol_usdt;
+pub(crate) use claim::__client_accounts_claim_expired_symbol_asset;
 pub(crate) use cancel_symbol::__client_accounts_cancel_symbol;
+pub(crate) use release_symbol::__client_accounts_release_symbol;
 pub(crate) use transfer_ownership::__client_accounts_transfer_ownership;
 pub(crate) use claim_ownership::__client_accounts_claim_ownership;
 pub(crate) use verify_or_close::__client_accounts_verify_or_close;
+pub(crate) use update_tns_price_observation::__client_accounts_update_tns_price_observation;
+pub(crate) use update_platform_payout::__client_accounts_update_platform_payout;
+pub(crate) use buyback_and_burn::__client_accounts_buyback_and_burn;
+pub(crate) use record_sol_price::__client_accounts_record_sol_price;
+pub(crate) use migrate_token_account::__client_accounts_migrate_token_account;
+pub(crate) use majority_holder::__client_accounts_start_majority_claim;
+pub(crate) use majority_holder::__client_accounts_complete_majority_claim;
+pub(crate) use majority_holder::__client_accounts_cancel_majority_claim;
+pub(crate) use batch::__client_accounts_batch_renew_symbols_sol;
+pub(crate) use batch::__client_accounts_batch_cancel_symbols;
+pub(crate) use auto_renewal::__client_accounts_enable_auto_renew_sol;
+pub(crate) use auto_renewal::__client_accounts_enable_auto_renew_stablecoin;
+pub(crate) use auto_renewal::__client_accounts_crank_auto_renew_sol;
+pub(crate) use auto_renewal::__client_accounts_crank_auto_renew_stablecoin;
+pub(crate) use auto_renewal::__client_accounts_withdraw_auto_renew_sol;
+pub(crate) use auto_renewal::__client_accounts_withdraw_auto_renew_stablecoin;
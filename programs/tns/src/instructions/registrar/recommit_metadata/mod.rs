@@ -0,0 +1,8 @@
+pub mod sol;
+pub mod asset;
+
+pub use sol::RecommitMetadataSol;
+pub use asset::RecommitMetadataAsset;
+
+pub(crate) use sol::__client_accounts_recommit_metadata_sol;
+pub(crate) use asset::__client_accounts_recommit_metadata_asset;
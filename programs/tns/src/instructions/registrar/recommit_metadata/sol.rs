@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::{Config, OracleConfig, Token, MetadataRecommitted, TnsError, Platform, KeeperVault, RevenueVault, WSOL_MINT};
+use super::super::helpers::{
+    validate_not_paused, validate_symbol_not_expired, validate_no_open_dispute,
+    validate_mint_metadata, validate_slippage, validate_platform_fee, record_platform_volume,
+    calculate_update_fee, transfer_sol_fees_with_platform, SolFeeAccounts, recommit_symbol_metadata,
+};
+
+/// Re-commit a symbol to its mint's current metadata name, URI and update authority.
+///
+/// Owners use this after a legitimate metadata change (rebrand, new logo,
+/// authority handover) so keepers stop treating it as drift. Costs the same
+/// fee as update_mint.
+#[derive(Accounts)]
+pub struct RecommitMetadataSol<'info> {
+    /// Shared Accounts
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
+        bump = token_account.bump,
+        has_one = owner @ TnsError::UnauthorizedOwner,
+    )]
+    pub token_account: Account<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    // Solana Payment Accounts
+
+    /// CHECK: Fee collector receives update fee
+    #[account(
+        mut,
+        address = config.fee_collector,
+    )]
+    pub fee_collector: AccountInfo<'info>,
+
+    /// CHECK: Pyth pull oracle price update account (owner verified against OracleConfig); a stale update falls back to a live fallback price
+    pub price_update: AccountInfo<'info>,
+
+    #[account(
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    /// Registered platform taking a share of the fee (required when platform_fee_bps > 0)
+    #[account(mut)]
+    pub platform: Option<Box<Account<'info, Platform>>>,
+
+    /// CHECK: Platform fee recipient - must be the platform's payout wallet
+    #[account(mut)]
+    pub platform_fee_account: Option<AccountInfo<'info>>,
+
+    /// Keeper vault - required when the revenue split has a keeper share
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Option<Box<Account<'info, KeeperVault>>>,
+
+    /// WSOL revenue vault - required when the revenue split has burn or staker shares
+    #[account(
+        mut,
+        seeds = [RevenueVault::SEED_PREFIX, WSOL_MINT.as_ref()],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Option<Box<Account<'info, RevenueVault>>>,
+
+    /// The symbol's current mint
+    #[account(address = token_account.mint @ TnsError::InvalidMint)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    /// The mint's metadata account
+    /// For Token-2022: pass mint as metadata (embedded metadata extension)
+    /// For classic SPL: pass Metaplex metadata PDA
+    /// CHECK: Validated via validate_mint_metadata helper
+    pub token_metadata: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<RecommitMetadataSol>, max_sol_cost: u64, platform_fee_bps: u16) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.config;
+
+    // Validate
+    validate_not_paused(config)?;
+    
+    validate_symbol_not_expired(&ctx.accounts.token_account, clock.unix_timestamp)?;
+
+    validate_no_open_dispute(&ctx.accounts.token_account)?;
+
+    // The ticker itself can't change - only name, URI and update authority
+    let metadata = validate_mint_metadata(
+        &ctx.accounts.token_metadata,
+        &ctx.accounts.token_mint.to_account_info(),
+        &ctx.accounts.token_account.symbol,
+    )?;
+
+    let commitment = &ctx.accounts.token_account.metadata_commitment;
+    let name_changed = !commitment.name_matches(&metadata.name);
+    let uri_changed = !commitment.uri_matches(&metadata.uri);
+    let update_authority_changed = !commitment.update_authority_matches(metadata.update_authority);
+
+    validate_platform_fee(ctx.accounts.platform.as_deref(), platform_fee_bps)?;
+
+    // Calculate and transfer fee
+    let fee = calculate_update_fee(
+        config,
+        clock.unix_timestamp,
+        &ctx.accounts.oracle_config,
+        &ctx.accounts.price_update,
+    )?;
+
+    // Validate slippage
+    validate_slippage(fee.fee_lamports, max_sol_cost)?;
+
+    // Transfer fees with optional platform fee split
+    let platform_fee_paid = transfer_sol_fees_with_platform(
+        SolFeeAccounts {
+            payer: &ctx.accounts.owner.to_account_info(),
+            fee_collector: &ctx.accounts.fee_collector,
+            keeper_vault: ctx.accounts.keeper_vault.as_deref(),
+            revenue_vault: ctx.accounts.revenue_vault.as_deref_mut(),
+            platform: ctx.accounts.platform.as_deref(),
+            platform_fee_account: ctx.accounts.platform_fee_account.as_ref(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        &config.get_revenue_split(),
+        fee.fee_lamports,
+        platform_fee_bps,
+    )?;
+
+    // Record the operation on the platform's counters
+    record_platform_volume(ctx.accounts.platform.as_deref_mut(), fee.fee_usd_micro, platform_fee_bps);
+
+    // Re-commit and clear any pending drift flag
+    recommit_symbol_metadata(
+        &mut ctx.accounts.token_account,
+        metadata.commitment(clock.unix_timestamp),
+    );
+
+    emit!(MetadataRecommitted {
+        token_account: ctx.accounts.token_account.key(),
+        symbol: ctx.accounts.token_account.symbol.clone(),
+        owner: ctx.accounts.owner.key(),
+        name_changed,
+        uri_changed,
+        update_authority_changed,
+        fee_paid: fee.fee_lamports,
+        platform_fee: platform_fee_paid,
+        sol_price_provider: Some(fee.price_provider),
+        committed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
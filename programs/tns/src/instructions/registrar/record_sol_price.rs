@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::{OracleConfig, SolCircuitTripped, SolPriceRecorded, SolPriceViolationRecorded, TnsError};
+use super::helpers::{load_price_update, read_pyth_price, price_to_micro, sol_price_guard_violation};
+
+/// Record the SOL/USD price as the deviation guard's last good price (permissionless crank)
+/// A price violating a guard fails, or is counted when OracleConfig.auto_pause
+/// is set. Each Pyth update counts once; trip_after_violations in a row trip
+/// the circuit breaker, and SOL/USD pricing then halts until the admin calls
+/// reset_sol_circuit. A good price clears the count.
+#[derive(Accounts)]
+pub struct RecordSolPrice<'info> {
+    /// Anyone can crank
+    pub cranker: Signer<'info>,
+
+    /// CHECK: Pyth pull oracle SOL/USD price update account (owner verified against OracleConfig)
+    pub price_update: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+}
+
+pub fn handler(ctx: Context<RecordSolPrice>) -> Result<()> {
+    let clock = Clock::get()?;
+    let oracle_config = &mut ctx.accounts.oracle_config;
+
+    require!(!oracle_config.sol_circuit_tripped, TnsError::SolPriceCircuitTripped);
+
+    let price_update = load_price_update(oracle_config, &ctx.accounts.price_update)?;
+    let price = read_pyth_price(oracle_config, &price_update, &oracle_config.sol_usd_feed_id)?;
+    let price_usd_micro = price_to_micro(&price);
+
+    // Only an update newer than the last counted violation counts, so one bad update can't be replayed
+    let uncounted_update = price.publish_time > oracle_config.last_violation_publish_time;
+
+    match sol_price_guard_violation(oracle_config, &price, clock.unix_timestamp) {
+        None => {
+            oracle_config.last_good_sol_price_micro = price_usd_micro;
+            oracle_config.last_good_sol_price_at = clock.unix_timestamp;
+            oracle_config.sol_violation_count = 0;
+
+            emit!(SolPriceRecorded {
+                oracle_config: oracle_config.key(),
+                cranker: ctx.accounts.cranker.key(),
+                price_usd_micro,
+                recorded_at: clock.unix_timestamp,
+            });
+        }
+        Some(violation) if oracle_config.auto_pause && uncounted_update => {
+            oracle_config.sol_violation_count = oracle_config.sol_violation_count.saturating_add(1);
+            oracle_config.last_violation_publish_time = price.publish_time;
+
+            let trip_after_violations = oracle_config.get_trip_after_violations();
+            if oracle_config.sol_violation_count < trip_after_violations {
+                emit!(SolPriceViolationRecorded {
+                    oracle_config: oracle_config.key(),
+                    cranker: ctx.accounts.cranker.key(),
+                    price_usd_micro,
+                    reason: violation.name(),
+                    violations: oracle_config.sol_violation_count,
+                    trip_after_violations,
+                    recorded_at: clock.unix_timestamp,
+                });
+
+                return Ok(());
+            }
+
+            oracle_config.sol_circuit_tripped = true;
+
+            emit!(SolCircuitTripped {
+                oracle_config: oracle_config.key(),
+                cranker: ctx.accounts.cranker.key(),
+                price_usd_micro,
+                last_good_price_usd_micro: oracle_config.last_good_sol_price_micro,
+                reason: violation.name(),
+                tripped_at: clock.unix_timestamp,
+            });
+        }
+        Some(violation) => return Err(error!(violation)),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,271 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{Config, OracleConfig, TnsPriceObservation, TnsPriceSources, KeeperVault, PaymentAsset, Token, SymbolRegistered, TnsError, Platform, RevenueVault, Treasury};
+use super::super::helpers::{
+    validate_not_paused, validate_symbol_format,
+    validate_and_calculate_expiration, validate_registration_access, validate_mint_metadata,
+    validate_eligibility, deposit_keeper_reward, initialize_token_account, validate_platform_fee, validate_bond_mode, record_platform_volume,
+    charge_token_fee, calculate_asset_amount, AssetPriceAccounts,
+    PlatformTokenFeeAccounts, SymbolInitData,
+};
+
+/// Register a new symbol paying with any enabled asset from the PaymentAsset registry
+#[derive(Accounts)]
+#[instruction(symbol: String, years: u8)]
+pub struct RegisterSymbolAsset<'info> {
+    /// Shared Accounts
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// Keeper vault receives the per-registration keeper deposit
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Box<Account<'info, KeeperVault>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Token::INIT_SPACE,
+        seeds = [Token::SEED_PREFIX, symbol.as_bytes()],
+        bump
+    )]
+    pub token_account: Box<Account<'info, Token>>,
+
+    /// The token mint being registered - validated as real SPL/Token-2022 mint
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Metaplex metadata account for token_mint - validated in handler
+    pub token_metadata: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    // Payment Asset Accounts
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        seeds = [PaymentAsset::SEED_PREFIX, payment_mint.key().as_ref()],
+        bump = payment_asset.bump,
+        constraint = payment_asset.enabled @ TnsError::PaymentAssetDisabled,
+    )]
+    pub payment_asset: Box<Account<'info, PaymentAsset>>,
+
+    /// Mutable so the burn share of a TNS fee can be burned
+    #[account(mut)]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = payer,
+    )]
+    pub payer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury PDA owning the fee vaults
+    #[account(
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump,
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    /// Treasury vault for the payment mint (the treasury PDA's associated token account)
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Registered platform taking a share of the fee (required when platform_fee_bps > 0)
+    #[account(mut)]
+    pub platform: Option<Box<Account<'info, Platform>>>,
+
+    /// Platform fee recipient token account - must be owned by the platform's payout wallet
+    #[account(mut)]
+    pub platform_fee_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Revenue vault for the payment mint - required when the revenue split has non-treasury shares
+    #[account(
+        mut,
+        seeds = [RevenueVault::SEED_PREFIX, payment_mint.key().as_ref()],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Option<Box<Account<'info, RevenueVault>>>,
+
+    /// Revenue vault token account - must be owned by revenue_vault
+    #[account(mut)]
+    pub revenue_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    // Price accounts, as required by the asset's price source
+    // TnsTwap: accounts for each configured pool follow as remaining_accounts
+
+    /// CHECK: Pyth feed for the asset (Pyth and Stablecoin sources) or SOL/USD (TnsTwap pools); owner verified against OracleConfig
+    pub price_update: Option<AccountInfo<'info>>,
+
+    #[account(
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    /// TWAP samples (TnsTwap source)
+    #[account(
+        seeds = [TnsPriceObservation::SEED_PREFIX],
+        bump = tns_price_observation.bump,
+    )]
+    pub tns_price_observation: Option<Box<Account<'info, TnsPriceObservation>>>,
+
+    /// Configured TNS spot price sources (TnsTwap source)
+    #[account(
+        seeds = [TnsPriceSources::SEED_PREFIX],
+        bump = tns_price_sources.bump,
+    )]
+    pub tns_price_sources: Option<Box<Account<'info, TnsPriceSources>>>,
+
+    /// CHECK: Pyth TNS/USD price update - required by the TnsTwap source when Config.tns_usd_pyth_feed is set; owner verified against OracleConfig
+    pub tns_usd_price_update: Option<AccountInfo<'info>>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RegisterSymbolAsset<'info>>,
+    symbol: String,
+    years: u8,
+    max_token_amount: u64,
+    platform_fee_bps: u16,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.config;
+    let mint = ctx.accounts.token_mint.key();
+
+    // Validate
+    validate_not_paused(config)?;
+
+    let normalized_symbol = validate_symbol_format(&symbol)?;
+
+    let expires_at = validate_and_calculate_expiration(
+        clock.unix_timestamp,
+        years,
+        clock.unix_timestamp,
+    )?;
+
+    validate_registration_access(
+        config,
+        &normalized_symbol,
+        &mint,
+        &ctx.accounts.payer.key(),
+        &ctx.accounts.token_mint,
+    )?;
+
+    let metadata = validate_mint_metadata(
+        &ctx.accounts.token_metadata,
+        &ctx.accounts.token_mint.to_account_info(),
+        &normalized_symbol,
+    )?;
+
+    validate_eligibility(
+        &config.eligibility_policy,
+        &ctx.accounts.token_metadata,
+        &ctx.accounts.token_mint,
+    )?;
+
+    validate_platform_fee(ctx.accounts.platform.as_deref(), platform_fee_bps)?;
+
+    // Bond mode is SOL-only; token payments can't post a bond
+    validate_bond_mode(config, false, platform_fee_bps)?;
+
+    // Owner is the payer, not the mint's update_authority
+    let owner = ctx.accounts.payer.key();
+
+    // Calculate fee in USD, then convert at the asset's price after its discount
+    let fee_usd_micro = config.calculate_registration_price_usd(clock.unix_timestamp, years);
+    let token_amount = calculate_asset_amount(
+        &ctx.accounts.payment_asset,
+        fee_usd_micro,
+        &AssetPriceAccounts {
+            config,
+            oracle_config: &ctx.accounts.oracle_config,
+            price_update: ctx.accounts.price_update.as_ref(),
+            tns_price_observation: ctx.accounts.tns_price_observation.as_deref(),
+            tns_price_sources: ctx.accounts.tns_price_sources.as_deref(),
+            tns_usd_price_update: ctx.accounts.tns_usd_price_update.as_ref(),
+            pool_accounts: ctx.remaining_accounts,
+            current_time: clock.unix_timestamp,
+        },
+    )?;
+
+    // Transfer with optional platform fee split
+    let platform_fee_paid = charge_token_fee(
+        PlatformTokenFeeAccounts {
+            payer: &ctx.accounts.payer,
+            payer_seeds: &[],
+            payer_token_account: &ctx.accounts.payer_token_account,
+            vault: &ctx.accounts.treasury_vault,
+            platform: ctx.accounts.platform.as_deref(),
+            platform_token_account: ctx.accounts.platform_fee_account.as_deref(),
+            revenue_vault: ctx.accounts.revenue_vault.as_deref_mut(),
+            revenue_token_account: ctx.accounts.revenue_token_account.as_deref(),
+            revenue_split: config.get_revenue_split(),
+            mint: &ctx.accounts.payment_mint,
+            token_program: &ctx.accounts.token_program,
+        },
+        ctx.accounts.token_account.key(),
+        token_amount,
+        max_token_amount,
+        platform_fee_bps,
+    )?;
+
+    // Record the operation on the platform's counters
+    record_platform_volume(ctx.accounts.platform.as_deref_mut(), fee_usd_micro, platform_fee_bps);
+
+    // Deposit keeper reward in SOL into the keeper vault
+    let keeper_deposit = deposit_keeper_reward(
+        &ctx.accounts.payer.to_account_info(),
+        &mut ctx.accounts.keeper_vault,
+        &ctx.accounts.system_program.to_account_info(),
+        config.get_keeper_reward_lamports(),
+    )?;
+
+    // Initialize symbol - owner is the payer
+    initialize_token_account(
+        &mut ctx.accounts.token_account,
+        SymbolInitData {
+            symbol: normalized_symbol.clone(),
+            mint,
+            owner,
+            current_time: clock.unix_timestamp,
+            expires_at,
+            bump: ctx.bumps.token_account,
+            metadata_commitment: metadata.commitment(clock.unix_timestamp),
+            keeper_deposit,
+            price_lock: ctx.accounts.config.new_price_lock(clock.unix_timestamp),
+            bond_lamports: 0,
+        },
+    );
+
+    emit!(SymbolRegistered {
+        token_account: ctx.accounts.token_account.key(),
+        symbol: normalized_symbol,
+        mint,
+        owner,
+        years,
+        fee_paid: token_amount,
+        platform_fee: platform_fee_paid,
+        sol_price_provider: None,
+        bond_posted: 0,
+        registered_at: clock.unix_timestamp,
+        expires_at,
+    });
+
+    Ok(())
+}
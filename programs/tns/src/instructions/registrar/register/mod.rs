@@ -1,14 +1,8 @@
 pub mod sol;
-pub mod tns;
-pub mod usdc;
-pub mod usdt;
+pub mod asset;
 
 pub use sol::RegisterSymbolSol;
-pub use tns::RegisterSymbolTns;
-pub use usdc::RegisterSymbolUsdc;
-pub use usdt::RegisterSymbolUsdt;
+pub use asset::RegisterSymbolAsset;
 
 pub(crate) use sol::__client_accounts_register_symbol_sol;
-pub(crate) use tns::__client_accounts_register_symbol_tns;
-pub(crate) use usdc::__client_accounts_register_symbol_usdc;
-pub(crate) use usdt::__client_accounts_register_symbol_usdt;
+pub(crate) use asset::__client_accounts_register_symbol_asset;
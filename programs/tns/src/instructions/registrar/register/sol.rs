@@ -1,12 +1,14 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
-use crate::{Config, Token, SymbolRegistered};
+use crate::{
+    BondVault, Config, OracleConfig, KeeperVault, Token, SymbolRegistered, Platform, RevenueVault, WSOL_MINT,
+};
 use super::super::helpers::{
     validate_not_paused, validate_symbol_format,
     validate_and_calculate_expiration, validate_registration_access, validate_mint_metadata,
-    calculate_fees_sol, transfer_sol_fees_with_platform, initialize_token_account,
-    validate_slippage, validate_platform_fee_bps, SymbolInitData,
+    validate_eligibility, deposit_keeper_reward, deposit_bond, calculate_fees_sol,
+    transfer_sol_fees_with_platform, SolFeeAccounts, initialize_token_account,
+    validate_slippage, validate_platform_fee, validate_bond_mode, record_platform_volume, SymbolInitData,
 };
 
 #[derive(Accounts)]
@@ -17,14 +19,28 @@ pub struct RegisterSymbolSol<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    /// Config also holds keeper reward lamports
     #[account(
-        mut,
         seeds = [Config::SEED_PREFIX],
         bump = config.bump,
     )]
     pub config: Account<'info, Config>,
 
+    /// Keeper vault receives the per-registration keeper deposit
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Account<'info, KeeperVault>,
+
+    /// Bond vault locks the registration price in bond pricing mode (required in bond mode)
+    #[account(
+        mut,
+        seeds = [BondVault::SEED_PREFIX],
+        bump = bond_vault.bump,
+    )]
+    pub bond_vault: Option<Box<Account<'info, BondVault>>>,
+
     #[account(
         init,
         payer = payer,
@@ -51,13 +67,30 @@ pub struct RegisterSymbolSol<'info> {
     )]
     pub fee_collector: AccountInfo<'info>,
 
-    /// Pyth pull oracle price update account (ownership verified by SDK)
-    pub price_update: Account<'info, PriceUpdateV2>,
+    /// CHECK: Pyth pull oracle price update account (owner verified against OracleConfig); a stale update falls back to a live fallback price
+    pub price_update: AccountInfo<'info>,
+
+    #[account(
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    /// Registered platform taking a share of the fee (required when platform_fee_bps > 0)
+    #[account(mut)]
+    pub platform: Option<Box<Account<'info, Platform>>>,
 
-    /// CHECK: Optional platform fee recipient (e.g., launchpad, referrer)
-    /// If provided with non-zero platform_fee_bps, receives a portion of the fee
+    /// CHECK: Platform fee recipient - must be the platform's payout wallet
     #[account(mut)]
     pub platform_fee_account: Option<AccountInfo<'info>>,
+
+    /// WSOL revenue vault - required when the revenue split has burn or staker shares
+    #[account(
+        mut,
+        seeds = [RevenueVault::SEED_PREFIX, WSOL_MINT.as_ref()],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Option<Box<Account<'info, RevenueVault>>>,
 }
 
 pub fn handler(
@@ -90,13 +123,21 @@ pub fn handler(
         &ctx.accounts.token_mint,
     )?;
 
-    validate_mint_metadata(
+    let metadata = validate_mint_metadata(
         &ctx.accounts.token_metadata,
         &ctx.accounts.token_mint.to_account_info(),
         &normalized_symbol,
     )?;
 
-    validate_platform_fee_bps(platform_fee_bps)?;
+    validate_eligibility(
+        &config.eligibility_policy,
+        &ctx.accounts.token_metadata,
+        &ctx.accounts.token_mint,
+    )?;
+
+    validate_platform_fee(ctx.accounts.platform.as_deref(), platform_fee_bps)?;
+
+    validate_bond_mode(config, true, platform_fee_bps)?;
 
     // Owner is the payer, not the mint's update_authority
     let owner = ctx.accounts.payer.key();
@@ -106,32 +147,51 @@ pub fn handler(
         config,
         clock.unix_timestamp,
         years,
+        &ctx.accounts.oracle_config,
         &ctx.accounts.price_update,
     )?;
 
-    // Validate slippage (fee + keeper reward)
+    // Validate slippage (fee or bond + keeper reward)
     let total_cost = fees.fee_lamports + fees.keeper_reward_lamports;
     validate_slippage(total_cost, max_sol_cost)?;
 
-    // Transfer fees with optional platform fee split
-    let platform_fee_paid = transfer_sol_fees_with_platform(
+    // In bond mode the registration price is locked as a refundable bond instead of paid as a fee
+    let (platform_fee_paid, bond_posted) = if config.bond_policy.enabled {
+        let bond_posted = deposit_bond(
+            &ctx.accounts.payer.to_account_info(),
+            ctx.accounts.bond_vault.as_deref_mut(),
+            &ctx.accounts.system_program.to_account_info(),
+            fees.fee_lamports,
+        )?;
+        (0, bond_posted)
+    } else {
+        // Transfer fees with optional platform fee split
+        let platform_fee_paid = transfer_sol_fees_with_platform(
+            SolFeeAccounts {
+                payer: &ctx.accounts.payer.to_account_info(),
+                fee_collector: &ctx.accounts.fee_collector,
+                keeper_vault: Some(&ctx.accounts.keeper_vault),
+                revenue_vault: ctx.accounts.revenue_vault.as_deref_mut(),
+                platform: ctx.accounts.platform.as_deref(),
+                platform_fee_account: ctx.accounts.platform_fee_account.as_ref(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+            },
+            &config.get_revenue_split(),
+            fees.fee_lamports,
+            platform_fee_bps,
+        )?;
+        (platform_fee_paid, 0)
+    };
+
+    // Record the operation on the platform's counters
+    let fee_usd_micro = config.calculate_registration_price_usd(clock.unix_timestamp, years);
+    record_platform_volume(ctx.accounts.platform.as_deref_mut(), fee_usd_micro, platform_fee_bps);
+
+    // Deposit keeper reward into the keeper vault
+    let keeper_deposit = deposit_keeper_reward(
         &ctx.accounts.payer.to_account_info(),
-        &ctx.accounts.fee_collector,
-        ctx.accounts.platform_fee_account.as_ref(),
+        &mut ctx.accounts.keeper_vault,
         &ctx.accounts.system_program.to_account_info(),
-        fees.fee_lamports,
-        platform_fee_bps,
-    )?;
-
-    // Transfer keeper reward to Config PDA
-    anchor_lang::system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            anchor_lang::system_program::Transfer {
-                from: ctx.accounts.payer.to_account_info(),
-                to: ctx.accounts.config.to_account_info(),
-            },
-        ),
         fees.keeper_reward_lamports,
     )?;
 
@@ -145,6 +205,10 @@ pub fn handler(
             current_time: clock.unix_timestamp,
             expires_at,
             bump: ctx.bumps.token_account,
+            metadata_commitment: metadata.commitment(clock.unix_timestamp),
+            keeper_deposit,
+            price_lock: ctx.accounts.config.new_price_lock(clock.unix_timestamp),
+            bond_lamports: bond_posted,
         },
     );
 
@@ -154,8 +218,10 @@ pub fn handler(
         mint,
         owner,
         years,
-        fee_paid: total_cost,
+        fee_paid: total_cost - bond_posted,
         platform_fee: platform_fee_paid,
+        sol_price_provider: Some(fees.price_provider),
+        bond_posted,
         registered_at: clock.unix_timestamp,
         expires_at,
     });
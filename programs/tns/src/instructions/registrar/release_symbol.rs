@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::{BondVault, Config, KeeperVault, Token, SymbolReleased, TnsError};
+use super::helpers::{
+    validate_not_paused, validate_no_open_dispute, validate_symbol_releasable,
+    pay_keeper_deposit, refund_bond,
+};
+
+/// Voluntarily release a symbol (owner only, while active and not drift-flagged)
+/// Closes the account immediately, returning rent plus the symbol's keeper deposit and bond to the owner
+/// The symbol becomes available for fresh registration
+#[derive(Accounts)]
+pub struct ReleaseSymbol<'info> {
+    /// Current owner - receives rent + keeper deposit + bond
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Keeper vault refunds the symbol's keeper deposit
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Account<'info, KeeperVault>,
+
+    /// Bond vault refunds the symbol's bond (required when the symbol has a bond)
+    #[account(
+        mut,
+        seeds = [BondVault::SEED_PREFIX],
+        bump = bond_vault.bump,
+    )]
+    pub bond_vault: Option<Account<'info, BondVault>>,
+
+    #[account(
+        mut,
+        seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
+        bump = token_account.bump,
+        has_one = owner @ TnsError::UnauthorizedOwner,
+        close = owner,
+    )]
+    pub token_account: Account<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ReleaseSymbol>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Validate not paused
+    validate_not_paused(&ctx.accounts.config)?;
+
+    validate_no_open_dispute(&ctx.accounts.token_account)?;
+
+    // Only live, unflagged symbols - otherwise the deposit and bond belong to the keeper path
+    validate_symbol_releasable(&ctx.accounts.token_account, clock.unix_timestamp)?;
+
+    // Capture rent before any transfers (closure happens at end via Anchor constraint)
+    let rent_returned = ctx.accounts.token_account.to_account_info().lamports();
+
+    // Refund the keeper deposit paid at registration
+    let deposit_refunded = pay_keeper_deposit(
+        &mut ctx.accounts.keeper_vault,
+        &ctx.accounts.owner.to_account_info(),
+        ctx.accounts.token_account.keeper_deposit,
+    )?;
+
+    // Refund the bond posted in bond pricing mode
+    let bond_refunded = refund_bond(
+        ctx.accounts.bond_vault.as_mut(),
+        &ctx.accounts.owner.to_account_info(),
+        ctx.accounts.token_account.bond_lamports,
+    )?;
+
+    emit!(SymbolReleased {
+        token_account: ctx.accounts.token_account.key(),
+        symbol: ctx.accounts.token_account.symbol.clone(),
+        owner: ctx.accounts.owner.key(),
+        mint: ctx.accounts.token_account.mint,
+        released_at: clock.unix_timestamp,
+        rent_returned,
+        deposit_refunded,
+        bond_refunded,
+    });
+
+    // Account closure is handled by Anchor's close = owner constraint
+    Ok(())
+}
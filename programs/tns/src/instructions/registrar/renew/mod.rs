@@ -1,14 +1,8 @@
 pub mod sol;
-pub mod tns;
-pub mod usdc;
-pub mod usdt;
+pub mod asset;
 
 pub use sol::RenewSymbolSol;
-pub use tns::RenewSymbolTns;
-pub use usdc::RenewSymbolUsdc;
-pub use usdt::RenewSymbolUsdt;
+pub use asset::RenewSymbolAsset;
 
 pub(crate) use sol::__client_accounts_renew_symbol_sol;
-pub(crate) use tns::__client_accounts_renew_symbol_tns;
-pub(crate) use usdc::__client_accounts_renew_symbol_usdc;
-pub(crate) use usdt::__client_accounts_renew_symbol_usdt;
+pub(crate) use asset::__client_accounts_renew_symbol_asset;
@@ -1,10 +1,9 @@
 use anchor_lang::prelude::*;
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
-use crate::{Config, Token, SymbolRenewed};
+use crate::{Config, OracleConfig, Token, SymbolRenewed, Platform, KeeperVault, RevenueVault, WSOL_MINT};
 use super::super::helpers::{
     validate_not_paused, validate_symbol_not_expired,
-    validate_and_calculate_expiration, validate_slippage, validate_platform_fee_bps,
-    calculate_fees_sol, transfer_sol_fees_with_platform, update_symbol_on_renewal,
+    validate_and_calculate_expiration, validate_slippage, validate_platform_fee, record_platform_volume,
+    get_sol_price, transfer_sol_fees_with_platform, SolFeeAccounts, update_symbol_on_renewal,
 };
 
 #[derive(Accounts)]
@@ -38,12 +37,38 @@ pub struct RenewSymbolSol<'info> {
     )]
     pub fee_collector: AccountInfo<'info>,
 
-    /// Pyth pull oracle price update account (ownership verified by SDK)
-    pub price_update: Account<'info, PriceUpdateV2>,
+    /// CHECK: Pyth pull oracle price update account (owner verified against OracleConfig); a stale update falls back to a live fallback price
+    pub price_update: AccountInfo<'info>,
 
-    /// CHECK: Optional platform fee recipient
+    #[account(
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    /// Registered platform taking a share of the fee (required when platform_fee_bps > 0)
+    #[account(mut)]
+    pub platform: Option<Box<Account<'info, Platform>>>,
+
+    /// CHECK: Platform fee recipient - must be the platform's payout wallet
     #[account(mut)]
     pub platform_fee_account: Option<AccountInfo<'info>>,
+
+    /// Keeper vault - required when the revenue split has a keeper share
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Option<Box<Account<'info, KeeperVault>>>,
+
+    /// WSOL revenue vault - required when the revenue split has burn or staker shares
+    #[account(
+        mut,
+        seeds = [RevenueVault::SEED_PREFIX, WSOL_MINT.as_ref()],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Option<Box<Account<'info, RevenueVault>>>,
 }
 
 pub fn handler(
@@ -68,29 +93,35 @@ pub fn handler(
         clock.unix_timestamp,
     )?;
 
-    validate_platform_fee_bps(platform_fee_bps)?;
+    validate_platform_fee(ctx.accounts.platform.as_deref(), platform_fee_bps)?;
 
-    // Calculate fees (no keeper reward for renewals)
-    let fees = calculate_fees_sol(
-        config,
-        clock.unix_timestamp,
-        years,
-        &ctx.accounts.price_update,
-    )?;
+    // Calculate renewal fee, honoring any price lock (no keeper reward for renewals)
+    let sol_price = get_sol_price(&ctx.accounts.oracle_config, &ctx.accounts.price_update, clock.unix_timestamp)?;
+    let fee_usd_micro = config.calculate_renewal_price_usd(&ctx.accounts.token_account, clock.unix_timestamp, years);
+    let fee_lamports = config.usd_to_lamports(fee_usd_micro, sol_price.price_micro);
 
     // Validate slippage (fee only, no keeper reward for renewals)
-    validate_slippage(fees.fee_lamports, max_sol_cost)?;
+    validate_slippage(fee_lamports, max_sol_cost)?;
 
     // Transfer fee with optional platform fee split
     let platform_fee_paid = transfer_sol_fees_with_platform(
-        &ctx.accounts.payer.to_account_info(),
-        &ctx.accounts.fee_collector,
-        ctx.accounts.platform_fee_account.as_ref(),
-        &ctx.accounts.system_program.to_account_info(),
-        fees.fee_lamports,
+        SolFeeAccounts {
+            payer: &ctx.accounts.payer.to_account_info(),
+            fee_collector: &ctx.accounts.fee_collector,
+            keeper_vault: ctx.accounts.keeper_vault.as_deref(),
+            revenue_vault: ctx.accounts.revenue_vault.as_deref_mut(),
+            platform: ctx.accounts.platform.as_deref(),
+            platform_fee_account: ctx.accounts.platform_fee_account.as_ref(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        &config.get_revenue_split(),
+        fee_lamports,
         platform_fee_bps,
     )?;
 
+    // Record the operation on the platform's counters
+    record_platform_volume(ctx.accounts.platform.as_deref_mut(), fee_usd_micro, platform_fee_bps);
+
     // Update symbol
     update_symbol_on_renewal(
         &mut ctx.accounts.token_account,
@@ -103,8 +134,9 @@ pub fn handler(
         renewed_by: ctx.accounts.payer.key(),
         owner: ctx.accounts.token_account.owner,
         years,
-        fee_paid: fees.fee_lamports,
+        fee_paid: fee_lamports,
         platform_fee: platform_fee_paid,
+        sol_price_provider: Some(sol_price.provider),
         old_expires_at,
         new_expires_at,
         renewed_at: clock.unix_timestamp,
@@ -1,119 +0,0 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
-use crate::{Config, Token, SymbolRenewed, TnsError, USDT_MINT};
-use super::super::helpers::{
-    validate_not_paused, validate_symbol_not_expired,
-    validate_and_calculate_expiration, validate_platform_fee_bps,
-    transfer_token_fees_with_platform, PlatformTokenFeeAccounts,
-    update_symbol_on_renewal,
-};
-
-#[derive(Accounts)]
-pub struct RenewSymbolUsdt<'info> {
-    /// Shared Accounts
-
-    #[account(mut)]
-    pub payer: Signer<'info>,
-
-    #[account(
-        seeds = [Config::SEED_PREFIX],
-        bump = config.bump,
-    )]
-    pub config: Account<'info, Config>,
-
-    #[account(
-        mut,
-        seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
-        bump = token_account.bump,
-    )]
-    pub token_account: Account<'info, Token>,
-
-    pub system_program: Program<'info, System>,
-
-    // USDT Payment Accounts
-
-    pub token_program: Interface<'info, TokenInterface>,
-
-    #[account(address = USDT_MINT @ TnsError::InvalidMint)]
-    pub usdt_mint: InterfaceAccount<'info, Mint>,
-
-    #[account(
-        mut,
-        token::mint = usdt_mint,
-        token::authority = payer,
-    )]
-    pub payer_usdt_account: InterfaceAccount<'info, TokenAccount>,
-
-    /// Fee collector's USDT token account (ATA must be created by admin beforehand)
-    #[account(
-        mut,
-        token::mint = usdt_mint,
-    )]
-    pub fee_collector_ata: InterfaceAccount<'info, TokenAccount>,
-
-    /// CHECK: Platform fee recipient token account. Validated in handler if provided.
-    #[account(mut)]
-    pub platform_fee_account: Option<AccountInfo<'info>>,
-}
-
-pub fn handler(ctx: Context<RenewSymbolUsdt>, years: u8, platform_fee_bps: u16) -> Result<()> {
-    let clock = Clock::get()?;
-    let config = &ctx.accounts.config;
-
-    // Validate
-    validate_not_paused(config)?;
-
-    validate_symbol_not_expired(&ctx.accounts.token_account, clock.unix_timestamp)?;
-
-    let old_expires_at = ctx.accounts.token_account.expires_at;
-
-    let new_expires_at = validate_and_calculate_expiration(
-        old_expires_at,
-        years,
-        clock.unix_timestamp,
-    )?;
-
-    validate_platform_fee_bps(platform_fee_bps)?;
-
-    // Calculate fee in USD (no Pyth needed - USDT = $1)
-    // No keeper reward for renewals
-    let fee_usd_micro = config.calculate_registration_price_usd(clock.unix_timestamp, years);
-
-    // Convert USD to token amount (1 USDT = $1)
-    let token_amount = fee_usd_micro;
-
-    // Transfer USDT with optional platform fee split
-    let platform_fee_paid = transfer_token_fees_with_platform(
-        &PlatformTokenFeeAccounts {
-            payer: &ctx.accounts.payer,
-            payer_token_account: &ctx.accounts.payer_usdt_account,
-            vault: &ctx.accounts.fee_collector_ata,
-            platform_token_account: ctx.accounts.platform_fee_account.as_ref(),
-            mint: &ctx.accounts.usdt_mint,
-            token_program: &ctx.accounts.token_program,
-        },
-        token_amount,
-        platform_fee_bps,
-    )?;
-
-    // Update symbol
-    update_symbol_on_renewal(
-        &mut ctx.accounts.token_account,
-        new_expires_at,
-    );
-
-    emit!(SymbolRenewed {
-        token_account: ctx.accounts.token_account.key(),
-        symbol: ctx.accounts.token_account.symbol.clone(),
-        renewed_by: ctx.accounts.payer.key(),
-        owner: ctx.accounts.token_account.owner,
-        years,
-        fee_paid: token_amount,
-        platform_fee: platform_fee_paid,
-        old_expires_at,
-        new_expires_at,
-        renewed_at: clock.unix_timestamp,
-    });
-
-    Ok(())
-}
@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
-use crate::{Config, Token, OwnershipTransferred, TnsError};
-use super::helpers::validate_not_paused;
+use crate::{BondVault, Config, KeeperVault, Token, OwnershipTransferred, TnsError};
+use super::helpers::{validate_not_paused, validate_no_open_dispute, refund_owner_deposits};
 
+/// Transfer a symbol to a new owner
+/// The outgoing owner is refunded the symbol's keeper deposit and bond
 #[derive(Accounts)]
 pub struct TransferOwnership<'info> {
+    /// Current owner - receives the symbol's keeper deposit and bond
+    #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
@@ -19,6 +23,22 @@ pub struct TransferOwnership<'info> {
         has_one = owner @ TnsError::UnauthorizedOwner,
     )]
     pub token_account: Account<'info, Token>,
+
+    /// Keeper vault refunds the symbol's keeper deposit to the old owner
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Account<'info, KeeperVault>,
+
+    /// Bond vault refunds the symbol's bond to the old owner (required when the symbol has a bond)
+    #[account(
+        mut,
+        seeds = [BondVault::SEED_PREFIX],
+        bump = bond_vault.bump,
+    )]
+    pub bond_vault: Option<Account<'info, BondVault>>,
 }
 
 pub fn handler(ctx: Context<TransferOwnership>, new_owner: Pubkey) -> Result<()> {
@@ -27,14 +47,24 @@ pub fn handler(ctx: Context<TransferOwnership>, new_owner: Pubkey) -> Result<()>
     // Validate not paused
     validate_not_paused(&ctx.accounts.config)?;
 
+    validate_no_open_dispute(&ctx.accounts.token_account)?;
+
     // Ensure new owner is different
     require!(ctx.accounts.token_account.owner != new_owner, TnsError::SameOwner);
 
     // Capture old owner before mutation
     let old_owner = ctx.accounts.token_account.owner;
 
-    // Transfer ownership
+    // Transfer ownership (a voluntary transfer carries no claim precedence)
     ctx.accounts.token_account.owner = new_owner;
+    ctx.accounts.token_account.clear_claim();
+
+    let (deposit_refunded, bond_refunded) = refund_owner_deposits(
+        &mut ctx.accounts.token_account,
+        &mut ctx.accounts.keeper_vault,
+        ctx.accounts.bond_vault.as_mut(),
+        &ctx.accounts.owner.to_account_info(),
+    )?;
 
     emit!(OwnershipTransferred {
         token_account: ctx.accounts.token_account.key(),
@@ -42,6 +72,8 @@ pub fn handler(ctx: Context<TransferOwnership>, new_owner: Pubkey) -> Result<()>
         old_owner,
         new_owner,
         transferred_at: clock.unix_timestamp,
+        deposit_refunded,
+        bond_refunded,
     });
 
     Ok(())
@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{Config, OracleConfig, TnsPriceObservation, TnsPriceSources, PaymentAsset, Token, MintUpdated, TnsError, Platform, RevenueVault, Treasury};
+use super::super::helpers::{
+    validate_not_paused, validate_symbol_not_expired, validate_no_open_dispute,
+    validate_mint_different, validate_mint_metadata, validate_eligibility, validate_platform_fee,
+    record_platform_volume, charge_token_fee, calculate_asset_amount, AssetPriceAccounts,
+    PlatformTokenFeeAccounts, update_symbol_mint,
+};
+
+/// Update a symbol's mint paying with any enabled asset from the PaymentAsset registry
+#[derive(Accounts)]
+pub struct UpdateMintAsset<'info> {
+    /// Shared Accounts
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
+        bump = token_account.bump,
+        has_one = owner @ TnsError::UnauthorizedOwner,
+    )]
+    pub token_account: Box<Account<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+
+    // Payment Asset Accounts
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        seeds = [PaymentAsset::SEED_PREFIX, payment_mint.key().as_ref()],
+        bump = payment_asset.bump,
+        constraint = payment_asset.enabled @ TnsError::PaymentAssetDisabled,
+    )]
+    pub payment_asset: Box<Account<'info, PaymentAsset>>,
+
+    /// Mutable so the burn share of a TNS fee can be burned
+    #[account(mut)]
+    pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury PDA owning the fee vaults
+    #[account(
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump,
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    /// Treasury vault for the payment mint (the treasury PDA's associated token account)
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Registered platform taking a share of the fee (required when platform_fee_bps > 0)
+    #[account(mut)]
+    pub platform: Option<Box<Account<'info, Platform>>>,
+
+    /// Platform fee recipient token account - must be owned by the platform's payout wallet
+    #[account(mut)]
+    pub platform_fee_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Revenue vault for the payment mint - required when the revenue split has non-treasury shares
+    #[account(
+        mut,
+        seeds = [RevenueVault::SEED_PREFIX, payment_mint.key().as_ref()],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Option<Box<Account<'info, RevenueVault>>>,
+
+    /// Revenue vault token account - must be owned by revenue_vault
+    #[account(mut)]
+    pub revenue_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The new mint to update the symbol to (validated as a real mint)
+    pub new_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Metaplex metadata account for new_mint - validated in handler
+    pub new_mint_metadata: AccountInfo<'info>,
+
+    // Price accounts, as required by the asset's price source
+    // TnsTwap: accounts for each configured pool follow as remaining_accounts
+
+    /// CHECK: Pyth feed for the asset (Pyth and Stablecoin sources) or SOL/USD (TnsTwap pools); owner verified against OracleConfig
+    pub price_update: Option<AccountInfo<'info>>,
+
+    #[account(
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    /// TWAP samples (TnsTwap source)
+    #[account(
+        seeds = [TnsPriceObservation::SEED_PREFIX],
+        bump = tns_price_observation.bump,
+    )]
+    pub tns_price_observation: Option<Box<Account<'info, TnsPriceObservation>>>,
+
+    /// Configured TNS spot price sources (TnsTwap source)
+    #[account(
+        seeds = [TnsPriceSources::SEED_PREFIX],
+        bump = tns_price_sources.bump,
+    )]
+    pub tns_price_sources: Option<Box<Account<'info, TnsPriceSources>>>,
+
+    /// CHECK: Pyth TNS/USD price update - required by the TnsTwap source when Config.tns_usd_pyth_feed is set; owner verified against OracleConfig
+    pub tns_usd_price_update: Option<AccountInfo<'info>>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateMintAsset<'info>>,
+    max_token_amount: u64,
+    platform_fee_bps: u16,
+) -> Result<()> {
+    let new_mint = ctx.accounts.new_mint.key();
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.config;
+
+    // Validate
+    validate_not_paused(config)?;
+
+    validate_symbol_not_expired(&ctx.accounts.token_account, clock.unix_timestamp)?;
+
+    validate_no_open_dispute(&ctx.accounts.token_account)?;
+
+    validate_mint_different(&ctx.accounts.token_account.mint, &new_mint)?;
+
+    // Validate metadata matches - owner unchanged (already verified as signer)
+    let metadata = validate_mint_metadata(
+        &ctx.accounts.new_mint_metadata,
+        &ctx.accounts.new_mint.to_account_info(),
+        &ctx.accounts.token_account.symbol,
+    )?;
+
+    validate_eligibility(
+        &ctx.accounts.config.eligibility_policy,
+        &ctx.accounts.new_mint_metadata,
+        &ctx.accounts.new_mint,
+    )?;
+
+    validate_platform_fee(ctx.accounts.platform.as_deref(), platform_fee_bps)?;
+
+    // Calculate fee in USD, then convert at the asset's price after its discount
+    let yearly_price_usd_micro = config.get_current_yearly_price_usd(clock.unix_timestamp);
+    let fee_usd_micro = yearly_price_usd_micro * config.update_fee_bps as u64 / 10000;
+    let token_amount = calculate_asset_amount(
+        &ctx.accounts.payment_asset,
+        fee_usd_micro,
+        &AssetPriceAccounts {
+            config,
+            oracle_config: &ctx.accounts.oracle_config,
+            price_update: ctx.accounts.price_update.as_ref(),
+            tns_price_observation: ctx.accounts.tns_price_observation.as_deref(),
+            tns_price_sources: ctx.accounts.tns_price_sources.as_deref(),
+            tns_usd_price_update: ctx.accounts.tns_usd_price_update.as_ref(),
+            pool_accounts: ctx.remaining_accounts,
+            current_time: clock.unix_timestamp,
+        },
+    )?;
+
+    // Capture old mint before mutation
+    let old_mint = ctx.accounts.token_account.mint;
+
+    // Transfer fee with optional platform fee split
+    let platform_fee_paid = charge_token_fee(
+        PlatformTokenFeeAccounts {
+            payer: &ctx.accounts.owner,
+            payer_seeds: &[],
+            payer_token_account: &ctx.accounts.owner_token_account,
+            vault: &ctx.accounts.treasury_vault,
+            platform: ctx.accounts.platform.as_deref(),
+            platform_token_account: ctx.accounts.platform_fee_account.as_deref(),
+            revenue_vault: ctx.accounts.revenue_vault.as_deref_mut(),
+            revenue_token_account: ctx.accounts.revenue_token_account.as_deref(),
+            revenue_split: config.get_revenue_split(),
+            mint: &ctx.accounts.payment_mint,
+            token_program: &ctx.accounts.token_program,
+        },
+        ctx.accounts.token_account.key(),
+        token_amount,
+        max_token_amount,
+        platform_fee_bps,
+    )?;
+
+    // Record the operation on the platform's counters
+    record_platform_volume(ctx.accounts.platform.as_deref_mut(), fee_usd_micro, platform_fee_bps);
+
+    // Update mint
+    update_symbol_mint(
+        &mut ctx.accounts.token_account,
+        new_mint,
+        metadata.commitment(clock.unix_timestamp),
+    );
+
+    emit!(MintUpdated {
+        token_account: ctx.accounts.token_account.key(),
+        symbol: ctx.accounts.token_account.symbol.clone(),
+        old_mint,
+        new_mint,
+        owner: ctx.accounts.owner.key(),
+        fee_paid: token_amount,
+        platform_fee: platform_fee_paid,
+        sol_price_provider: None,
+        updated_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
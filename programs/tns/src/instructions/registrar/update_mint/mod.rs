@@ -1,14 +1,8 @@
 pub mod sol;
-pub mod tns;
-pub mod usdc;
-pub mod usdt;
+pub mod asset;
 
 pub use sol::UpdateMintSol;
-pub use tns::UpdateMintTns;
-pub use usdc::UpdateMintUsdc;
-pub use usdt::UpdateMintUsdt;
+pub use asset::UpdateMintAsset;
 
 pub(crate) use sol::__client_accounts_update_mint_sol;
-pub(crate) use tns::__client_accounts_update_mint_tns;
-pub(crate) use usdc::__client_accounts_update_mint_usdc;
-pub(crate) use usdt::__client_accounts_update_mint_usdt;
+pub(crate) use asset::__client_accounts_update_mint_asset;
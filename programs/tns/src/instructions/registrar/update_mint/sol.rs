@@ -1,11 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
-use crate::{Config, Token, MintUpdated, TnsError};
+use crate::{Config, OracleConfig, Token, MintUpdated, TnsError, Platform, KeeperVault, RevenueVault, WSOL_MINT};
 use super::super::helpers::{
-    validate_not_paused, validate_symbol_not_expired, validate_mint_different, validate_mint_metadata,
-    validate_slippage, validate_platform_fee_bps, calculate_update_fee,
-    transfer_sol_fees_with_platform, update_symbol_mint,
+    validate_not_paused, validate_symbol_not_expired, validate_no_open_dispute,
+    validate_mint_different, validate_mint_metadata, validate_eligibility, validate_slippage,
+    validate_platform_fee, record_platform_volume, calculate_update_fee,
+    transfer_sol_fees_with_platform, SolFeeAccounts, update_symbol_mint,
 };
 
 #[derive(Accounts)]
@@ -40,13 +40,39 @@ pub struct UpdateMintSol<'info> {
     )]
     pub fee_collector: AccountInfo<'info>,
 
-    /// Pyth pull oracle price update account (ownership verified by SDK)
-    pub price_update: Account<'info, PriceUpdateV2>,
+    /// CHECK: Pyth pull oracle price update account (owner verified against OracleConfig); a stale update falls back to a live fallback price
+    pub price_update: AccountInfo<'info>,
 
-    /// CHECK: Optional platform fee recipient (launchpad/referrer)
+    #[account(
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    /// Registered platform taking a share of the fee (required when platform_fee_bps > 0)
+    #[account(mut)]
+    pub platform: Option<Box<Account<'info, Platform>>>,
+
+    /// CHECK: Platform fee recipient - must be the platform's payout wallet
     #[account(mut)]
     pub platform_fee_account: Option<AccountInfo<'info>>,
 
+    /// Keeper vault - required when the revenue split has a keeper share
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Option<Box<Account<'info, KeeperVault>>>,
+
+    /// WSOL revenue vault - required when the revenue split has burn or staker shares
+    #[account(
+        mut,
+        seeds = [RevenueVault::SEED_PREFIX, WSOL_MINT.as_ref()],
+        bump = revenue_vault.bump,
+    )]
+    pub revenue_vault: Option<Box<Account<'info, RevenueVault>>>,
+
     /// The new mint to update the symbol to (validated as a real mint)
     pub new_mint: InterfaceAccount<'info, Mint>,
 
@@ -63,22 +89,31 @@ pub fn handler(ctx: Context<UpdateMintSol>, max_sol_cost: u64, platform_fee_bps:
     validate_not_paused(config)?;
     
     validate_symbol_not_expired(&ctx.accounts.token_account, clock.unix_timestamp)?;
+
+    validate_no_open_dispute(&ctx.accounts.token_account)?;
     
     validate_mint_different(&ctx.accounts.token_account.mint, &new_mint)?;
 
     // Validate metadata matches - owner unchanged (already verified as signer)
-    validate_mint_metadata(
+    let metadata = validate_mint_metadata(
         &ctx.accounts.new_mint_metadata,
         &ctx.accounts.new_mint.to_account_info(),
         &ctx.accounts.token_account.symbol,
     )?;
 
-    validate_platform_fee_bps(platform_fee_bps)?;
+    validate_eligibility(
+        &ctx.accounts.config.eligibility_policy,
+        &ctx.accounts.new_mint_metadata,
+        &ctx.accounts.new_mint,
+    )?;
+
+    validate_platform_fee(ctx.accounts.platform.as_deref(), platform_fee_bps)?;
 
     // Calculate and transfer fee
     let fee = calculate_update_fee(
         config,
         clock.unix_timestamp,
+        &ctx.accounts.oracle_config,
         &ctx.accounts.price_update,
     )?;
 
@@ -90,18 +125,28 @@ pub fn handler(ctx: Context<UpdateMintSol>, max_sol_cost: u64, platform_fee_bps:
 
     // Transfer fees with optional platform fee split
     let platform_fee_paid = transfer_sol_fees_with_platform(
-        &ctx.accounts.owner.to_account_info(),
-        &ctx.accounts.fee_collector,
-        ctx.accounts.platform_fee_account.as_ref(),
-        &ctx.accounts.system_program.to_account_info(),
+        SolFeeAccounts {
+            payer: &ctx.accounts.owner.to_account_info(),
+            fee_collector: &ctx.accounts.fee_collector,
+            keeper_vault: ctx.accounts.keeper_vault.as_deref(),
+            revenue_vault: ctx.accounts.revenue_vault.as_deref_mut(),
+            platform: ctx.accounts.platform.as_deref(),
+            platform_fee_account: ctx.accounts.platform_fee_account.as_ref(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        &config.get_revenue_split(),
         fee.fee_lamports,
         platform_fee_bps,
     )?;
 
+    // Record the operation on the platform's counters
+    record_platform_volume(ctx.accounts.platform.as_deref_mut(), fee.fee_usd_micro, platform_fee_bps);
+
     // Update mint
     update_symbol_mint(
         &mut ctx.accounts.token_account,
         new_mint,
+        metadata.commitment(clock.unix_timestamp),
     );
 
     emit!(MintUpdated {
@@ -112,6 +157,7 @@ pub fn handler(ctx: Context<UpdateMintSol>, max_sol_cost: u64, platform_fee_bps:
         owner: ctx.accounts.owner.key(),
         fee_paid: fee.fee_lamports,
         platform_fee: platform_fee_paid,
+        sol_price_provider: Some(fee.price_provider),
         updated_at: clock.unix_timestamp,
     });
 
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::{Platform, PlatformUpdated, TnsError};
+
+/// Platform authority changes the wallet receiving its fee share
+/// Token shares must then be sent to token accounts owned by the new wallet.
+#[derive(Accounts)]
+pub struct UpdatePlatformPayout<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Platform::SEED_PREFIX, authority.key().as_ref()],
+        bump = platform.bump,
+        has_one = authority @ TnsError::UnauthorizedPlatformAuthority,
+    )]
+    pub platform: Account<'info, Platform>,
+}
+
+pub fn handler(ctx: Context<UpdatePlatformPayout>, payout_wallet: Pubkey) -> Result<()> {
+    let platform = &mut ctx.accounts.platform;
+    platform.payout_wallet = payout_wallet;
+
+    emit!(PlatformUpdated {
+        platform: platform.key(),
+        authority: platform.authority,
+        payout_wallet: platform.payout_wallet,
+        max_fee_bps: platform.max_fee_bps,
+        active: platform.active,
+        updated_by: ctx.accounts.authority.key(),
+        updated_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::{Config, OracleConfig, TnsPriceObservation, TnsPriceObserved, TnsPriceSources};
+use super::helpers::{get_tns_spot_price_micro, TnsSpotPriceAccounts};
+
+/// Record a TNS/USD spot price sample (permissionless crank)
+/// Samples are at least TNS_OBSERVATION_MIN_INTERVAL_SECONDS apart and move at
+/// most the TWAP policy's spot deviation from the previous sample; TNS fees
+/// are priced at the TWAP across them. Accounts for each configured pool
+/// follow as remaining_accounts.
+#[derive(Accounts)]
+pub struct UpdateTnsPriceObservation<'info> {
+    /// Anyone can crank
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [TnsPriceObservation::SEED_PREFIX],
+        bump = tns_price_observation.bump,
+    )]
+    pub tns_price_observation: Box<Account<'info, TnsPriceObservation>>,
+
+    #[account(
+        seeds = [TnsPriceSources::SEED_PREFIX],
+        bump = tns_price_sources.bump,
+    )]
+    pub tns_price_sources: Box<Account<'info, TnsPriceSources>>,
+
+    /// CHECK: Pyth pull oracle SOL/USD price update account (owner verified against OracleConfig)
+    pub price_update: AccountInfo<'info>,
+
+    #[account(
+        seeds = [OracleConfig::SEED_PREFIX],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    /// CHECK: Pyth TNS/USD price update - required when Config.tns_usd_pyth_feed is set; owner verified against OracleConfig
+    pub tns_usd_price_update: Option<AccountInfo<'info>>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateTnsPriceObservation<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let spot_price_usd_micro = get_tns_spot_price_micro(
+        &ctx.accounts.config,
+        &TnsSpotPriceAccounts {
+            price_sources: &ctx.accounts.tns_price_sources,
+            oracle_config: &ctx.accounts.oracle_config,
+            price_update: Some(&ctx.accounts.price_update),
+            tns_usd_price_update: ctx.accounts.tns_usd_price_update.as_ref(),
+            pool_accounts: ctx.remaining_accounts,
+        },
+    )?;
+
+    let observation = ctx.accounts.tns_price_observation.record(
+        clock.unix_timestamp,
+        spot_price_usd_micro,
+        ctx.accounts.config.get_tns_max_spot_deviation(),
+    )?;
+
+    emit!(TnsPriceObserved {
+        price_observation: ctx.accounts.tns_price_observation.key(),
+        cranker: ctx.accounts.cranker.key(),
+        price_usd_micro: observation.price_usd_micro,
+        spot_price_usd_micro,
+        cumulative_price: observation.cumulative_price,
+        observed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,18 +1,33 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
-use crate::{Config, Token, SymbolDriftDetected, TnsError, KEEPER_REWARD_LAMPORTS};
-use super::helpers::extract_metadata_symbol;
+use crate::{
+    BondVault, Config, KeeperVault, Token, SymbolDriftDetected, SymbolDriftFlagged, SymbolDriftCleared,
+    TnsError,
+};
+use super::helpers::{
+    extract_metadata_fields, detect_metadata_drift, validate_no_open_dispute, pay_keeper_reward, forfeit_bond,
+};
 
-/// Close a symbol registration when metadata drift is detected.
+/// Verify a symbol registration against its metadata, flagging or closing on drift.
 ///
-/// This instruction succeeds only when the token's on-chain metadata symbol
-/// no longer matches the registered TNS symbol. If symbols still match,
-/// the instruction fails with NoDriftDetected.
+/// Drift means the metadata symbol no longer matches, or the metadata name,
+/// URI or update authority no longer match the commitment recorded at
+/// registration, claim, mint update or re-commit.
 ///
-/// Keepers should detect drift off-chain before calling this instruction.
-/// On success, the keeper receives the account rent plus a keeper reward.
+/// Drift handling is two-phase:
 ///
-/// Economic enforcement: changing your metadata symbol after registration
+/// 1. **Flag**: The first call on a drifted symbol records `drift_flagged_at`
+///    and starts the remediation window. The account stays open.
+/// 2. **Close**: A call after the window has elapsed, with drift still present,
+///    closes the account. The keeper receives the account rent plus the symbol's keeper deposit,
+///    and any bond is forfeited (keeper share to the keeper, the rest to the treasury).
+///
+/// During the window the owner can restore the metadata (any call to this
+/// instruction then clears the flag), re-commit to legitimate changes via
+/// recommit_metadata, or point the symbol at a matching mint via update_mint. If symbols match and nothing is flagged, the
+/// instruction fails with NoDriftDetected.
+///
+/// Economic enforcement: leaving your metadata symbol changed past the window
 /// means anyone can close your registration and claim the rent.
 #[derive(Accounts)]
 pub struct VerifyOrClose<'info> {
@@ -20,20 +35,33 @@ pub struct VerifyOrClose<'info> {
     #[account(mut)]
     pub keeper: Signer<'info>,
 
-    /// Config PDA holds keeper reward lamports
     #[account(
-        mut,
         seeds = [Config::SEED_PREFIX],
         bump = config.bump,
     )]
     pub config: Account<'info, Config>,
 
-    /// The token account to close (only succeeds if drift detected)
+    /// Keeper vault pays out the symbol's keeper deposit on close
+    #[account(
+        mut,
+        seeds = [KeeperVault::SEED_PREFIX],
+        bump = keeper_vault.bump,
+    )]
+    pub keeper_vault: Account<'info, KeeperVault>,
+
+    /// Bond vault forfeits the symbol's bond on close (required when the symbol has a bond)
+    #[account(
+        mut,
+        seeds = [BondVault::SEED_PREFIX],
+        bump = bond_vault.bump,
+    )]
+    pub bond_vault: Option<Account<'info, BondVault>>,
+
+    /// The token account to flag or close (closed only after the drift window)
     #[account(
         mut,
         seeds = [Token::SEED_PREFIX, token_account.symbol.as_bytes()],
         bump = token_account.bump,
-        close = keeper,
     )]
     pub token_account: Account<'info, Token>,
 
@@ -44,59 +72,114 @@ pub struct VerifyOrClose<'info> {
     /// The mint's metadata account
     /// For Token-2022: pass mint as metadata (embedded metadata extension)
     /// For classic SPL: pass Metaplex metadata PDA
-    /// CHECK: Validated via extract_metadata_symbol helper
+    /// CHECK: Validated via extract_metadata_fields helper
     pub token_metadata: AccountInfo<'info>,
 }
 
 pub fn handler(ctx: Context<VerifyOrClose>) -> Result<()> {
-    let token = &ctx.accounts.token_account;
     let clock = Clock::get()?;
 
-    // Extract symbol from metadata (supports both Token-2022 and Metaplex)
-    let metadata_symbol = extract_metadata_symbol(
+    // Disputed symbols can't be closed until the dispute is resolved
+    validate_no_open_dispute(&ctx.accounts.token_account)?;
+
+    // Extract metadata (supports both Token-2022 and Metaplex)
+    let metadata = extract_metadata_fields(
         &ctx.accounts.token_metadata,
         &ctx.accounts.token_mint.to_account_info(),
     )?;
 
-    // Require drift - fail if symbols still match
+    let token = &mut ctx.accounts.token_account;
+    let drift = detect_metadata_drift(token, &metadata);
+
+    // Metadata matches: clear a pending flag, otherwise there's nothing to do
+    if !drift.any() {
+        require!(token.is_drift_flagged(), TnsError::NoDriftDetected);
+
+        token.drift_flagged_at = 0;
+
+        emit!(SymbolDriftCleared {
+            token_account: token.key(),
+            symbol: token.symbol.clone(),
+            caller: ctx.accounts.keeper.key(),
+            cleared_at: clock.unix_timestamp,
+        });
+
+        return Ok(());
+    }
+
+    let grace_period = ctx.accounts.config.get_drift_grace_period();
+
+    // Phase 1: flag drift and start the remediation window
+    if !token.is_drift_flagged() {
+        token.drift_flagged_at = clock.unix_timestamp;
+
+        emit!(SymbolDriftFlagged {
+            token_account: token.key(),
+            symbol: token.symbol.clone(),
+            new_metadata_symbol: metadata.symbol.clone(),
+            mint: token.mint,
+            owner: token.owner,
+            keeper: ctx.accounts.keeper.key(),
+            symbol_changed: drift.symbol_changed,
+            name_changed: drift.name_changed,
+            uri_changed: drift.uri_changed,
+            update_authority_changed: drift.update_authority_changed,
+            flagged_at: clock.unix_timestamp,
+            closable_at: clock.unix_timestamp.saturating_add(grace_period),
+        });
+
+        return Ok(());
+    }
+
+    // Phase 2: close once the window has elapsed
     require!(
-        metadata_symbol != token.symbol,
-        TnsError::NoDriftDetected
+        token.is_drift_grace_elapsed(clock.unix_timestamp, grace_period),
+        TnsError::DriftGracePeriodActive
     );
 
-    // Capture data for event before Anchor closes the account
-    let rent_returned = ctx.accounts.token_account.to_account_info().lamports();
+    // Capture data for event before the account is closed
+    let rent_returned = token.to_account_info().lamports();
     let symbol = token.symbol.clone();
     let mint = token.mint;
     let previous_owner = token.owner;
+    let keeper_deposit = token.keeper_deposit;
 
-    // Pay keeper reward from Config PDA
-    let keeper_reward = KEEPER_REWARD_LAMPORTS;
-    let config_info = ctx.accounts.config.to_account_info();
-    let config_balance = config_info.lamports();
-
-    // Get minimum rent for the config account
-    let rent = Rent::get()?;
-    let min_rent = rent.minimum_balance(8 + Config::INIT_SPACE);
+    // Pay the symbol's keeper deposit (or legacy reward) from the keeper vault
+    let keeper_reward = pay_keeper_reward(
+        &mut ctx.accounts.keeper_vault,
+        &ctx.accounts.keeper.to_account_info(),
+        keeper_deposit,
+        ctx.accounts.config.get_keeper_reward_lamports(),
+    )?;
 
-    // Only pay if config has sufficient balance (above rent-exempt minimum)
-    if config_balance > min_rent + keeper_reward {
-        let keeper_info = ctx.accounts.keeper.to_account_info();
+    // Forfeit the bond posted in bond pricing mode
+    let bond_forfeited = token.bond_lamports;
+    let bond_keeper_reward = forfeit_bond(
+        ctx.accounts.bond_vault.as_mut(),
+        &ctx.accounts.keeper.to_account_info(),
+        bond_forfeited,
+        &ctx.accounts.config.bond_policy,
+    )?;
 
-        **config_info.try_borrow_mut_lamports()? -= keeper_reward;
-        **keeper_info.try_borrow_mut_lamports()? += keeper_reward;
-    }
+    let token_account_key = ctx.accounts.token_account.key();
+    ctx.accounts.token_account.close(ctx.accounts.keeper.to_account_info())?;
 
-    // Emit event at end (account closure handled by Anchor's close constraint)
     emit!(SymbolDriftDetected {
-        token_account: ctx.accounts.token_account.key(),
+        token_account: token_account_key,
         symbol,
-        new_metadata_symbol: metadata_symbol.to_string(),
+        new_metadata_symbol: metadata.symbol,
         mint,
         previous_owner,
         keeper: ctx.accounts.keeper.key(),
+        symbol_changed: drift.symbol_changed,
+        name_changed: drift.name_changed,
+        uri_changed: drift.uri_changed,
+        update_authority_changed: drift.update_authority_changed,
         detected_at: clock.unix_timestamp,
         rent_returned,
+        keeper_reward,
+        bond_forfeited,
+        bond_keeper_reward,
     });
 
     Ok(())
@@ -26,6 +26,7 @@ pub mod tns {
     }
 
     /// Update protocol configuration (admin only)
+    #[allow(clippy::too_many_arguments)]
     pub fn update_config(
         ctx: Context<UpdateConfig>,
         new_fee_collector: Option<Pubkey>,
@@ -35,6 +36,15 @@ pub mod tns {
         keeper_reward_lamports: Option<u64>,
         base_price_usd_micro: Option<u64>,
         annual_increase_bps: Option<u16>,
+        majority_hold_period_seconds: Option<i64>,
+        claim_cooldown_seconds: Option<i64>,
+        drift_grace_period_seconds: Option<i64>,
+        eligibility_policy: Option<EligibilityPolicy>,
+        price_lock_policy: Option<PriceLockPolicy>,
+        stablecoin_depeg_band_bps: Option<u16>,
+        tns_twap_policy: Option<TnsTwapPolicy>,
+        revenue_split: Option<RevenueSplit>,
+        bond_policy: Option<BondPolicy>,
     ) -> Result<()> {
         instructions::admin::update_config::handler(
             ctx,
@@ -45,6 +55,15 @@ pub mod tns {
             keeper_reward_lamports,
             base_price_usd_micro,
             annual_increase_bps,
+            majority_hold_period_seconds,
+            claim_cooldown_seconds,
+            drift_grace_period_seconds,
+            eligibility_policy,
+            price_lock_policy,
+            stablecoin_depeg_band_bps,
+            tns_twap_policy,
+            revenue_split,
+            bond_policy,
         )
     }
 
@@ -55,6 +74,15 @@ pub mod tns {
         instructions::admin::seed_symbol::handler(ctx, symbol, years, owner)
     }
 
+    /// Seed several verified tokens in one transaction (admin only, no fee)
+    /// remaining_accounts: [token_account, token_mint, token_metadata] per entry
+    pub fn batch_seed_symbols<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchSeedSymbols<'info>>,
+        entries: Vec<BatchSeedEntry>,
+    ) -> Result<()> {
+        instructions::admin::batch_seed_symbols::handler(ctx, entries)
+    }
+
     /// Force-update a symbol's owner, mint, or expiration (admin only)
     /// Use for fixing mistakes, revoking from bad actors, or extending for partners
     pub fn admin_update_symbol(
@@ -73,45 +101,225 @@ pub mod tns {
         instructions::admin::admin_close_symbol::handler(ctx)
     }
 
-    /// Register a new symbol paying with SOL
-    pub fn register_symbol_sol(
-        ctx: Context<RegisterSymbolSol>,
-        symbol: String,
-        years: u8,
-        max_sol_cost: u64,
-        platform_fee_bps: u16,
+    /// Create the dispute config with the initial arbiter set (admin only)
+    pub fn initialize_dispute_config(
+        ctx: Context<InitializeDisputeConfig>,
+        arbiters: Vec<Pubkey>,
+        quorum: u8,
     ) -> Result<()> {
-        instructions::registrar::register::sol::handler(ctx, symbol, years, max_sol_cost, platform_fee_bps)
+        instructions::admin::initialize_dispute_config::handler(ctx, arbiters, quorum)
     }
 
-    /// Register a new symbol paying with TNS token (25% discount)
-    pub fn register_symbol_tns(
-        ctx: Context<RegisterSymbolTns>,
-        symbol: String,
-        years: u8,
-        platform_fee_bps: u16,
+    /// Update the arbiter set and dispute parameters (admin only)
+    pub fn update_dispute_config(
+        ctx: Context<UpdateDisputeConfig>,
+        arbiters: Option<Vec<Pubkey>>,
+        quorum: Option<u8>,
+        min_bond_lamports: Option<u64>,
+        response_window_seconds: Option<i64>,
+        voting_window_seconds: Option<i64>,
+    ) -> Result<()> {
+        instructions::admin::update_dispute_config::handler(
+            ctx,
+            arbiters,
+            quorum,
+            min_bond_lamports,
+            response_window_seconds,
+            voting_window_seconds,
+        )
+    }
+
+    /// Create the keeper vault that holds per-registration keeper deposits (admin only)
+    pub fn initialize_keeper_vault(ctx: Context<InitializeKeeperVault>) -> Result<()> {
+        instructions::admin::initialize_keeper_vault::handler(ctx)
+    }
+
+    /// Reconcile the keeper vault against its recorded deposits (admin only)
+    /// Migrates legacy Config PDA lamports and optionally sweeps the surplus to the fee collector
+    pub fn reconcile_keeper_vault(ctx: Context<ReconcileKeeperVault>, sweep_surplus: bool) -> Result<()> {
+        instructions::admin::reconcile_keeper_vault::handler(ctx, sweep_surplus)
+    }
+
+    /// Create the bond vault that holds refundable registration bonds (admin only)
+    pub fn initialize_bond_vault(ctx: Context<InitializeBondVault>) -> Result<()> {
+        instructions::admin::initialize_bond_vault::handler(ctx)
+    }
+
+    /// Sweep forfeited bonds above rent + outstanding bonds to the fee collector (admin only)
+    pub fn sweep_bond_vault(ctx: Context<SweepBondVault>) -> Result<()> {
+        instructions::admin::sweep_bond_vault::handler(ctx)
+    }
+
+    /// Accept a new payment asset for the generic *_asset instructions (admin only)
+    pub fn add_payment_asset(
+        ctx: Context<AddPaymentAsset>,
+        price_source: PriceSource,
+        discount_bps: u16,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::admin::add_payment_asset::handler(ctx, price_source, discount_bps, enabled)
+    }
+
+    /// Update a payment asset's price source, discount or enabled flag (admin only)
+    pub fn update_payment_asset(
+        ctx: Context<UpdatePaymentAsset>,
+        price_source: Option<PriceSource>,
+        discount_bps: Option<u16>,
+        enabled: Option<bool>,
+    ) -> Result<()> {
+        instructions::admin::update_payment_asset::handler(ctx, price_source, discount_bps, enabled)
+    }
+
+    /// Create the TNS price observation buffer used for TWAP pricing (admin only)
+    pub fn initialize_tns_price_observation(ctx: Context<InitializeTnsPriceObservation>) -> Result<()> {
+        instructions::admin::initialize_tns_price_observation::handler(ctx)
+    }
+
+    /// Create the list of DEX pools used to price TNS (admin only)
+    pub fn initialize_tns_price_sources(
+        ctx: Context<InitializeTnsPriceSources>,
+        pools: Vec<TnsPoolSource>,
+        min_sources: u8,
+    ) -> Result<()> {
+        instructions::admin::initialize_tns_price_sources::handler(ctx, pools, min_sources)
+    }
+
+    /// Replace the TNS pool list or minimum source count (admin only)
+    pub fn update_tns_price_sources(
+        ctx: Context<UpdateTnsPriceSources>,
+        pools: Option<Vec<TnsPoolSource>>,
+        min_sources: Option<u8>,
+    ) -> Result<()> {
+        instructions::admin::update_tns_price_sources::handler(ctx, pools, min_sources)
+    }
+
+    /// Register a platform allowed to take a share of fees (admin only)
+    pub fn register_platform(
+        ctx: Context<RegisterPlatform>,
+        authority: Pubkey,
+        payout_wallet: Pubkey,
+        max_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::admin::register_platform::handler(ctx, authority, payout_wallet, max_fee_bps)
+    }
+
+    /// Update a platform's payout wallet, fee limit or active flag (admin only)
+    pub fn update_platform(
+        ctx: Context<UpdatePlatform>,
+        payout_wallet: Option<Pubkey>,
+        max_fee_bps: Option<u16>,
+        active: Option<bool>,
+    ) -> Result<()> {
+        instructions::admin::update_platform::handler(ctx, payout_wallet, max_fee_bps, active)
+    }
+
+    /// Create the revenue vault holding split revenue for a payment mint (admin only)
+    pub fn initialize_revenue_vault(
+        ctx: Context<InitializeRevenueVault>,
+        buyback_pool: Pubkey,
+        buyback_max_slippage_bps: u16,
+    ) -> Result<()> {
+        instructions::admin::initialize_revenue_vault::handler(ctx, buyback_pool, buyback_max_slippage_bps)
+    }
+
+    /// Update a revenue vault's buyback pool or slippage limit (admin only)
+    pub fn update_revenue_vault(
+        ctx: Context<UpdateRevenueVault>,
+        buyback_pool: Option<Pubkey>,
+        buyback_max_slippage_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::admin::update_revenue_vault::handler(ctx, buyback_pool, buyback_max_slippage_bps)
+    }
+
+    /// Create the treasury PDA that owns the token fee vaults (admin only)
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>, allowlist: Vec<Pubkey>) -> Result<()> {
+        instructions::admin::initialize_treasury::handler(ctx, allowlist)
+    }
+
+    /// Replace the wallets treasury withdrawals may go to (admin only)
+    pub fn update_treasury_allowlist(ctx: Context<UpdateTreasuryAllowlist>, allowlist: Vec<Pubkey>) -> Result<()> {
+        instructions::admin::update_treasury_allowlist::handler(ctx, allowlist)
+    }
+
+    /// Withdraw tokens from a treasury vault to an allowlisted wallet (admin only)
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::admin::withdraw_treasury::handler(ctx, amount)
+    }
+
+    /// Create the oracle config with default Pyth feeds and verification rules (admin only)
+    pub fn initialize_oracle_config(ctx: Context<InitializeOracleConfig>) -> Result<()> {
+        instructions::admin::initialize_oracle_config::handler(ctx)
+    }
+
+    /// Rotate Pyth feeds or change staleness, verification, price update owner and SOL/USD guards (admin only)
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_oracle_config(
+        ctx: Context<UpdateOracleConfig>,
+        sol_usd_feed_id: Option<[u8; 32]>,
+        max_staleness_seconds: Option<u64>,
+        min_verification: Option<OracleVerificationLevel>,
+        price_update_owner: Option<Pubkey>,
+        max_confidence_bps: Option<u16>,
+        min_sol_price_usd_micro: Option<u64>,
+        max_sol_price_usd_micro: Option<u64>,
+        max_deviation_bps: Option<u16>,
+        auto_pause: Option<bool>,
+        trip_after_violations: Option<u8>,
+        max_last_good_age_seconds: Option<i64>,
+    ) -> Result<()> {
+        instructions::admin::update_oracle_config::handler(
+            ctx,
+            sol_usd_feed_id,
+            max_staleness_seconds,
+            min_verification,
+            price_update_owner,
+            max_confidence_bps,
+            min_sol_price_usd_micro,
+            max_sol_price_usd_micro,
+            max_deviation_bps,
+            auto_pause,
+            trip_after_violations,
+            max_last_good_age_seconds,
+        )
+    }
+
+    /// Clear a tripped SOL/USD circuit breaker and set the last good price (admin only)
+    /// A zero price re-anchors the deviation guard on the next recorded price
+    pub fn reset_sol_circuit(ctx: Context<ResetSolCircuit>, last_good_price_usd_micro: u64) -> Result<()> {
+        instructions::admin::reset_sol_circuit::handler(ctx, last_good_price_usd_micro)
+    }
+
+    /// Post a SOL/USD fallback price used while the Pyth update is stale (admin only)
+    /// Lives for at most 15 minutes; a zero TTL clears it
+    pub fn post_fallback_sol_price(
+        ctx: Context<PostFallbackSolPrice>,
+        price_usd_micro: u64,
+        ttl_seconds: i64,
     ) -> Result<()> {
-        instructions::registrar::register::tns::handler(ctx, symbol, years, platform_fee_bps)
+        instructions::admin::post_fallback_sol_price::handler(ctx, price_usd_micro, ttl_seconds)
     }
 
-    /// Register a new symbol paying with USDC
-    pub fn register_symbol_usdc(
-        ctx: Context<RegisterSymbolUsdc>,
+    /// Register a new symbol paying with SOL
+    pub fn register_symbol_sol(
+        ctx: Context<RegisterSymbolSol>,
         symbol: String,
         years: u8,
+        max_sol_cost: u64,
         platform_fee_bps: u16,
     ) -> Result<()> {
-        instructions::registrar::register::usdc::handler(ctx, symbol, years, platform_fee_bps)
+        instructions::registrar::register::sol::handler(ctx, symbol, years, max_sol_cost, platform_fee_bps)
     }
 
-    /// Register a new symbol paying with USDT
-    pub fn register_symbol_usdt(
-        ctx: Context<RegisterSymbolUsdt>,
+    /// Register a new symbol paying with any enabled PaymentAsset
+    /// remaining_accounts (TnsTwap assets): accounts for each configured TNS pool source, in order
+    pub fn register_symbol_asset<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RegisterSymbolAsset<'info>>,
         symbol: String,
         years: u8,
+        max_token_amount: u64,
         platform_fee_bps: u16,
     ) -> Result<()> {
-        instructions::registrar::register::usdt::handler(ctx, symbol, years, platform_fee_bps)
+        instructions::registrar::register::asset::handler(ctx, symbol, years, max_token_amount, platform_fee_bps)
     }
 
     /// Renew a symbol paying with SOL
@@ -124,68 +332,101 @@ pub mod tns {
         instructions::registrar::renew::sol::handler(ctx, years, max_sol_cost, platform_fee_bps)
     }
 
-    /// Renew a symbol paying with TNS token (25% discount)
-    pub fn renew_symbol_tns(
-        ctx: Context<RenewSymbolTns>,
+    /// Renew a symbol paying with any enabled PaymentAsset
+    /// remaining_accounts (TnsTwap assets): accounts for each configured TNS pool source, in order
+    pub fn renew_symbol_asset<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RenewSymbolAsset<'info>>,
         years: u8,
+        max_token_amount: u64,
         platform_fee_bps: u16,
     ) -> Result<()> {
-        instructions::registrar::renew::tns::handler(ctx, years, platform_fee_bps)
+        instructions::registrar::renew::asset::handler(ctx, years, max_token_amount, platform_fee_bps)
     }
 
-    /// Renew a symbol paying with USDC
-    pub fn renew_symbol_usdc(
-        ctx: Context<RenewSymbolUsdc>,
+    /// Renew several symbols paying with SOL (Token accounts as remaining_accounts)
+    /// One oracle read and one transfer per recipient for the whole batch
+    pub fn batch_renew_symbols_sol<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchRenewSymbolsSol<'info>>,
         years: u8,
+        max_sol_cost: u64,
         platform_fee_bps: u16,
     ) -> Result<()> {
-        instructions::registrar::renew::usdc::handler(ctx, years, platform_fee_bps)
+        instructions::registrar::batch::renew_sol::handler(ctx, years, max_sol_cost, platform_fee_bps)
     }
 
-    /// Renew a symbol paying with USDT
-    pub fn renew_symbol_usdt(
-        ctx: Context<RenewSymbolUsdt>,
-        years: u8,
-        platform_fee_bps: u16,
+    /// Opt a symbol into auto-renewal funded with SOL (owner only)
+    pub fn enable_auto_renew_sol(
+        ctx: Context<EnableAutoRenewSol>,
+        years_per_renewal: u8,
+        max_years: u16,
+        max_fee_per_renewal: u64,
+        deposit_lamports: u64,
     ) -> Result<()> {
-        instructions::registrar::renew::usdt::handler(ctx, years, platform_fee_bps)
+        instructions::registrar::auto_renewal::enable_sol::handler(
+            ctx,
+            years_per_renewal,
+            max_years,
+            max_fee_per_renewal,
+            deposit_lamports,
+        )
     }
 
-    /// Claim an expired symbol paying with SOL (anyone can claim expired symbols)
-    pub fn claim_expired_symbol_sol(
-        ctx: Context<ClaimExpiredSymbolSol>,
-        years: u8,
-        max_sol_cost: u64,
-        platform_fee_bps: u16,
+    /// Opt a symbol into auto-renewal funded with a priced stable PaymentAsset (owner only)
+    pub fn enable_auto_renew_stablecoin(
+        ctx: Context<EnableAutoRenewStablecoin>,
+        years_per_renewal: u8,
+        max_years: u16,
+        max_fee_per_renewal: u64,
+        deposit_amount: u64,
     ) -> Result<()> {
-        instructions::registrar::claim::sol::handler(ctx, years, max_sol_cost, platform_fee_bps)
+        instructions::registrar::auto_renewal::enable_stablecoin::handler(
+            ctx,
+            years_per_renewal,
+            max_years,
+            max_fee_per_renewal,
+            deposit_amount,
+        )
     }
 
-    /// Claim an expired symbol paying with TNS token (25% discount)
-    pub fn claim_expired_symbol_tns(
-        ctx: Context<ClaimExpiredSymbolTns>,
-        years: u8,
-        platform_fee_bps: u16,
-    ) -> Result<()> {
-        instructions::registrar::claim::tns::handler(ctx, years, platform_fee_bps)
+    /// Renew a symbol from its SOL auto-renew escrow (permissionless, cranker earns a tip)
+    pub fn crank_auto_renew_sol(ctx: Context<CrankAutoRenewSol>) -> Result<()> {
+        instructions::registrar::auto_renewal::crank_sol::handler(ctx)
+    }
+
+    /// Renew a symbol from its token auto-renew escrow (permissionless, cranker earns a tip)
+    pub fn crank_auto_renew_stablecoin(ctx: Context<CrankAutoRenewStablecoin>) -> Result<()> {
+        instructions::registrar::auto_renewal::crank_stablecoin::handler(ctx)
+    }
+
+    /// Disable auto-renew and withdraw the remaining SOL escrow (owner only)
+    pub fn withdraw_auto_renew_sol(ctx: Context<WithdrawAutoRenewSol>) -> Result<()> {
+        instructions::registrar::auto_renewal::withdraw_sol::handler(ctx)
+    }
+
+    /// Disable auto-renew and withdraw the remaining token escrow (owner only)
+    pub fn withdraw_auto_renew_stablecoin(ctx: Context<WithdrawAutoRenewStablecoin>) -> Result<()> {
+        instructions::registrar::auto_renewal::withdraw_stablecoin::handler(ctx)
     }
 
-    /// Claim an expired symbol paying with USDC
-    pub fn claim_expired_symbol_usdc(
-        ctx: Context<ClaimExpiredSymbolUsdc>,
+    /// Claim an expired symbol paying with SOL (anyone can claim expired symbols)
+    pub fn claim_expired_symbol_sol(
+        ctx: Context<ClaimExpiredSymbolSol>,
         years: u8,
+        max_sol_cost: u64,
         platform_fee_bps: u16,
     ) -> Result<()> {
-        instructions::registrar::claim::usdc::handler(ctx, years, platform_fee_bps)
+        instructions::registrar::claim::sol::handler(ctx, years, max_sol_cost, platform_fee_bps)
     }
 
-    /// Claim an expired symbol paying with USDT
-    pub fn claim_expired_symbol_usdt(
-        ctx: Context<ClaimExpiredSymbolUsdt>,
+    /// Claim an expired symbol paying with any enabled PaymentAsset
+    /// remaining_accounts (TnsTwap assets): accounts for each configured TNS pool source, in order
+    pub fn claim_expired_symbol_asset<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimExpiredSymbolAsset<'info>>,
         years: u8,
+        max_token_amount: u64,
         platform_fee_bps: u16,
     ) -> Result<()> {
-        instructions::registrar::claim::usdt::handler(ctx, years, platform_fee_bps)
+        instructions::registrar::claim::asset::handler(ctx, years, max_token_amount, platform_fee_bps)
     }
 
     /// Update the mint associated with a symbol paying with SOL (owner only)
@@ -197,28 +438,34 @@ pub mod tns {
         instructions::registrar::update_mint::sol::handler(ctx, max_sol_cost, platform_fee_bps)
     }
 
-    /// Update the mint associated with a symbol paying with TNS (25% discount)
-    pub fn update_mint_tns(
-        ctx: Context<UpdateMintTns>,
+    /// Update the mint associated with a symbol paying with any enabled PaymentAsset (owner only)
+    /// remaining_accounts (TnsTwap assets): accounts for each configured TNS pool source, in order
+    pub fn update_mint_asset<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateMintAsset<'info>>,
+        max_token_amount: u64,
         platform_fee_bps: u16,
     ) -> Result<()> {
-        instructions::registrar::update_mint::tns::handler(ctx, platform_fee_bps)
+        instructions::registrar::update_mint::asset::handler(ctx, max_token_amount, platform_fee_bps)
     }
 
-    /// Update the mint associated with a symbol paying with USDC
-    pub fn update_mint_usdc(
-        ctx: Context<UpdateMintUsdc>,
+    /// Re-commit a symbol to its mint's current metadata paying with SOL (owner only)
+    /// Accepts a legitimate name/URI/update authority change so it isn't treated as drift
+    pub fn recommit_metadata_sol(
+        ctx: Context<RecommitMetadataSol>,
+        max_sol_cost: u64,
         platform_fee_bps: u16,
     ) -> Result<()> {
-        instructions::registrar::update_mint::usdc::handler(ctx, platform_fee_bps)
+        instructions::registrar::recommit_metadata::sol::handler(ctx, max_sol_cost, platform_fee_bps)
     }
 
-    /// Update the mint associated with a symbol paying with USDT
-    pub fn update_mint_usdt(
-        ctx: Context<UpdateMintUsdt>,
+    /// Re-commit a symbol to its mint's current metadata paying with any enabled PaymentAsset (owner only)
+    /// remaining_accounts (TnsTwap assets): accounts for each configured TNS pool source, in order
+    pub fn recommit_metadata_asset<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecommitMetadataAsset<'info>>,
+        max_token_amount: u64,
         platform_fee_bps: u16,
     ) -> Result<()> {
-        instructions::registrar::update_mint::usdt::handler(ctx, platform_fee_bps)
+        instructions::registrar::recommit_metadata::asset::handler(ctx, max_token_amount, platform_fee_bps)
     }
 
     /// Transfer ownership of a symbol to a new owner
@@ -232,10 +479,9 @@ pub mod tns {
     /// Claim ownership of a symbol by proving token authority
     ///
     /// Allows the rightful owner of a token to claim the TNS record even if
-    /// someone else registered it first. Three paths to claim:
+    /// someone else registered it first. Two paths to claim:
     /// 1. Mint authority - if you control the mint, you control the token
     /// 2. Metadata update authority - if you control metadata, you control the brand
-    /// 3. Majority holder (>50%) - if you hold majority supply, you have economic control
     ///
     /// This creates a clear ownership hierarchy where token authority always
     /// takes precedence over TNS ownership.
@@ -243,17 +489,101 @@ pub mod tns {
         instructions::registrar::claim_ownership::handler(ctx)
     }
 
+    /// Start a majority-holder claim by escrowing more than 50% of supply
+    /// Ownership can only be taken after the configured hold period
+    pub fn start_majority_claim(ctx: Context<StartMajorityClaim>, amount: u64) -> Result<()> {
+        instructions::registrar::majority_holder::start::handler(ctx, amount)
+    }
+
+    /// Complete a majority-holder claim after the hold period
+    /// Re-checks majority against current supply, transfers ownership and releases escrow
+    pub fn complete_majority_claim(ctx: Context<CompleteMajorityClaim>) -> Result<()> {
+        instructions::registrar::majority_holder::complete::handler(ctx)
+    }
+
+    /// Cancel a majority-holder claim and release escrowed tokens
+    pub fn cancel_majority_claim(ctx: Context<CancelMajorityClaim>) -> Result<()> {
+        instructions::registrar::majority_holder::cancel::handler(ctx)
+    }
+
     /// Cancel an abandoned symbol (1+ year past grace period)
-    /// Closes the account, keeper receives rent + keeper reward
+    /// Closes the account, keeper receives rent + the symbol's keeper deposit
     pub fn cancel_symbol(ctx: Context<CancelSymbol>) -> Result<()> {
         instructions::registrar::cancel_symbol::handler(ctx)
     }
 
+    /// Cancel several abandoned symbols (Token accounts as remaining_accounts)
+    /// Keeper receives all rent + the sum of the symbols' keeper deposits
+    pub fn batch_cancel_symbols<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchCancelSymbols<'info>>,
+    ) -> Result<()> {
+        instructions::registrar::batch::cancel::handler(ctx)
+    }
+
+    /// Release a symbol voluntarily (owner only)
+    /// Closes the account, owner receives rent + their keeper deposit
+    pub fn release_symbol(ctx: Context<ReleaseSymbol>) -> Result<()> {
+        instructions::registrar::release_symbol::handler(ctx)
+    }
+
     /// Verify that a registered symbol still matches its mint's metadata.
-    /// If the metadata symbol has drifted (owner changed it), the account
-    /// is closed and keeper receives the rent as reward.
+    /// Two-phase: the first call on a drifted symbol flags it and starts a
+    /// remediation window; a call after the window closes the account and
+    /// the keeper receives rent + reward. Calling after the owner restores
+    /// matching metadata clears the flag.
     /// Economic enforcement: change metadata = lose registration + rent
     pub fn verify_or_close(ctx: Context<VerifyOrClose>) -> Result<()> {
         instructions::registrar::verify_or_close::handler(ctx)
     }
+
+    /// Record a TNS/USD spot price sample for the TWAP (permissionless crank)
+    /// remaining_accounts: accounts for each configured TNS pool source, in order
+    pub fn update_tns_price_observation<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateTnsPriceObservation<'info>>,
+    ) -> Result<()> {
+        instructions::registrar::update_tns_price_observation::handler(ctx)
+    }
+
+    /// Record the SOL/USD price as the last good price, or trip the circuit
+    /// breaker on a guard violation when auto-pause is on (permissionless crank)
+    pub fn record_sol_price(ctx: Context<RecordSolPrice>) -> Result<()> {
+        instructions::registrar::record_sol_price::handler(ctx)
+    }
+
+    /// Grow a Token account created under an older layout to the current one (permissionless, payer tops up rent)
+    pub fn migrate_token_account(ctx: Context<MigrateTokenAccount>) -> Result<()> {
+        instructions::registrar::migrate_token_account::handler(ctx)
+    }
+
+    /// Change the wallet receiving a platform's fee share (platform authority only)
+    pub fn update_platform_payout(ctx: Context<UpdatePlatformPayout>, payout_wallet: Pubkey) -> Result<()> {
+        instructions::registrar::update_platform_payout::handler(ctx, payout_wallet)
+    }
+
+    /// Swap part of a revenue vault's burn bucket to TNS and burn it (permissionless crank)
+    pub fn buyback_and_burn(ctx: Context<BuybackAndBurn>, amount_in: u64) -> Result<()> {
+        instructions::registrar::buyback_and_burn::handler(ctx, amount_in)
+    }
+
+    /// Open a dispute against a symbol by posting a bond
+    /// Freezes ownership changes until the dispute is resolved
+    pub fn open_dispute(ctx: Context<OpenDispute>, bond_lamports: u64, evidence_uri: String) -> Result<()> {
+        instructions::arbitration::open_dispute::handler(ctx, bond_lamports, evidence_uri)
+    }
+
+    /// Post a counter-bond as the symbol owner (within the response window)
+    pub fn respond_to_dispute(ctx: Context<RespondToDispute>) -> Result<()> {
+        instructions::arbitration::respond_to_dispute::handler(ctx)
+    }
+
+    /// Vote on an open dispute (arbiters only)
+    pub fn cast_dispute_vote(ctx: Context<CastDisputeVote>, for_challenger: bool) -> Result<()> {
+        instructions::arbitration::cast_dispute_vote::handler(ctx, for_challenger)
+    }
+
+    /// Resolve a dispute once a side reaches quorum or voting ends
+    /// Transfers the symbol or slashes the losing bond, then unfreezes the symbol
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>) -> Result<()> {
+        instructions::arbitration::resolve_dispute::handler(ctx)
+    }
 }
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::{Token, AUTO_RENEW_WINDOW_SECONDS};
+
+/// Opt-in auto-renewal escrow for a symbol, prefunded by its owner
+/// PDA seeds: ["auto_renew", token_account, owner]
+///
+/// Seeding by owner gives each new owner their own escrow after a transfer,
+/// claim or dispute; the previous owner's escrow can no longer be cranked and
+/// stays withdrawable by them.
+///
+/// SOL escrow is held as lamports on this account (above rent). Stablecoin
+/// escrow is a token account PDA owned by this account:
+/// seeds ["auto_renew_escrow", auto_renew]
+#[account]
+#[derive(InitSpace)]
+pub struct AutoRenew {
+    /// The Token account renewed by the crank
+    pub token_account: Pubkey,
+
+    /// Owner who funded the escrow (receives the remainder on withdraw)
+    pub owner: Pubkey,
+
+    /// Payment asset: Pubkey::default() for SOL, otherwise USDC_MINT or USDT_MINT
+    pub payment_mint: Pubkey,
+
+    /// Years added by each crank (1-10)
+    pub years_per_renewal: u8,
+
+    /// Years the crank may still add in total (owner-set cap)
+    pub years_remaining: u16,
+
+    /// Maximum fee per renewal (lamports for SOL, token units debited for stablecoins,
+    /// including any Token-2022 transfer fee)
+    pub max_fee_per_renewal: u64,
+
+    /// Unix timestamp of the last crank (0 = never)
+    pub last_renewed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Escrow token account bump seed (0 for SOL)
+    pub escrow_bump: u8,
+}
+
+impl AutoRenew {
+    pub const SEED_PREFIX: &'static [u8] = b"auto_renew";
+    pub const ESCROW_SEED_PREFIX: &'static [u8] = b"auto_renew_escrow";
+
+    /// Check if the escrow pays in SOL
+    pub fn is_sol(&self) -> bool {
+        self.payment_mint == Pubkey::default()
+    }
+
+    /// Check if the symbol is inside the renewal window before expiration
+    pub fn is_due(token: &Token, current_time: i64) -> bool {
+        current_time >= token.expires_at.saturating_sub(AUTO_RENEW_WINDOW_SECONDS)
+    }
+}
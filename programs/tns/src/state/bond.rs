@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::TnsError;
+
+/// Refundable bond pricing policy stored in Config
+/// When enabled, SOL registrations and expired claims lock the registration
+/// price as a bond in the BondVault instead of paying it as a fee. The bond is
+/// refunded on voluntary release (or to the outgoing owner on an ownership
+/// change) and forfeited on cancellation or drift close, with keeper_share_bps
+/// going to the closing keeper and the rest to the treasury.
+///
+/// Bond mode is SOL-only: bonds are held in lamports, so token-paid
+/// registrations and expired claims (the *_asset instructions) are rejected
+/// with BondModeUnsupported while it is enabled. Only the registration price
+/// is bonded; renewals, mint updates and metadata re-commits are still charged
+/// as fees.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace, Debug)]
+pub struct BondPolicy {
+    /// Take refundable bonds instead of registration fees
+    pub enabled: bool,
+
+    /// Share of a forfeited bond paid to the closing keeper, in basis points
+    pub keeper_share_bps: u16,
+}
+
+impl BondPolicy {
+    /// Keeper's share of a forfeited bond
+    pub fn keeper_share(&self, bond_lamports: u64) -> u64 {
+        (bond_lamports as u128 * self.keeper_share_bps as u128 / 10000) as u64
+    }
+}
+
+/// Holds refundable registration bonds
+/// PDA seeds: ["bond_vault"]
+///
+/// Each Token records its own bond. Lamports above rent + outstanding bonds are
+/// the treasury's share of forfeited bonds and can be swept by the admin via
+/// sweep_bond_vault.
+#[account]
+#[derive(InitSpace)]
+pub struct BondVault {
+    /// Sum of bonds recorded on Token accounts that are still open
+    pub outstanding_bonds: u64,
+
+    /// Lifetime bonds posted
+    pub total_bonded: u64,
+
+    /// Lifetime bonds refunded to releasing owners
+    pub total_refunded: u64,
+
+    /// Lifetime bonds forfeited on cancellation, drift close, expired claim or admin close
+    pub total_forfeited: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BondVault {
+    pub const SEED_PREFIX: &'static [u8] = b"bond_vault";
+
+    /// Record a posted bond
+    pub fn record_bond(&mut self, amount: u64) -> Result<()> {
+        self.outstanding_bonds = self.outstanding_bonds
+            .checked_add(amount)
+            .ok_or(error!(TnsError::MathOverflow))?;
+        self.total_bonded = self.total_bonded.saturating_add(amount);
+
+        Ok(())
+    }
+
+    /// Record a bond refunded to its symbol's owner
+    pub fn record_refund(&mut self, amount: u64) -> Result<()> {
+        self.release_bond(amount)?;
+        self.total_refunded = self.total_refunded.saturating_add(amount);
+
+        Ok(())
+    }
+
+    /// Record a forfeited bond (any part not paid to a keeper becomes surplus)
+    pub fn record_forfeit(&mut self, amount: u64) -> Result<()> {
+        self.release_bond(amount)?;
+        self.total_forfeited = self.total_forfeited.saturating_add(amount);
+
+        Ok(())
+    }
+
+    fn release_bond(&mut self, amount: u64) -> Result<()> {
+        self.outstanding_bonds = self.outstanding_bonds
+            .checked_sub(amount)
+            .ok_or(error!(TnsError::BondVaultInsufficient))?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::{MAX_REGISTRATION_YEARS, MULTI_YEAR_DISCOUNT_BPS, SECONDS_PER_YEAR};
+use crate::{
+    BondPolicy, EligibilityPolicy, PriceLock, PriceLockPolicy, RevenueSplit, TnsTwapPolicy, Token, MAX_REGISTRATION_YEARS, MULTI_YEAR_DISCOUNT_BPS, SECONDS_PER_YEAR,
+    MAJORITY_HOLD_PERIOD_SECONDS, CLAIM_COOLDOWN_SECONDS, DRIFT_GRACE_PERIOD_SECONDS,
+    STABLECOIN_DEPEG_BAND_BPS, TNS_TWAP_WINDOW_SECONDS, TNS_MAX_SPOT_DEVIATION_BPS,
+};
 
 /// Global config for the TNS protocol
 /// PDA seeds: ["config"]
@@ -22,11 +26,11 @@ pub struct Config {
     pub update_fee_bps: u16,
 
     /// Pyth price account for SOL/USD (push oracle)
+    /// Informational only; the SOL/USD feed is read from OracleConfig
     pub sol_usd_pyth_feed: Pubkey,
 
-    /// Reserved: Optional Pyth price account for direct TNS/USD feed
-    /// Currently unused - TNS price is derived from DEX pool reserves + SOL/USD Pyth feed
-    /// Kept for potential future direct Pyth TNS/USD oracle integration
+    /// Optional Pyth TNS/USD price update account
+    /// When set, it is one of the TNS price sources alongside the pools in TnsPriceSources
     pub tns_usd_pyth_feed: Option<Pubkey>,
 
     /// Fixed keeper reward in lamports (paid separately from registration fee)
@@ -47,8 +51,39 @@ pub struct Config {
     /// PDA bump seed
     pub bump: u8,
 
+    /// How long a majority holder's tokens must stay in escrow before the claim completes
+    /// (0 = use MAJORITY_HOLD_PERIOD_SECONDS)
+    pub majority_hold_period_seconds: i64,
+
+    /// Cooldown after a claim before a same-precedence claimant can take the symbol
+    /// (0 = use CLAIM_COOLDOWN_SECONDS)
+    pub claim_cooldown_seconds: i64,
+
+    /// Remediation window between a keeper flagging drift and the symbol becoming closable
+    /// (0 = use DRIFT_GRACE_PERIOD_SECONDS)
+    pub drift_grace_period_seconds: i64,
+
+    /// Mint eligibility rules for registration, expired claims and mint updates
+    pub eligibility_policy: EligibilityPolicy,
+
+    /// Per-symbol renewal price lock rules
+    pub price_lock_policy: PriceLockPolicy,
+
+    /// Maximum distance of the USDC/USDT oracle price from $1, in basis points
+    /// (0 = use STABLECOIN_DEPEG_BAND_BPS)
+    pub stablecoin_depeg_band_bps: u16,
+
+    /// TWAP window and spot deviation guard for TNS-denominated fees
+    pub tns_twap_policy: TnsTwapPolicy,
+
+    /// Division of protocol revenue across treasury, keeper vault, burn and stakers
+    pub revenue_split: RevenueSplit,
+
+    /// Refundable bond pricing mode for SOL registrations and expired claims
+    pub bond_policy: BondPolicy,
+
     /// Reserved for future use
-    pub _reserved: [u8; 128],
+    pub _reserved: [u8; 70],
 }
 
 impl Config {
@@ -73,13 +108,59 @@ impl Config {
 
     /// Calculate total price in USD micro-cents for multi-year registration with discount
     pub fn calculate_registration_price_usd(&self, current_time: i64, years: u8) -> u64 {
+        Self::multi_year_price_usd(self.get_current_yearly_price_usd(current_time), years)
+    }
+
+    /// Calculate total renewal price in USD micro-cents for a symbol
+    /// Renewals before expiry honor the symbol's price lock while the policy is enabled
+    pub fn calculate_renewal_price_usd(&self, token: &Token, current_time: i64, years: u8) -> u64 {
+        let current_yearly_price = self.get_current_yearly_price_usd(current_time);
+
+        let yearly_price = if self.price_lock_policy.enabled
+            && token.price_lock.is_set()
+            && current_time < token.expires_at
+        {
+            token.price_lock.yearly_price(
+                current_yearly_price,
+                current_time,
+                self.price_lock_policy.max_escalation_bps,
+            )
+        } else {
+            current_yearly_price
+        };
+
+        Self::multi_year_price_usd(yearly_price, years)
+    }
+
+    /// Calculate total renewal price in lamports for a symbol
+    pub fn calculate_renewal_price_lamports(
+        &self,
+        token: &Token,
+        current_time: i64,
+        years: u8,
+        sol_price_micro: u64,
+    ) -> u64 {
+        let usd_price = self.calculate_renewal_price_usd(token, current_time, years);
+        self.usd_to_lamports(usd_price, sol_price_micro)
+    }
+
+    /// Price lock to record on registration or expired claim (unset while the policy is disabled)
+    pub fn new_price_lock(&self, current_time: i64) -> PriceLock {
+        if !self.price_lock_policy.enabled {
+            return PriceLock::default();
+        }
+
+        PriceLock::new(self.get_current_yearly_price_usd(current_time), current_time)
+    }
+
+    /// Apply the multi-year discount to a yearly price
+    fn multi_year_price_usd(yearly_price: u64, years: u8) -> u64 {
         let years = years.min(MAX_REGISTRATION_YEARS) as usize;
-        
+
         if years == 0 {
             return 0;
         }
 
-        let yearly_price = self.get_current_yearly_price_usd(current_time);
         let base_total = yearly_price * years as u64;
 
         // Apply multi-year discount
@@ -115,4 +196,74 @@ impl Config {
     pub fn get_keeper_reward_lamports(&self) -> u64 {
         self.keeper_reward_lamports
     }
+
+    /// Get the majority-holder escrow period in seconds
+    /// Falls back to the default for configs created before the field existed
+    pub fn get_majority_hold_period(&self) -> i64 {
+        if self.majority_hold_period_seconds > 0 {
+            self.majority_hold_period_seconds
+        } else {
+            MAJORITY_HOLD_PERIOD_SECONDS
+        }
+    }
+
+    /// Get the claim cooldown in seconds
+    /// Falls back to the default for configs created before the field existed
+    pub fn get_claim_cooldown(&self) -> i64 {
+        if self.claim_cooldown_seconds > 0 {
+            self.claim_cooldown_seconds
+        } else {
+            CLAIM_COOLDOWN_SECONDS
+        }
+    }
+
+    /// Get the stablecoin depeg band in basis points
+    /// Falls back to the default for configs created before the field existed
+    pub fn get_stablecoin_depeg_band(&self) -> u16 {
+        if self.stablecoin_depeg_band_bps > 0 {
+            self.stablecoin_depeg_band_bps
+        } else {
+            STABLECOIN_DEPEG_BAND_BPS
+        }
+    }
+
+    /// Get the TNS TWAP window in seconds
+    /// Falls back to the default for configs created before the field existed
+    pub fn get_tns_twap_window(&self) -> i64 {
+        if self.tns_twap_policy.window_seconds > 0 {
+            self.tns_twap_policy.window_seconds as i64
+        } else {
+            TNS_TWAP_WINDOW_SECONDS as i64
+        }
+    }
+
+    /// Get the maximum TNS spot deviation from the TWAP in basis points
+    /// Falls back to the default for configs created before the field existed
+    pub fn get_tns_max_spot_deviation(&self) -> u16 {
+        if self.tns_twap_policy.max_deviation_bps > 0 {
+            self.tns_twap_policy.max_deviation_bps
+        } else {
+            TNS_MAX_SPOT_DEVIATION_BPS
+        }
+    }
+
+    /// Get the protocol revenue split
+    /// Falls back to 100% treasury for configs created before the field existed
+    pub fn get_revenue_split(&self) -> RevenueSplit {
+        if self.revenue_split.total_bps() > 0 {
+            self.revenue_split
+        } else {
+            RevenueSplit::TREASURY_ONLY
+        }
+    }
+
+    /// Get the drift remediation window in seconds
+    /// Falls back to the default for configs created before the field existed
+    pub fn get_drift_grace_period(&self) -> i64 {
+        if self.drift_grace_period_seconds > 0 {
+            self.drift_grace_period_seconds
+        } else {
+            DRIFT_GRACE_PERIOD_SECONDS
+        }
+    }
 }
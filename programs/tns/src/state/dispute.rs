@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+/// How a dispute was resolved
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DisputeOutcome {
+    /// Challenger reached quorum - symbol transferred, owner's bond slashed
+    Challenger,
+
+    /// Owner reached quorum - challenger's bond slashed
+    Owner,
+
+    /// No quorum by the voting deadline - both bonds refunded
+    Dismissed,
+}
+
+/// An open dispute against a registered symbol
+/// PDA seeds: ["dispute", token_account]
+///
+/// Holds both bonds as lamports until the dispute is resolved.
+#[account]
+#[derive(InitSpace)]
+pub struct Dispute {
+    /// The Token account being contested
+    pub token_account: Pubkey,
+
+    /// Who opened the dispute and would receive the symbol if it prevails
+    pub challenger: Pubkey,
+
+    /// The symbol owner at the time the dispute was opened
+    pub owner: Pubkey,
+
+    /// Bond posted by the challenger (lamports)
+    pub challenger_bond: u64,
+
+    /// Counter-bond posted by the owner (0 if the owner has not responded)
+    pub owner_bond: u64,
+
+    /// Off-chain evidence supporting the complaint
+    #[max_len(200)]
+    pub evidence_uri: String,
+
+    /// Unix timestamp when the dispute was opened
+    pub opened_at: i64,
+
+    /// Deadline for the owner to post a counter-bond
+    pub response_deadline: i64,
+
+    /// Deadline for arbiters to vote
+    pub voting_deadline: i64,
+
+    /// Votes a side needs to prevail (snapshot of DisputeConfig.quorum)
+    pub quorum: u8,
+
+    /// Votes in favor of the challenger
+    pub votes_for_challenger: u8,
+
+    /// Votes in favor of the owner
+    pub votes_for_owner: u8,
+
+    /// Arbiters who have already voted
+    #[max_len(10)]
+    pub voters: Vec<Pubkey>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Dispute {
+    pub const SEED_PREFIX: &'static [u8] = b"dispute";
+
+    /// Check if the owner has posted a counter-bond
+    pub fn has_response(&self) -> bool {
+        self.owner_bond > 0
+    }
+
+    /// Voting opens once the owner responds or the response window closes
+    pub fn is_voting_open(&self, current_time: i64) -> bool {
+        (self.has_response() || current_time > self.response_deadline)
+            && current_time <= self.voting_deadline
+    }
+
+    /// Check if the challenger has reached quorum
+    pub fn challenger_prevails(&self) -> bool {
+        self.votes_for_challenger >= self.quorum
+    }
+
+    /// Check if the owner has reached quorum
+    pub fn owner_prevails(&self) -> bool {
+        self.votes_for_owner >= self.quorum
+    }
+
+    /// A dispute can be resolved once a side reaches quorum or voting has ended
+    pub fn is_resolvable(&self, current_time: i64) -> bool {
+        self.challenger_prevails() || self.owner_prevails() || current_time > self.voting_deadline
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+/// Dispute parameters and the arbiter set that votes on contested registrations
+/// PDA seeds: ["dispute_config"]
+#[account]
+#[derive(InitSpace)]
+pub struct DisputeConfig {
+    /// Arbiters allowed to vote on disputes
+    #[max_len(10)]
+    pub arbiters: Vec<Pubkey>,
+
+    /// Votes a side needs to prevail (must be a strict majority of the arbiter set)
+    pub quorum: u8,
+
+    /// Minimum bond a challenger must post to open a dispute (lamports)
+    pub min_bond_lamports: u64,
+
+    /// Time the owner has to post a counter-bond after a dispute is opened
+    pub response_window_seconds: i64,
+
+    /// Time arbiters have to vote once the response window has closed
+    pub voting_window_seconds: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DisputeConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"dispute_config";
+
+    /// Check if a key belongs to the arbiter set
+    pub fn is_arbiter(&self, key: &Pubkey) -> bool {
+        self.arbiters.contains(key)
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// Registration eligibility rules stored in Config
+/// Enforced by register_symbol_*, claim_expired_symbol_* and update_mint_*.
+/// All rules are off by default; each violated rule fails with its own error.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace, Debug)]
+pub struct EligibilityPolicy {
+    /// Metadata can't be changed (Metaplex is_mutable == false,
+    /// Token-2022 metadata update authority revoked)
+    pub require_immutable_metadata: bool,
+
+    /// Mint authority must be revoked (fixed supply)
+    pub require_mint_authority_revoked: bool,
+
+    /// Mint must not have a freeze authority
+    pub require_no_freeze_authority: bool,
+
+    /// Minimum circulating supply in base units (0 = no minimum)
+    pub min_supply: u64,
+
+    /// Reject Token-2022 mints with extensions that let an authority seize,
+    /// block or hook holder transfers (PermanentDelegate, TransferHook,
+    /// MintCloseAuthority, Pausable)
+    pub reject_dangerous_extensions: bool,
+}
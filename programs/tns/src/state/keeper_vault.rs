@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::TnsError;
+
+/// Holds keeper reward deposits paid at registration
+/// PDA seeds: ["keeper_vault"]
+///
+/// Each Token records its own deposit; whichever keeper closes the symbol
+/// receives exactly that amount. Symbols without a deposit (legacy and seeded)
+/// are paid from the legacy reserve migrated out of the Config PDA. Lamports
+/// above rent + outstanding deposits + legacy reserve are surplus and can be
+/// swept by the admin via reconcile_keeper_vault.
+#[account]
+#[derive(InitSpace)]
+pub struct KeeperVault {
+    /// Sum of deposits recorded on Token accounts that are still open
+    pub outstanding_deposits: u64,
+
+    /// Lifetime deposits received from registrations
+    pub total_deposited: u64,
+
+    /// Lifetime deposits paid out to keepers (or refunded to releasing owners)
+    pub total_paid: u64,
+
+    /// Legacy keeper reward lamports migrated from the Config PDA, reserved for
+    /// keepers closing symbols that have no recorded deposit
+    pub legacy_reserve: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl KeeperVault {
+    pub const SEED_PREFIX: &'static [u8] = b"keeper_vault";
+
+    /// Record a registration deposit
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        self.outstanding_deposits = self.outstanding_deposits
+            .checked_add(amount)
+            .ok_or(error!(TnsError::MathOverflow))?;
+        self.total_deposited = self.total_deposited.saturating_add(amount);
+
+        Ok(())
+    }
+
+    /// Record a deposit paid out when its symbol is closed
+    pub fn record_payout(&mut self, amount: u64) -> Result<()> {
+        self.forfeit_deposit(amount)?;
+        self.total_paid = self.total_paid.saturating_add(amount);
+
+        Ok(())
+    }
+
+    /// Record lamports migrated from the Config PDA into the legacy reserve
+    pub fn record_legacy_migration(&mut self, amount: u64) -> Result<()> {
+        self.legacy_reserve = self.legacy_reserve
+            .checked_add(amount)
+            .ok_or(error!(TnsError::MathOverflow))?;
+
+        Ok(())
+    }
+
+    /// Record a keeper reward paid from the legacy reserve
+    /// Pays the full reward while the reserve lasts, then whatever is left
+    pub fn release_legacy_reward(&mut self, reward: u64) -> u64 {
+        let amount = reward.min(self.legacy_reserve);
+        self.legacy_reserve -= amount;
+        self.total_paid = self.total_paid.saturating_add(amount);
+
+        amount
+    }
+
+    /// Lamports the vault must hold above rent
+    pub fn committed_lamports(&self) -> Result<u64> {
+        self.outstanding_deposits
+            .checked_add(self.legacy_reserve)
+            .ok_or(error!(TnsError::MathOverflow))
+    }
+
+    /// Release a deposit without paying it out (it becomes surplus)
+    pub fn forfeit_deposit(&mut self, amount: u64) -> Result<()> {
+        self.outstanding_deposits = self.outstanding_deposits
+            .checked_sub(amount)
+            .ok_or(error!(TnsError::KeeperVaultInsufficient))?;
+
+        Ok(())
+    }
+}
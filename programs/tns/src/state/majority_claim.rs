@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+/// A pending majority-holder claim with tokens locked in program escrow
+/// PDA seeds: ["majority_claim", token_account, claimant]
+///
+/// The escrow token account is a PDA owned by this account:
+/// seeds ["majority_escrow", majority_claim]
+#[account]
+#[derive(InitSpace)]
+pub struct MajorityClaim {
+    /// The Token account being claimed
+    pub token_account: Pubkey,
+
+    /// Who escrowed the tokens and becomes owner on completion
+    pub claimant: Pubkey,
+
+    /// The mint whose supply is held (Token.mint when the claim started)
+    pub mint: Pubkey,
+
+    /// Amount escrowed
+    pub amount: u64,
+
+    /// Unix timestamp when tokens were escrowed
+    pub started_at: i64,
+
+    /// Unix timestamp when the claim can complete
+    pub unlocks_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Escrow token account bump seed
+    pub escrow_bump: u8,
+}
+
+impl MajorityClaim {
+    pub const SEED_PREFIX: &'static [u8] = b"majority_claim";
+    pub const ESCROW_SEED_PREFIX: &'static [u8] = b"majority_escrow";
+
+    /// Check if escrowed amount is strictly more than half of supply
+    pub fn is_majority(amount: u64, supply: u64) -> bool {
+        supply > 0 && amount > supply / 2
+    }
+}
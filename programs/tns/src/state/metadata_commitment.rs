@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+/// Commitment to the metadata a symbol was registered against
+/// Lets keepers detect a swapped name, URI or update authority, not just a changed ticker.
+/// Each field is committed as the first 8 bytes of its SHA-256 hash.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace, Debug)]
+pub struct MetadataCommitment {
+    /// Truncated hash of the metadata name
+    pub name_hash: [u8; 8],
+
+    /// Truncated hash of the metadata URI
+    pub uri_hash: [u8; 8],
+
+    /// Truncated hash of the metadata update authority (None hashes distinctly)
+    pub update_authority_hash: [u8; 8],
+
+    /// Unix timestamp of the commitment (0 = no commitment, only the symbol is checked)
+    pub committed_at: i64,
+}
+
+impl MetadataCommitment {
+    pub fn new(name: &str, uri: &str, update_authority: Option<Pubkey>, current_time: i64) -> Self {
+        Self {
+            name_hash: Self::hash_field(name.as_bytes()),
+            uri_hash: Self::hash_field(uri.as_bytes()),
+            update_authority_hash: Self::hash_authority(update_authority),
+            committed_at: current_time,
+        }
+    }
+
+    /// Check if a commitment has been recorded
+    pub fn is_set(&self) -> bool {
+        self.committed_at > 0
+    }
+
+    pub fn name_matches(&self, name: &str) -> bool {
+        self.name_hash == Self::hash_field(name.as_bytes())
+    }
+
+    pub fn uri_matches(&self, uri: &str) -> bool {
+        self.uri_hash == Self::hash_field(uri.as_bytes())
+    }
+
+    pub fn update_authority_matches(&self, update_authority: Option<Pubkey>) -> bool {
+        self.update_authority_hash == Self::hash_authority(update_authority)
+    }
+
+    fn hash_field(value: &[u8]) -> [u8; 8] {
+        let hash = hashv(&[value]).to_bytes();
+        let mut truncated = [0u8; 8];
+        truncated.copy_from_slice(&hash[..8]);
+        truncated
+    }
+
+    fn hash_authority(update_authority: Option<Pubkey>) -> [u8; 8] {
+        match update_authority {
+            Some(authority) => Self::hash_field(authority.as_ref()),
+            None => Self::hash_field(&[]),
+        }
+    }
+}
@@ -1,5 +1,37 @@
 pub mod config;
 pub mod token;
+pub mod dispute_config;
+pub mod dispute;
+pub mod majority_claim;
+pub mod eligibility_policy;
+pub mod metadata_commitment;
+pub mod keeper_vault;
+pub mod auto_renew;
+pub mod price_lock;
+pub mod payment_asset;
+pub mod tns_price_observation;
+pub mod tns_price_sources;
+pub mod platform;
+pub mod revenue;
+pub mod treasury;
+pub mod oracle_config;
+pub mod bond;
 
 pub use config::Config;
 pub use token::Token;
+pub use dispute_config::DisputeConfig;
+pub use dispute::{Dispute, DisputeOutcome};
+pub use majority_claim::MajorityClaim;
+pub use eligibility_policy::EligibilityPolicy;
+pub use metadata_commitment::MetadataCommitment;
+pub use keeper_vault::KeeperVault;
+pub use auto_renew::AutoRenew;
+pub use price_lock::{PriceLock, PriceLockPolicy};
+pub use payment_asset::{PaymentAsset, PriceSource};
+pub use tns_price_observation::{TnsPriceObservation, PriceObservation, TnsTwapPolicy};
+pub use tns_price_sources::{TnsPriceSources, TnsPoolSource, TnsPoolKind};
+pub use platform::Platform;
+pub use revenue::{RevenueSplit, RevenueShares, RevenueVault};
+pub use treasury::Treasury;
+pub use oracle_config::{OracleConfig, OracleVerificationLevel, PriceProvider};
+pub use bond::{BondPolicy, BondVault};
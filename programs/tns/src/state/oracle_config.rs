@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use pyth_solana_receiver_sdk::price_update::VerificationLevel;
+use crate::{SOL_CIRCUIT_TRIP_VIOLATIONS, SOL_LAST_GOOD_PRICE_MAX_AGE_SECONDS};
+
+/// Minimum Wormhole verification a Pyth price update must carry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum OracleVerificationLevel {
+    /// At least num_signatures guardian signatures were checked
+    Partial { num_signatures: u8 },
+
+    /// The full guardian quorum was checked
+    Full,
+}
+
+impl From<OracleVerificationLevel> for VerificationLevel {
+    fn from(level: OracleVerificationLevel) -> Self {
+        match level {
+            OracleVerificationLevel::Partial { num_signatures } => VerificationLevel::Partial { num_signatures },
+            OracleVerificationLevel::Full => VerificationLevel::Full,
+        }
+    }
+}
+
+/// Source of the SOL/USD price a fee was quoted at
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum PriceProvider {
+    /// Pyth pull oracle price update
+    Pyth,
+
+    /// Admin-posted fallback price (Pyth update stale)
+    Fallback,
+}
+
+/// Pyth oracle parameters used by every priced instruction
+/// PDA seeds: ["oracle_config"]
+///
+/// Feeds, staleness and verification rules live on-chain so they can be rotated
+/// by the admin without a program upgrade. Price update accounts are only
+/// deserialized when owned by price_update_owner (the Pyth receiver program).
+///
+/// SOL/USD reads are also checked against circuit-breaker guards (confidence,
+/// absolute bounds, deviation from the last good price). Zero disables a guard.
+/// The deviation guard is skipped once the last good price is older than
+/// max_last_good_age_seconds. With auto_pause set, record_sol_price counts
+/// violating updates instead of failing and trips sol_circuit_tripped after
+/// trip_after_violations in a row, halting SOL/USD pricing until the admin resets it.
+///
+/// When the Pyth SOL/USD update is stale, SOL payments and TNS spot
+/// pricing fall back to an admin-posted price until fallback_sol_price_expires_at.
+#[account]
+#[derive(InitSpace)]
+pub struct OracleConfig {
+    /// Pyth SOL/USD feed ID
+    pub sol_usd_feed_id: [u8; 32],
+
+    /// Maximum age of a price, in seconds
+    pub max_staleness_seconds: u64,
+
+    /// Minimum verification level accepted
+    pub min_verification: OracleVerificationLevel,
+
+    /// Program that must own price update accounts
+    pub price_update_owner: Pubkey,
+
+    /// Maximum confidence interval as a share of the price, in basis points
+    pub max_confidence_bps: u16,
+
+    /// Lowest accepted SOL/USD price in micro-cents
+    pub min_sol_price_usd_micro: u64,
+
+    /// Highest accepted SOL/USD price in micro-cents
+    pub max_sol_price_usd_micro: u64,
+
+    /// Maximum move of SOL/USD away from last_good_sol_price_micro, in basis points
+    pub max_deviation_bps: u16,
+
+    /// Last SOL/USD price that passed every guard, in micro-cents (0 = none yet)
+    pub last_good_sol_price_micro: u64,
+
+    /// Unix timestamp of last_good_sol_price_micro
+    pub last_good_sol_price_at: i64,
+
+    /// Whether record_sol_price trips the circuit breaker on a violation
+    pub auto_pause: bool,
+
+    /// SOL/USD pricing is halted until the admin resets the circuit
+    pub sol_circuit_tripped: bool,
+
+    /// Admin-posted SOL/USD fallback price in micro-cents
+    pub fallback_sol_price_usd_micro: u64,
+
+    /// Unix timestamp after which the fallback price is no longer used
+    pub fallback_sol_price_expires_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Consecutive violating updates that trip the circuit breaker
+    /// (0 = use SOL_CIRCUIT_TRIP_VIOLATIONS)
+    pub trip_after_violations: u8,
+
+    /// Violating updates recorded by record_sol_price since the last good price
+    pub sol_violation_count: u8,
+
+    /// Pyth publish time of the last counted violation (each update counts once)
+    pub last_violation_publish_time: i64,
+
+    /// Age after which last_good_sol_price_micro no longer anchors the deviation guard
+    /// (0 = use SOL_LAST_GOOD_PRICE_MAX_AGE_SECONDS)
+    pub max_last_good_age_seconds: i64,
+
+    /// Reserved for future use
+    pub _reserved: [u8; 56],
+}
+
+impl OracleConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"oracle_config";
+
+    /// The fallback SOL/USD price, if one is posted and not yet expired
+    pub fn live_fallback_sol_price(&self, current_time: i64) -> Option<u64> {
+        (self.fallback_sol_price_usd_micro > 0 && current_time < self.fallback_sol_price_expires_at)
+            .then_some(self.fallback_sol_price_usd_micro)
+    }
+
+    /// Get the consecutive violations that trip the circuit breaker
+    /// Falls back to the default for configs created before the field existed
+    pub fn get_trip_after_violations(&self) -> u8 {
+        if self.trip_after_violations > 0 {
+            self.trip_after_violations
+        } else {
+            SOL_CIRCUIT_TRIP_VIOLATIONS
+        }
+    }
+
+    /// The last good SOL/USD price, if the deviation guard should still compare against it
+    pub fn anchoring_sol_price(&self, current_time: i64) -> Option<u64> {
+        let max_age = if self.max_last_good_age_seconds > 0 {
+            self.max_last_good_age_seconds
+        } else {
+            SOL_LAST_GOOD_PRICE_MAX_AGE_SECONDS
+        };
+
+        (self.last_good_sol_price_micro > 0 && current_time - self.last_good_sol_price_at <= max_age)
+            .then_some(self.last_good_sol_price_micro)
+    }
+}
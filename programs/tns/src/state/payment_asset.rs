@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+/// How a payment asset is priced in USD
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum PriceSource {
+    /// Fixed USD price per whole token in micro-cents (1_000_000 = $1 peg)
+    FixedPeg { usd_micro: u64 },
+
+    /// Pyth pull oracle feed quoting the asset in USD
+    Pyth { feed_id: [u8; 32] },
+
+    /// Pyth feed for a USD stablecoin; payments are rejected while it trades
+    /// outside Config.stablecoin_depeg_band_bps around $1
+    Stablecoin { feed_id: [u8; 32] },
+
+    /// TNS/USD TWAP from the TnsPriceObservation, rejected while the spot price
+    /// deviates too far from it (TNS_MINT only)
+    TnsTwap,
+}
+
+/// Registry entry for a token accepted by the generic payment instructions
+/// PDA seeds: ["payment_asset", mint]
+#[account]
+#[derive(InitSpace)]
+pub struct PaymentAsset {
+    /// Accepted payment mint
+    pub mint: Pubkey,
+
+    /// Mint decimals (copied from the mint when the asset is added)
+    pub decimals: u8,
+
+    /// USD price source for the asset
+    pub price_source: PriceSource,
+
+    /// Discount off the USD fee when paying with this asset, in basis points
+    pub discount_bps: u16,
+
+    /// Whether payments in this asset are accepted
+    pub enabled: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Reserved for future use
+    pub _reserved: [u8; 32],
+}
+
+impl PaymentAsset {
+    pub const SEED_PREFIX: &'static [u8] = b"payment_asset";
+
+    /// Apply the asset's discount to a USD fee
+    pub fn discounted_usd(&self, fee_usd_micro: u64) -> u64 {
+        (fee_usd_micro as u128 * (10000 - self.discount_bps as u128) / 10000) as u64
+    }
+
+    /// Convert a USD amount to raw token units at the given price per whole token
+    pub fn usd_to_amount(&self, usd_micro: u64, price_usd_micro: u64) -> Option<u64> {
+        if price_usd_micro == 0 {
+            return None;
+        }
+
+        let amount = (usd_micro as u128)
+            .checked_mul(10u128.pow(self.decimals as u32))?
+            .checked_div(price_usd_micro as u128)?;
+
+        u64::try_from(amount).ok()
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+/// Registry entry for a platform (launchpad, wallet, referrer) allowed to take
+/// a share of TNS fees
+/// PDA seeds: ["platform", authority]
+///
+/// Fee splits are only honored for active platforms and up to max_fee_bps.
+/// SOL shares are paid to payout_wallet; token shares to a token account of the
+/// payment mint owned by payout_wallet.
+#[account]
+#[derive(InitSpace)]
+pub struct Platform {
+    /// Platform's own key, allowed to change the payout wallet
+    pub authority: Pubkey,
+
+    /// Wallet receiving the platform's fee share
+    pub payout_wallet: Pubkey,
+
+    /// Maximum share of a fee the platform may take, in basis points (set by admin)
+    pub max_fee_bps: u16,
+
+    /// Whether fee splits to this platform are honored
+    pub active: bool,
+
+    /// Number of fee-paying operations routed through the platform
+    pub operation_count: u64,
+
+    /// Cumulative USD list price of those operations, in micro-cents
+    pub volume_usd_micro: u64,
+
+    /// Cumulative USD value of the platform's share, in micro-cents
+    pub fees_usd_micro: u64,
+
+    /// Unix timestamp of registration
+    pub registered_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Reserved for future use
+    pub _reserved: [u8; 32],
+}
+
+impl Platform {
+    pub const SEED_PREFIX: &'static [u8] = b"platform";
+
+    /// Add a fee-paying operation to the cumulative counters
+    /// Counters saturate rather than fail so statistics can never block a payment.
+    pub fn record(&mut self, volume_usd_micro: u64, platform_fee_bps: u16) {
+        let fee_usd_micro = (volume_usd_micro as u128 * platform_fee_bps as u128 / 10000) as u64;
+
+        self.operation_count = self.operation_count.saturating_add(1);
+        self.volume_usd_micro = self.volume_usd_micro.saturating_add(volume_usd_micro);
+        self.fees_usd_micro = self.fees_usd_micro.saturating_add(fee_usd_micro);
+    }
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::SECONDS_PER_YEAR;
+
+/// Renewal price lock policy stored in Config
+/// When enabled, registrations and expired claims record the current yearly
+/// price on the Token; renewals before expiry are charged at that price plus
+/// at most max_escalation_bps per full year since it was locked.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace, Debug)]
+pub struct PriceLockPolicy {
+    /// Record and honor per-symbol price locks
+    pub enabled: bool,
+
+    /// Maximum escalation over the locked price per full year locked, in basis points
+    /// (0 = renew at the locked price)
+    pub max_escalation_bps: u16,
+}
+
+/// Yearly renewal price locked on a Token at registration or expired claim
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace, Debug)]
+pub struct PriceLock {
+    /// Locked yearly price in USD micro-cents (0 = no lock)
+    pub yearly_price_usd_micro: u64,
+
+    /// Unix timestamp when the price was locked
+    pub locked_at: i64,
+}
+
+impl PriceLock {
+    pub fn new(yearly_price_usd_micro: u64, current_time: i64) -> Self {
+        Self {
+            yearly_price_usd_micro,
+            locked_at: current_time,
+        }
+    }
+
+    /// Check if a price is locked
+    pub fn is_set(&self) -> bool {
+        self.yearly_price_usd_micro > 0
+    }
+
+    /// Yearly price to charge: the locked price plus capped escalation,
+    /// never more than the current yearly price
+    pub fn yearly_price(&self, current_yearly_price: u64, current_time: i64, max_escalation_bps: u16) -> u64 {
+        let years_locked = ((current_time - self.locked_at) / SECONDS_PER_YEAR).max(0) as u128;
+        let locked = self.yearly_price_usd_micro as u128;
+        let escalation = locked * max_escalation_bps as u128 * years_locked / 10000;
+
+        (locked + escalation).min(current_yearly_price as u128) as u64
+    }
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::TnsError;
+
+/// How protocol revenue (fees after any platform share) is divided, stored in Config
+/// Basis points must sum to 10000. All zero means 100% treasury.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace, Debug)]
+pub struct RevenueSplit {
+    /// Share paid to the fee collector (SOL) or the treasury vault (tokens)
+    pub treasury_bps: u16,
+
+    /// Share paid to the keeper vault (SOL) or the keeper bucket (tokens)
+    pub keeper_bps: u16,
+
+    /// Share burned (TNS) or held for buyback-and-burn (SOL, stablecoins)
+    pub burn_bps: u16,
+
+    /// Share held in the staker bucket
+    pub staker_bps: u16,
+}
+
+/// A fee amount divided according to a RevenueSplit
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct RevenueShares {
+    pub treasury: u64,
+    pub keeper: u64,
+    pub burn: u64,
+    pub staker: u64,
+}
+
+impl RevenueShares {
+    /// Total of the keeper, burn and staker shares
+    pub fn non_treasury(&self) -> u64 {
+        self.keeper + self.burn + self.staker
+    }
+}
+
+impl RevenueSplit {
+    /// 100% to the treasury
+    pub const TREASURY_ONLY: Self = Self {
+        treasury_bps: 10000,
+        keeper_bps: 0,
+        burn_bps: 0,
+        staker_bps: 0,
+    };
+
+    /// Sum of all shares in basis points
+    pub fn total_bps(&self) -> u32 {
+        self.treasury_bps as u32 + self.keeper_bps as u32 + self.burn_bps as u32 + self.staker_bps as u32
+    }
+
+    /// Divide an amount; rounding dust goes to the treasury
+    pub fn split(&self, amount: u64) -> RevenueShares {
+        let share = |bps: u16| (amount as u128 * bps as u128 / 10000) as u64;
+
+        let keeper = share(self.keeper_bps);
+        let burn = share(self.burn_bps);
+        let staker = share(self.staker_bps);
+
+        RevenueShares {
+            treasury: amount - keeper - burn - staker,
+            keeper,
+            burn,
+            staker,
+        }
+    }
+}
+
+/// Revenue held for one payment mint, split into keeper, burn and staker buckets
+/// PDA seeds: ["revenue_vault", mint] (WSOL mint for SOL)
+///
+/// SOL revenue is held as lamports on this account; token revenue sits in the
+/// vault token account (seeds ["revenue_tokens", mint]) owned by this PDA.
+/// The burn bucket is swapped to TNS through buyback_pool and burned by the
+/// permissionless buyback_and_burn crank.
+#[account]
+#[derive(InitSpace)]
+pub struct RevenueVault {
+    /// Payment mint (WSOL for SOL revenue)
+    pub mint: Pubkey,
+
+    /// Keeper share of token fees awaiting distribution
+    pub keeper_balance: u64,
+
+    /// Amount awaiting buyback-and-burn
+    pub burn_balance: u64,
+
+    /// Staker share awaiting distribution
+    pub staker_balance: u64,
+
+    /// Lifetime TNS burned from this vault's revenue
+    pub total_tns_burned: u64,
+
+    /// Raydium CPMM pool swapping this mint for TNS (default = buyback disabled)
+    pub buyback_pool: Pubkey,
+
+    /// Maximum shortfall of the swap output below the TWAP-implied amount, in basis points
+    pub buyback_max_slippage_bps: u16,
+
+    /// Bump of the vault token account
+    pub token_bump: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Reserved for future use
+    pub _reserved: [u8; 32],
+}
+
+impl RevenueVault {
+    pub const SEED_PREFIX: &'static [u8] = b"revenue_vault";
+    pub const TOKEN_SEED_PREFIX: &'static [u8] = b"revenue_tokens";
+
+    /// Credit the vault-held shares of a fee
+    pub fn record(&mut self, shares: &RevenueShares) -> Result<()> {
+        self.keeper_balance = self.keeper_balance.checked_add(shares.keeper).ok_or(TnsError::MathOverflow)?;
+        self.burn_balance = self.burn_balance.checked_add(shares.burn).ok_or(TnsError::MathOverflow)?;
+        self.staker_balance = self.staker_balance.checked_add(shares.staker).ok_or(TnsError::MathOverflow)?;
+
+        Ok(())
+    }
+}
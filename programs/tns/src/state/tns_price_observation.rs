@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use crate::{TnsError, TNS_OBSERVATION_CAPACITY, TNS_OBSERVATION_MIN_INTERVAL_SECONDS};
+
+/// TNS TWAP rules stored in Config
+/// TNS-denominated fees are priced at the time-weighted average over the window
+/// and rejected while the spot price is further than max_deviation_bps from it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace, Debug)]
+pub struct TnsTwapPolicy {
+    /// TWAP window in seconds (0 = use TNS_TWAP_WINDOW_SECONDS)
+    pub window_seconds: u32,
+
+    /// Maximum distance of the spot price from the TWAP, in basis points
+    /// (0 = use TNS_MAX_SPOT_DEVIATION_BPS)
+    pub max_deviation_bps: u16,
+}
+
+/// A single TNS/USD price sample
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace, Debug)]
+pub struct PriceObservation {
+    /// Unix timestamp of the sample
+    pub timestamp: i64,
+
+    /// Spot TNS/USD price at the sample, in micro-cents
+    pub price_usd_micro: u64,
+
+    /// Sum of price × seconds elapsed, up to this sample
+    pub cumulative_price: u128,
+}
+
+/// Ring buffer of TNS/USD price samples, updated by a permissionless crank
+/// PDA seeds: ["tns_price_observation"]
+///
+/// Each sample accumulates the previous spot price over the time since the
+/// previous sample, so the average between two samples is the difference of
+/// their cumulative prices divided by the elapsed time.
+///
+/// Samples are spot prices read at crank time, not accumulated on every swap,
+/// so a cranker can skew the pools, crank and unskew in one transaction. To
+/// bound that, each sample is clamped to within the spot deviation band of the
+/// previous one: moving the TWAP by N bands takes N cranks a minimum interval
+/// apart, each paying the cost of holding the skew. The first sample is not
+/// clamped.
+#[account]
+#[derive(InitSpace)]
+pub struct TnsPriceObservation {
+    /// Samples, oldest overwritten first
+    pub observations: [PriceObservation; TNS_OBSERVATION_CAPACITY],
+
+    /// Index of the most recent sample
+    pub head: u8,
+
+    /// Number of samples recorded (at most TNS_OBSERVATION_CAPACITY)
+    pub count: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TnsPriceObservation {
+    pub const SEED_PREFIX: &'static [u8] = b"tns_price_observation";
+
+    /// Most recent sample, if any
+    pub fn latest(&self) -> Option<&PriceObservation> {
+        if self.count == 0 {
+            return None;
+        }
+
+        Some(&self.observations[self.head as usize])
+    }
+
+    /// Record a spot price sample, clamped to within max_step_bps of the previous sample
+    /// Samples must be at least TNS_OBSERVATION_MIN_INTERVAL_SECONDS apart so the
+    /// buffer always spans a useful amount of history.
+    pub fn record(
+        &mut self,
+        current_time: i64,
+        spot_price_usd_micro: u64,
+        max_step_bps: u16,
+    ) -> Result<PriceObservation> {
+        let (index, cumulative_price, price_usd_micro) = match self.latest() {
+            Some(latest) => {
+                let elapsed = current_time - latest.timestamp;
+                require!(
+                    elapsed >= TNS_OBSERVATION_MIN_INTERVAL_SECONDS,
+                    TnsError::ObservationTooSoon
+                );
+
+                let cumulative = latest.cumulative_price
+                    .checked_add(latest.price_usd_micro as u128 * elapsed as u128)
+                    .ok_or(TnsError::MathOverflow)?;
+
+                let max_step = (latest.price_usd_micro as u128 * max_step_bps as u128 / 10000) as u64;
+                let price = spot_price_usd_micro.clamp(
+                    latest.price_usd_micro.saturating_sub(max_step),
+                    latest.price_usd_micro.saturating_add(max_step),
+                );
+
+                ((self.head as usize + 1) % TNS_OBSERVATION_CAPACITY, cumulative, price)
+            }
+            None => (0, 0, spot_price_usd_micro),
+        };
+
+        let observation = PriceObservation {
+            timestamp: current_time,
+            price_usd_micro,
+            cumulative_price,
+        };
+
+        self.observations[index] = observation;
+        self.head = index as u8;
+        self.count = (self.count as usize + 1).min(TNS_OBSERVATION_CAPACITY) as u8;
+
+        Ok(observation)
+    }
+
+    /// Time-weighted average price over at least `window_seconds` ending now
+    /// Returns None without a sample inside the window (stale) or one at least
+    /// a full window old (not enough history).
+    pub fn twap(&self, current_time: i64, window_seconds: i64) -> Option<u64> {
+        let latest = self.latest()?;
+        let since_latest = current_time - latest.timestamp;
+        if since_latest < 0 || since_latest > window_seconds {
+            return None;
+        }
+
+        let cumulative_now = latest.cumulative_price + latest.price_usd_micro as u128 * since_latest as u128;
+        let window_start = current_time - window_seconds;
+
+        // Newest sample at or before the window start, walking back from the head
+        let start = (0..self.count as usize)
+            .map(|offset| {
+                let index = (self.head as usize + TNS_OBSERVATION_CAPACITY - offset) % TNS_OBSERVATION_CAPACITY;
+                &self.observations[index]
+            })
+            .find(|observation| observation.timestamp <= window_start)?;
+
+        let elapsed = (current_time - start.timestamp) as u128;
+        Some(((cumulative_now - start.cumulative_price) / elapsed) as u64)
+    }
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::MAX_TNS_POOL_SOURCES;
+
+/// How a pool's TNS and SOL reserves are read
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum TnsPoolKind {
+    /// Constant-product pool whose reserves are its vault balances (Pump AMM and similar)
+    /// Accounts: [tns_vault, sol_vault]
+    VaultPair,
+    /// Raydium CPMM pool; accrued protocol and fund fees are excluded from the vault balances
+    /// Accounts: [pool_state, tns_vault, sol_vault]
+    RaydiumCpmm { pool_state: Pubkey },
+}
+
+/// A TNS/SOL constant-product pool used as a TNS price source
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct TnsPoolSource {
+    /// AMM adapter
+    pub kind: TnsPoolKind,
+
+    /// Pool's TNS vault token account
+    pub tns_vault: Pubkey,
+
+    /// Pool's WSOL vault token account
+    pub sol_vault: Pubkey,
+}
+
+impl TnsPoolSource {
+    /// Number of accounts the pool needs in remaining_accounts
+    pub fn account_count(&self) -> usize {
+        match self.kind {
+            TnsPoolKind::VaultPair => 2,
+            TnsPoolKind::RaydiumCpmm { .. } => 3,
+        }
+    }
+}
+
+/// On-chain list of DEX pools used to price TNS
+/// PDA seeds: ["tns_price_sources"]
+///
+/// The TNS spot price is the median of every configured pool plus the Pyth
+/// TNS/USD feed when Config.tns_usd_pyth_feed is set. TNS instructions pass
+/// each pool's accounts, in order, as remaining_accounts.
+#[account]
+#[derive(InitSpace)]
+pub struct TnsPriceSources {
+    /// Pool sources, read in order
+    #[max_len(MAX_TNS_POOL_SOURCES)]
+    pub pools: Vec<TnsPoolSource>,
+
+    /// Minimum number of sources (pools + Pyth feed) that must price TNS
+    pub min_sources: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TnsPriceSources {
+    pub const SEED_PREFIX: &'static [u8] = b"tns_price_sources";
+
+    /// Total remaining_accounts expected for all pools
+    pub fn pool_account_count(&self) -> usize {
+        self.pools.iter().map(TnsPoolSource::account_count).sum()
+    }
+}
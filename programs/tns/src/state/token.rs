@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::{GRACE_PERIOD_SECONDS, CANCEL_PERIOD_SECONDS};
+use crate::{MetadataCommitment, PriceLock, GRACE_PERIOD_SECONDS, CANCEL_PERIOD_SECONDS, CLAIM_PRECEDENCE_NONE};
 
 /// The Token account - represents ownership of a unique token symbol
 /// PDA seeds: ["token", symbol_bytes]
+///
+/// Accounts created before the price lock was added are shorter than this
+/// layout and must be grown with migrate_token_account before use.
 #[account]
 #[derive(InitSpace)]
 pub struct Token {
@@ -25,8 +28,37 @@ pub struct Token {
     /// Unix timestamp when registration expires (might remove if the community decides on a no-expiration model)
     pub expires_at: i64,
 
+    /// Whether a dispute is open against this symbol (ownership changes are frozen)
+    pub dispute_open: bool,
+
+    /// Precedence of the authority path the current owner claimed through
+    /// (CLAIM_PRECEDENCE_NONE if the owner registered, bought or was transferred the symbol)
+    pub claim_precedence: u8,
+
+    /// Unix timestamp of the last successful claim (0 if none since the last owner change)
+    pub last_claimed_at: i64,
+
+    /// Unix timestamp when a keeper flagged metadata drift (0 = not flagged)
+    pub drift_flagged_at: i64,
+
+    /// Commitment to the metadata name, URI and update authority
+    /// (set at registration, claim and mint update; re-committed by the owner)
+    pub metadata_commitment: MetadataCommitment,
+
+    /// Keeper reward deposited into the KeeperVault at registration
+    /// (paid to whichever keeper closes this symbol; 0 for seeded and legacy symbols)
+    pub keeper_deposit: u64,
+
+    /// Renewal price locked at registration or expired claim
+    /// (honored while Config.price_lock_policy is enabled)
+    pub price_lock: PriceLock,
+
+    /// Refundable bond locked in the BondVault at registration or expired claim
+    /// (0 when the symbol was registered for a fee)
+    pub bond_lamports: u64,
+
     /// Reserved for future use
-    pub _reserved: [u8; 64],
+    pub _reserved: [u8; 46],
 }
 
 impl Token {
@@ -51,4 +83,26 @@ impl Token {
     pub fn is_cancelable(&self, current_time: i64) -> bool {
         current_time > self.expires_at + GRACE_PERIOD_SECONDS + CANCEL_PERIOD_SECONDS
     }
+
+    /// Check if metadata drift has been flagged
+    pub fn is_drift_flagged(&self) -> bool {
+        self.drift_flagged_at > 0
+    }
+
+    /// Check if the drift remediation window has closed
+    pub fn is_drift_grace_elapsed(&self, current_time: i64, grace_period: i64) -> bool {
+        self.is_drift_flagged() && current_time >= self.drift_flagged_at.saturating_add(grace_period)
+    }
+
+    /// Record an authority claim so lower-precedence claimants can't take the symbol back
+    pub fn record_claim(&mut self, precedence: u8, current_time: i64) {
+        self.claim_precedence = precedence;
+        self.last_claimed_at = current_time;
+    }
+
+    /// Clear claim protection when ownership changes by any path other than a claim
+    pub fn clear_claim(&mut self) {
+        self.claim_precedence = CLAIM_PRECEDENCE_NONE;
+        self.last_claimed_at = 0;
+    }
 }
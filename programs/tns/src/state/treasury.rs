@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::MAX_TREASURY_DESTINATIONS;
+
+/// Program-owned treasury holding token fees
+/// PDA seeds: ["treasury"]
+///
+/// Token fees are paid into the treasury's associated token account for the
+/// payment mint (one vault per asset). Funds leave only through the admin's
+/// withdraw_treasury, and only to token accounts owned by an allowlisted wallet.
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    /// Wallets whose token accounts may receive withdrawals
+    #[max_len(MAX_TREASURY_DESTINATIONS)]
+    pub allowlist: Vec<Pubkey>,
+
+    /// Number of withdrawals made
+    pub withdrawal_count: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Treasury {
+    pub const SEED_PREFIX: &'static [u8] = b"treasury";
+}
@@ -0,0 +1,153 @@
+/**
+ * Migrate Token Accounts - Grow every Token account to the current layout
+ *
+ * Token accounts created before price locks (167 bytes) can't be loaded by any
+ * instruction until migrate_token_account grows them. Run this right after
+ * upgrading the program so renewals, claims and closes keep working.
+ *
+ * Usage:
+ *   npx tsx scripts/migrate-token-accounts.ts --dry-run   # List accounts that need migrating
+ *   npx tsx scripts/migrate-token-accounts.ts             # Migrate them (payer covers the extra rent)
+ *   npx tsx scripts/migrate-token-accounts.ts --batch=5   # Set migrations per transaction
+ */
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Connection, Keypair, PublicKey, Transaction } from "@solana/web3.js";
+import { readFileSync, existsSync } from "fs";
+import { join, dirname } from "path";
+import { fileURLToPath } from "url";
+import "dotenv/config";
+
+const __dirname = dirname(fileURLToPath(import.meta.url));
+
+// Configuration
+const BATCH_SIZE = 8;
+const BATCH_DELAY_MS = 2000; // Delay between batches to avoid rate limiting
+const RPC_URL = process.env.SOLANA_RPC_URL || process.env.RPC_URL || "http://localhost:8899";
+const PAYER_KEYPAIR_PATH =
+  process.env.ADMIN_KEYPAIR || join(__dirname, "..", "admin-keypair.json");
+
+// Current Token account size (8 + Token::INIT_SPACE)
+export const TOKEN_ACCOUNT_LEN = 231;
+
+export interface LegacyTokenAccount {
+  pubkey: PublicKey;
+  dataLen: number;
+}
+
+// Find Token accounts shorter than the current layout
+export async function findLegacyTokenAccounts(program: Program): Promise<LegacyTokenAccount[]> {
+  const accounts = await program.provider.connection.getProgramAccounts(program.programId, {
+    filters: [{ memcmp: program.coder.accounts.memcmp("token") }],
+  });
+
+  return accounts
+    .filter(({ account }) => account.data.length < TOKEN_ACCOUNT_LEN)
+    .map(({ pubkey, account }) => ({ pubkey, dataLen: account.data.length }));
+}
+
+// Migrate the given accounts, several per transaction; returns the signatures
+export async function migrateTokenAccounts(
+  program: Program,
+  accounts: PublicKey[],
+  batchSize: number = BATCH_SIZE,
+  delayMs: number = 0
+): Promise<string[]> {
+  const provider = program.provider as anchor.AnchorProvider;
+  const signatures: string[] = [];
+
+  for (let i = 0; i < accounts.length; i += batchSize) {
+    const tx = new Transaction();
+    for (const tokenAccount of accounts.slice(i, i + batchSize)) {
+      tx.add(
+        await program.methods
+          .migrateTokenAccount()
+          .accountsPartial({
+            payer: provider.wallet.publicKey,
+            tokenAccount,
+          })
+          .instruction()
+      );
+    }
+
+    signatures.push(await provider.sendAndConfirm(tx));
+
+    if (delayMs > 0 && i + batchSize < accounts.length) {
+      await new Promise((r) => setTimeout(r, delayMs));
+    }
+  }
+
+  return signatures;
+}
+
+async function main() {
+  const args = process.argv.slice(2);
+  const dryRun = args.includes("--dry-run");
+  const batchSize = parseInt(
+    args.find((a) => a.startsWith("--batch="))?.split("=")[1] ?? `${BATCH_SIZE}`
+  );
+
+  console.log("=".repeat(60));
+  console.log("TNS Token Account Migration");
+  console.log("=".repeat(60));
+  console.log(`RPC: ${RPC_URL}`);
+
+  const payerKeypair = Keypair.fromSecretKey(
+    Uint8Array.from(JSON.parse(readFileSync(PAYER_KEYPAIR_PATH, "utf-8")))
+  );
+  console.log(`Payer: ${payerKeypair.publicKey.toBase58()}`);
+
+  const connection = new Connection(RPC_URL, "confirmed");
+  const wallet = new anchor.Wallet(payerKeypair);
+  const provider = new anchor.AnchorProvider(connection, wallet, {
+    commitment: "confirmed",
+  });
+
+  const idlPath = join(__dirname, "..", "target", "idl", "tns.json");
+  if (!existsSync(idlPath)) {
+    console.error("Error: IDL not found. Run anchor build first.");
+    process.exit(1);
+  }
+  const idl = JSON.parse(readFileSync(idlPath, "utf-8"));
+  const program = new Program(idl, provider);
+  console.log(`Program: ${program.programId.toBase58()}`);
+  console.log("");
+
+  const legacy = await findLegacyTokenAccounts(program);
+  console.log(`Found ${legacy.length} Token accounts to migrate`);
+  for (const { pubkey, dataLen } of legacy) {
+    console.log(`  ${pubkey.toBase58()} (${dataLen} bytes)`);
+  }
+
+  if (dryRun || legacy.length === 0) {
+    return;
+  }
+
+  console.log("");
+  console.log(`Migrating in batches of ${batchSize}...`);
+  const signatures = await migrateTokenAccounts(
+    program,
+    legacy.map(({ pubkey }) => pubkey),
+    batchSize,
+    BATCH_DELAY_MS
+  );
+  for (const sig of signatures) {
+    console.log(`  Transaction: ${sig}`);
+  }
+
+  const remaining = await findLegacyTokenAccounts(program);
+  console.log("");
+  console.log(`Done. ${remaining.length} accounts left to migrate.`);
+  if (remaining.length > 0) {
+    process.exit(1);
+  }
+}
+
+// Run only when invoked directly (the tests import the helpers)
+if (process.argv[1] === fileURLToPath(import.meta.url)) {
+  main().catch((err) => {
+    console.error(err);
+    process.exit(1);
+  });
+}
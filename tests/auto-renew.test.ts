@@ -0,0 +1,187 @@
+import BN from "bn.js";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  getBalance,
+  refreshConfigState,
+  ensureUnpaused,
+  registerSymbolSol,
+} from "./helpers/setup";
+
+// Escrow deposit and per-renewal cap used in tests
+const DEPOSIT = new BN(LAMPORTS_PER_SOL / 2);
+const MAX_FEE = new BN(LAMPORTS_PER_SOL / 10);
+
+function getAutoRenewPda(programId: PublicKey, tokenAccount: PublicKey, owner: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("auto_renew"), tokenAccount.toBuffer(), owner.toBuffer()],
+    programId
+  );
+  return pda;
+}
+
+describe("TNS - Auto-Renew", () => {
+  let ctx: TestContext;
+  const cranker = Keypair.generate();
+
+  async function enable(tokenPda: PublicKey, maxYears: number, owner?: Keypair): Promise<PublicKey> {
+    const ownerKey = owner?.publicKey ?? ctx.admin.publicKey;
+    const autoRenewPda = getAutoRenewPda(ctx.program.programId, tokenPda, ownerKey);
+
+    const builder = ctx.program.methods
+      .enableAutoRenewSol(1, maxYears, MAX_FEE, DEPOSIT)
+      .accountsPartial({
+        owner: ownerKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+        autoRenew: autoRenewPda,
+      });
+    await (owner ? builder.signers([owner]) : builder).rpc();
+
+    return autoRenewPda;
+  }
+
+  async function crank(tokenPda: PublicKey, autoRenewPda: PublicKey) {
+    await ctx.program.methods
+      .crankAutoRenewSol()
+      .accountsPartial({
+        cranker: cranker.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+        autoRenew: autoRenewPda,
+        feeCollector: ctx.feeCollectorPubkey,
+        priceUpdate: ctx.priceUpdate,
+        keeperVault: ctx.keeperVaultPda,
+        revenueVault: null,
+      })
+      .signers([cranker])
+      .rpc();
+  }
+
+  async function expireSoon(tokenPda: PublicKey) {
+    const now = Math.floor(Date.now() / 1000);
+    await ctx.program.methods
+      .adminUpdateSymbol(null, null, new BN(now + 24 * 60 * 60))
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await fundAccounts(ctx.provider, cranker);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+  });
+
+  it("crank is rejected outside the renewal window", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "ARNA");
+    const autoRenewPda = await enable(tokenPda, 2);
+
+    try {
+      await crank(tokenPda, autoRenewPda);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("AutoRenewNotDue");
+    }
+  });
+
+  it("crank renews from escrow, tips the cranker and respects the year cap", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "ARNB");
+    const autoRenewPda = await enable(tokenPda, 1);
+    await expireSoon(tokenPda);
+
+    const before = await ctx.program.account.token.fetch(tokenPda);
+    const escrowBefore = await getBalance(ctx.provider, autoRenewPda);
+    const crankerBefore = await getBalance(ctx.provider, cranker.publicKey);
+
+    await crank(tokenPda, autoRenewPda);
+
+    const after = await ctx.program.account.token.fetch(tokenPda);
+    const autoRenew = await ctx.program.account.autoRenew.fetch(autoRenewPda);
+    const escrowAfter = await getBalance(ctx.provider, autoRenewPda);
+    const crankerAfter = await getBalance(ctx.provider, cranker.publicKey);
+
+    expect(after.expiresAt.sub(before.expiresAt).toNumber()).to.equal(31_557_600);
+    expect(autoRenew.yearsRemaining).to.equal(0);
+    expect(escrowAfter).to.be.lessThan(escrowBefore);
+    // Tip exceeds the cranker's tx fee
+    expect(crankerAfter).to.be.greaterThan(crankerBefore);
+
+    await expireSoon(tokenPda);
+    try {
+      await crank(tokenPda, autoRenewPda);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("AutoRenewYearsExhausted");
+    }
+  });
+
+  it("owner withdraws the remaining escrow", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "ARNC");
+    const autoRenewPda = await enable(tokenPda, 2);
+
+    const escrow = await getBalance(ctx.provider, autoRenewPda);
+    const ownerBefore = await getBalance(ctx.provider, ctx.admin.publicKey);
+
+    await ctx.program.methods
+      .withdrawAutoRenewSol()
+      .accountsPartial({
+        owner: ctx.admin.publicKey,
+        autoRenew: autoRenewPda,
+      })
+      .rpc();
+
+    const ownerAfter = await getBalance(ctx.provider, ctx.admin.publicKey);
+    expect(ownerAfter - ownerBefore).to.equal(escrow - 5000);
+    expect(await ctx.provider.connection.getAccountInfo(autoRenewPda)).to.be.null;
+  });
+
+  it("a new owner gets their own escrow and the old one is no longer cranked", async () => {
+    const newOwner = Keypair.generate();
+    await fundAccounts(ctx.provider, newOwner);
+
+    const tokenPda = await registerSymbolSol(ctx, "ARND");
+    const oldAutoRenewPda = await enable(tokenPda, 2);
+
+    await ctx.program.methods
+      .transferOwnership(newOwner.publicKey)
+      .accountsPartial({
+        owner: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+      })
+      .rpc();
+
+    // The previous owner's escrow can't renew the symbol any more
+    await expireSoon(tokenPda);
+    try {
+      await crank(tokenPda, oldAutoRenewPda);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("ConstraintSeeds");
+    }
+
+    const newAutoRenewPda = await enable(tokenPda, 2, newOwner);
+    expect(newAutoRenewPda.equals(oldAutoRenewPda)).to.be.false;
+    await crank(tokenPda, newAutoRenewPda);
+
+    // The previous owner still withdraws their escrow
+    await ctx.program.methods
+      .withdrawAutoRenewSol()
+      .accountsPartial({
+        owner: ctx.admin.publicKey,
+        autoRenew: oldAutoRenewPda,
+      })
+      .rpc();
+    expect(await ctx.provider.connection.getAccountInfo(oldAutoRenewPda)).to.be.null;
+  });
+});
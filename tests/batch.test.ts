@@ -0,0 +1,171 @@
+import BN from "bn.js";
+import { Keypair, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  getTokenPda,
+  getBalance,
+  refreshConfigState,
+  ensureUnpaused,
+  createTokenWithMetadata,
+  getMetadataPda,
+} from "./helpers/setup";
+
+// Max slippage for tests (1 SOL)
+const MAX_SOL_COST = new BN(1_000_000_000);
+
+describe("TNS - Batch Operations", () => {
+  let ctx: TestContext;
+
+  async function seedBatch(symbols: string[], owner: PublicKey): Promise<PublicKey[]> {
+    const entries = [];
+    const remainingAccounts = [];
+    const pdas = [];
+
+    for (const symbol of symbols) {
+      const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, symbol);
+      const tokenPda = getTokenPda(ctx.program.programId, symbol);
+
+      entries.push({ symbol, years: 2, owner });
+      remainingAccounts.push(
+        { pubkey: tokenPda, isSigner: false, isWritable: true },
+        { pubkey: mint, isSigner: false, isWritable: false },
+        { pubkey: getMetadataPda(mint), isSigner: false, isWritable: false }
+      );
+      pdas.push(tokenPda);
+    }
+
+    await ctx.program.methods
+      .batchSeedSymbols(entries)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+      })
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+
+    return pdas;
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+  });
+
+  it("seeds several symbols in one transaction", async () => {
+    const owner = Keypair.generate().publicKey;
+    const pdas = await seedBatch(["BSDA", "BSDB", "BSDC"], owner);
+
+    for (const pda of pdas) {
+      const token = await ctx.program.account.token.fetch(pda);
+      expect(token.owner.toString()).to.equal(owner.toString());
+      expect(token.keeperDeposit.toNumber()).to.equal(0);
+    }
+  });
+
+  it("seeds a symbol whose address was pre-funded with dust", async () => {
+    const owner = Keypair.generate().publicKey;
+    const tokenPda = getTokenPda(ctx.program.programId, "BSDD");
+
+    await ctx.provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: ctx.admin.publicKey,
+          toPubkey: tokenPda,
+          lamports: 1_000,
+        })
+      )
+    );
+
+    await seedBatch(["BSDD"], owner);
+
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    const info = await ctx.provider.connection.getAccountInfo(tokenPda);
+    expect(token.owner.toString()).to.equal(owner.toString());
+    expect(info.owner.toString()).to.equal(ctx.program.programId.toString());
+  });
+
+  it("renews several symbols with one aggregated payment", async () => {
+    const pdas = await seedBatch(["BRNA", "BRNB"], ctx.admin.publicKey);
+    const before = await Promise.all(pdas.map((pda) => ctx.program.account.token.fetch(pda)));
+    const collectorBefore = await getBalance(ctx.provider, ctx.feeCollectorPubkey);
+
+    await ctx.program.methods
+      .batchRenewSymbolsSol(1, MAX_SOL_COST, 0)
+      .accountsPartial({
+        payer: ctx.admin.publicKey,
+        config: ctx.configPda,
+        feeCollector: ctx.feeCollectorPubkey,
+        priceUpdate: ctx.priceUpdate,
+        platform: null,
+        platformFeeAccount: null,
+        keeperVault: null,
+        revenueVault: null,
+      })
+      .remainingAccounts(pdas.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+      .rpc();
+
+    const collectorAfter = await getBalance(ctx.provider, ctx.feeCollectorPubkey);
+    const after = await Promise.all(pdas.map((pda) => ctx.program.account.token.fetch(pda)));
+
+    const extended = after.map((t, i) => t.expiresAt.sub(before[i].expiresAt).toNumber());
+    expect(extended[0]).to.equal(31_557_600);
+    expect(extended[1]).to.equal(31_557_600);
+
+    // Both symbols pay the same per-symbol fee
+    expect((collectorAfter - collectorBefore) % 2).to.equal(0);
+    expect(collectorAfter - collectorBefore).to.be.greaterThan(0);
+  });
+
+  it("rejects the same symbol twice in a batch", async () => {
+    const [pda] = await seedBatch(["BDUP"], ctx.admin.publicKey);
+
+    try {
+      await ctx.program.methods
+        .batchRenewSymbolsSol(1, MAX_SOL_COST, 0)
+        .accountsPartial({
+          payer: ctx.admin.publicKey,
+          config: ctx.configPda,
+          feeCollector: ctx.feeCollectorPubkey,
+          priceUpdate: ctx.priceUpdate,
+          platform: null,
+          platformFeeAccount: null,
+          keeperVault: null,
+          revenueVault: null,
+        })
+        .remainingAccounts([
+          { pubkey: pda, isSigner: false, isWritable: true },
+          { pubkey: pda, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("DuplicateBatchItem");
+    }
+  });
+
+  it("rejects cancel of symbols that are still active", async () => {
+    const pdas = await seedBatch(["BCNA"], ctx.admin.publicKey);
+
+    try {
+      await ctx.program.methods
+        .batchCancelSymbols()
+        .accountsPartial({
+          keeper: ctx.admin.publicKey,
+          config: ctx.configPda,
+          keeperVault: ctx.keeperVaultPda,
+        })
+        .remainingAccounts(pdas.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("NotYetCancelable");
+    }
+  });
+});
@@ -0,0 +1,346 @@
+import BN from "bn.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  getTokenPda,
+  getBalance,
+  getBondVaultPda,
+  refreshConfigState,
+  ensureUnpaused,
+  createTokenWithMetadata,
+  getMetadataPda,
+  registerSymbolSol,
+} from "./helpers/setup";
+
+// Max slippage for tests (1 SOL)
+const MAX_SOL_COST = new BN(1_000_000_000);
+
+const SECONDS_PER_YEAR = 31_557_600;
+const KEEPER_SHARE_BPS = 5000;
+
+type BondPolicy = { enabled: boolean; keeperShareBps: number };
+
+describe("TNS - Bond Pricing Mode", () => {
+  let ctx: TestContext;
+  let bondVaultPda: PublicKey;
+  const owner = Keypair.generate();
+  const keeper = Keypair.generate();
+
+  async function setBondPolicy(policy: BondPolicy) {
+    await ctx.program.methods
+      .updateConfig(
+        null, null, null, null, null, null, null, null, null, null, null, null, null, null, null,
+        policy
+      )
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        newAdmin: null,
+      })
+      .rpc();
+  }
+
+  async function makeCancelable(tokenPda: PublicKey) {
+    const threeYearsAgo = Math.floor(Date.now() / 1000) - 3 * SECONDS_PER_YEAR;
+
+    await ctx.program.methods
+      .adminUpdateSymbol(null, null, new BN(threeYearsAgo))
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await fundAccounts(ctx.provider, owner);
+    await fundAccounts(ctx.provider, keeper);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+
+    bondVaultPda = getBondVaultPda(ctx.program.programId);
+    await setBondPolicy({ enabled: true, keeperShareBps: KEEPER_SHARE_BPS });
+  });
+
+  after(async () => {
+    await setBondPolicy({ enabled: false, keeperShareBps: 0 });
+  });
+
+  it("locks the registration price as a bond instead of paying a fee", async () => {
+    const collectorBefore = await getBalance(ctx.provider, ctx.feeCollectorPubkey);
+    const vaultBefore = await ctx.program.account.bondVault.fetch(bondVaultPda);
+
+    const tokenPda = await registerSymbolSol(ctx, "BNDA", { payer: owner, years: 2 });
+
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    const vaultAfter = await ctx.program.account.bondVault.fetch(bondVaultPda);
+
+    expect(token.bondLamports.toNumber()).to.be.greaterThan(0);
+    expect(vaultAfter.outstandingBonds.sub(vaultBefore.outstandingBonds).toString())
+      .to.equal(token.bondLamports.toString());
+    expect(await getBalance(ctx.provider, ctx.feeCollectorPubkey)).to.equal(collectorBefore);
+  });
+
+  it("refunds the bond when the owner releases the symbol", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "BNDB", { payer: owner, years: 2 });
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    const rent = await getBalance(ctx.provider, tokenPda);
+    const ownerBefore = await getBalance(ctx.provider, owner.publicKey);
+    const vaultBefore = await ctx.program.account.bondVault.fetch(bondVaultPda);
+
+    await ctx.program.methods
+      .releaseSymbol()
+      .accountsPartial({
+        owner: owner.publicKey,
+        config: ctx.configPda,
+        keeperVault: ctx.keeperVaultPda,
+        bondVault: bondVaultPda,
+        tokenAccount: tokenPda,
+      })
+      .signers([owner])
+      .rpc();
+
+    const ownerAfter = await getBalance(ctx.provider, owner.publicKey);
+    const vaultAfter = await ctx.program.account.bondVault.fetch(bondVaultPda);
+
+    // Owner pays the tx fee (5000 lamports) out of the refund
+    expect(ownerAfter - ownerBefore).to.equal(
+      rent + token.keeperDeposit.toNumber() + token.bondLamports.toNumber() - 5000
+    );
+    expect(vaultBefore.outstandingBonds.sub(vaultAfter.outstandingBonds).toString())
+      .to.equal(token.bondLamports.toString());
+    expect(vaultAfter.totalRefunded.sub(vaultBefore.totalRefunded).toString())
+      .to.equal(token.bondLamports.toString());
+  });
+
+  it("refunds the bond to the displaced owner when the symbol is claimed, not to the claimant", async () => {
+    // The admin created the mint, so it holds the update authority
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, "BNDF");
+    const tokenPda = await registerSymbolSol(ctx, "BNDF", { payer: owner, mint, years: 2 });
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    const ownerBefore = await getBalance(ctx.provider, owner.publicKey);
+
+    await ctx.program.methods
+      .claimOwnership()
+      .accountsPartial({
+        claimant: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+        owner: owner.publicKey,
+        tokenMint: mint,
+        tokenMetadata: getMetadataPda(mint),
+        keeperVault: ctx.keeperVaultPda,
+        bondVault: bondVaultPda,
+      })
+      .rpc();
+
+    // The displaced owner gets the keeper deposit and bond back
+    expect((await getBalance(ctx.provider, owner.publicKey)) - ownerBefore).to.equal(
+      token.keeperDeposit.toNumber() + token.bondLamports.toNumber()
+    );
+    const claimed = await ctx.program.account.token.fetch(tokenPda);
+    expect(claimed.owner.toString()).to.equal(ctx.admin.publicKey.toString());
+    expect(claimed.keeperDeposit.toNumber()).to.equal(0);
+    expect(claimed.bondLamports.toNumber()).to.equal(0);
+
+    // Releasing afterwards returns only the rent to the claimant
+    const rent = await getBalance(ctx.provider, tokenPda);
+    const adminBefore = await getBalance(ctx.provider, ctx.admin.publicKey);
+    const vaultBefore = await ctx.program.account.bondVault.fetch(bondVaultPda);
+
+    await ctx.program.methods
+      .releaseSymbol()
+      .accountsPartial({
+        owner: ctx.admin.publicKey,
+        config: ctx.configPda,
+        keeperVault: ctx.keeperVaultPda,
+        bondVault: bondVaultPda,
+        tokenAccount: tokenPda,
+      })
+      .rpc();
+
+    // Admin pays the tx fee (5000 lamports) out of the rent
+    expect((await getBalance(ctx.provider, ctx.admin.publicKey)) - adminBefore).to.equal(rent - 5000);
+    const vaultAfter = await ctx.program.account.bondVault.fetch(bondVaultPda);
+    expect(vaultAfter.totalRefunded.toString()).to.equal(vaultBefore.totalRefunded.toString());
+  });
+
+  it("forfeits the bond on cancellation, paying the keeper its share", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "BNDC", { payer: owner, years: 2 });
+    await makeCancelable(tokenPda);
+
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    const rent = await getBalance(ctx.provider, tokenPda);
+    const keeperBefore = await getBalance(ctx.provider, keeper.publicKey);
+    const vaultBefore = await ctx.program.account.bondVault.fetch(bondVaultPda);
+
+    await ctx.program.methods
+      .cancelSymbol()
+      .accountsPartial({
+        keeper: keeper.publicKey,
+        config: ctx.configPda,
+        keeperVault: ctx.keeperVaultPda,
+        bondVault: bondVaultPda,
+        tokenAccount: tokenPda,
+      })
+      .signers([keeper])
+      .rpc();
+
+    const bond = token.bondLamports.toNumber();
+    const keeperShare = Math.floor((bond * KEEPER_SHARE_BPS) / 10000);
+    const keeperAfter = await getBalance(ctx.provider, keeper.publicKey);
+    const vaultAfter = await ctx.program.account.bondVault.fetch(bondVaultPda);
+
+    expect(keeperAfter - keeperBefore).to.equal(
+      rent + token.keeperDeposit.toNumber() + keeperShare - 5000
+    );
+    expect(vaultAfter.totalForfeited.sub(vaultBefore.totalForfeited).toNumber()).to.equal(bond);
+  });
+
+  it("owner can't pull the bond back out of an expired symbol before it is canceled", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "BNDD", { payer: owner, years: 2 });
+    await makeCancelable(tokenPda);
+
+    try {
+      await ctx.program.methods
+        .releaseSymbol()
+        .accountsPartial({
+          owner: owner.publicKey,
+          config: ctx.configPda,
+          keeperVault: ctx.keeperVaultPda,
+          bondVault: bondVaultPda,
+          tokenAccount: tokenPda,
+        })
+        .signers([owner])
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("SymbolNotActive");
+    }
+
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    expect(token.bondLamports.toNumber()).to.be.greaterThan(0);
+  });
+
+  it("admin sweeps forfeited bonds to the fee collector without touching open bonds", async () => {
+    const collectorBefore = await getBalance(ctx.provider, ctx.feeCollectorPubkey);
+    const vaultBefore = await ctx.program.account.bondVault.fetch(bondVaultPda);
+
+    await ctx.program.methods
+      .sweepBondVault()
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        bondVault: bondVaultPda,
+        feeCollector: ctx.feeCollectorPubkey,
+      })
+      .rpc();
+
+    const vaultAfter = await ctx.program.account.bondVault.fetch(bondVaultPda);
+    const rentMinimum = await ctx.provider.connection.getMinimumBalanceForRentExemption(
+      (await ctx.provider.connection.getAccountInfo(bondVaultPda))!.data.length
+    );
+
+    expect(await getBalance(ctx.provider, ctx.feeCollectorPubkey)).to.be.greaterThan(collectorBefore);
+    expect(vaultAfter.outstandingBonds.toString()).to.equal(vaultBefore.outstandingBonds.toString());
+    expect(await getBalance(ctx.provider, bondVaultPda)).to.equal(
+      rentMinimum + vaultAfter.outstandingBonds.toNumber()
+    );
+  });
+
+  it("still charges renewals as fees, leaving the bond unchanged", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "BNDG", { payer: owner });
+    const tokenBefore = await ctx.program.account.token.fetch(tokenPda);
+    const collectorBefore = await getBalance(ctx.provider, ctx.feeCollectorPubkey);
+    const vaultBefore = await ctx.program.account.bondVault.fetch(bondVaultPda);
+
+    await ctx.program.methods
+      .renewSymbolSol(1, MAX_SOL_COST, 0)
+      .accountsPartial({
+        payer: owner.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+        feeCollector: ctx.feeCollectorPubkey,
+        priceUpdate: ctx.priceUpdate,
+        platform: null,
+        platformFeeAccount: null,
+        keeperVault: null,
+        revenueVault: null,
+      })
+      .signers([owner])
+      .rpc();
+
+    const tokenAfter = await ctx.program.account.token.fetch(tokenPda);
+    const vaultAfter = await ctx.program.account.bondVault.fetch(bondVaultPda);
+
+    expect(await getBalance(ctx.provider, ctx.feeCollectorPubkey)).to.be.greaterThan(collectorBefore);
+    expect(tokenAfter.bondLamports.toString()).to.equal(tokenBefore.bondLamports.toString());
+    expect(vaultAfter.outstandingBonds.toString()).to.equal(vaultBefore.outstandingBonds.toString());
+  });
+
+  it("requires the bond vault while bond mode is enabled", async () => {
+    const symbol = "BNDE";
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, symbol);
+
+    try {
+      await ctx.program.methods
+        .registerSymbolSol(symbol, 1, MAX_SOL_COST, 0)
+        .accountsPartial({
+          payer: owner.publicKey,
+          config: ctx.configPda,
+          keeperVault: ctx.keeperVaultPda,
+          bondVault: null,
+          tokenAccount: getTokenPda(ctx.program.programId, symbol),
+          tokenMint: mint,
+          tokenMetadata: getMetadataPda(mint),
+          feeCollector: ctx.feeCollectorPubkey,
+          priceUpdate: ctx.priceUpdate,
+          platform: null,
+          platformFeeAccount: null,
+          revenueVault: null,
+        })
+        .signers([owner])
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("MissingBondVault");
+    }
+  });
+
+  it("rejects a keeper share above 100%", async () => {
+    try {
+      await setBondPolicy({ enabled: true, keeperShareBps: 10001 });
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidBondPolicy");
+    }
+  });
+
+  it("non-admin cannot sweep the bond vault", async () => {
+    try {
+      await ctx.program.methods
+        .sweepBondVault()
+        .accountsPartial({
+          admin: keeper.publicKey,
+          config: ctx.configPda,
+          bondVault: bondVaultPda,
+          feeCollector: ctx.feeCollectorPubkey,
+        })
+        .signers([keeper])
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("UnauthorizedAdmin");
+    }
+  });
+});
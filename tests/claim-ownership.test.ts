@@ -6,6 +6,7 @@ import {
   createMint,
   mintTo,
   getOrCreateAssociatedTokenAccount,
+  TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import {
   setupTest,
@@ -115,7 +116,9 @@ describe("TNS - Claim Ownership", () => {
         tokenMetadata: testTokenMetadata,
         feeCollector: ctx.feeCollectorPubkey,
         priceUpdate: ctx.priceUpdate,
+        platform: null,
         platformFeeAccount: null,
+        revenueVault: null,
       })
       .rpc();
 
@@ -146,7 +149,6 @@ describe("TNS - Claim Ownership", () => {
         tokenAccount: tokenPda,
         tokenMint: testTokenMint,
         tokenMetadata: testTokenMetadata,
-        claimantTokenAccount: null, // Not using majority holder path
       })
       .rpc();
 
@@ -167,7 +169,6 @@ describe("TNS - Claim Ownership", () => {
           tokenAccount: tokenPda,
           tokenMint: testTokenMint,
           tokenMetadata: testTokenMetadata,
-          claimantTokenAccount: null,
         })
         .rpc();
 
@@ -200,7 +201,6 @@ describe("TNS - Claim Ownership", () => {
           tokenAccount: tokenPda,
           tokenMint: testTokenMint,
           tokenMetadata: testTokenMetadata,
-          claimantTokenAccount: null,
         })
         .signers([registrant])
         .rpc();
@@ -244,7 +244,9 @@ describe("TNS - Claim Ownership", () => {
           tokenMetadata: mintAuthTokenMetadata,
           feeCollector: ctx.feeCollectorPubkey,
           priceUpdate: ctx.priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -275,7 +277,6 @@ describe("TNS - Claim Ownership", () => {
           tokenAccount: mintAuthTokenPda,
           tokenMint: mintAuthTokenMint,
           tokenMetadata: mintAuthTokenMetadata,
-          claimantTokenAccount: null, // Not using majority holder path
         })
         .rpc();
 
@@ -284,6 +285,117 @@ describe("TNS - Claim Ownership", () => {
     });
   });
 
+  describe("claim precedence", () => {
+    const precedenceSymbol = "PRECED";
+    let precedenceMint: PublicKey;
+    let precedenceMetadata: PublicKey;
+    let precedencePda: PublicKey;
+    let mintAuthority: Keypair;
+    let updateAuthority: Keypair;
+
+    before(async () => {
+      mintAuthority = Keypair.generate();
+      updateAuthority = Keypair.generate();
+      await fundAccounts(ctx.provider, mintAuthority, updateAuthority);
+
+      // Mint authority and metadata update authority are different parties
+      precedenceMint = await createMint(
+        ctx.provider.connection,
+        mintAuthority,
+        mintAuthority.publicKey,
+        null,
+        9
+      );
+      precedenceMetadata = getMetadataPda(precedenceMint);
+
+      const tx = new anchor.web3.Transaction().add(
+        createMetadataV3Ix(
+          precedenceMetadata,
+          precedenceMint,
+          mintAuthority.publicKey,
+          mintAuthority.publicKey,
+          updateAuthority.publicKey,
+          `${precedenceSymbol} Token`,
+          precedenceSymbol,
+          "",
+          false
+        )
+      );
+      await anchor.web3.sendAndConfirmTransaction(ctx.provider.connection, tx, [mintAuthority]);
+
+      precedencePda = getTokenPda(ctx.program.programId, precedenceSymbol);
+
+      await ctx.program.methods
+        .registerSymbolSol(precedenceSymbol, 1, MAX_SOL_COST, 0)
+        .accountsPartial({
+          payer: ctx.admin.publicKey,
+          config: ctx.configPda,
+          tokenAccount: precedencePda,
+          tokenMint: precedenceMint,
+          tokenMetadata: precedenceMetadata,
+          feeCollector: ctx.feeCollectorPubkey,
+          priceUpdate: ctx.priceUpdate,
+          platform: null,
+          platformFeeAccount: null,
+          revenueVault: null,
+        })
+        .rpc();
+    });
+
+    async function claim(claimant: Keypair) {
+      await ctx.program.methods
+        .claimOwnership()
+        .accountsPartial({
+          claimant: claimant.publicKey,
+          config: ctx.configPda,
+          tokenAccount: precedencePda,
+          tokenMint: precedenceMint,
+          tokenMetadata: precedenceMetadata,
+        })
+        .signers([claimant])
+        .rpc();
+    }
+
+    it("update authority takes precedence over mint authority", async () => {
+      // Mint authority claims first (precedence 2)
+      await claim(mintAuthority);
+      let token = await ctx.program.account.token.fetch(precedencePda);
+      expect(token.owner.toString()).to.equal(mintAuthority.publicKey.toString());
+      expect(token.claimPrecedence).to.equal(2);
+
+      // Higher-precedence update authority is not blocked by the cooldown
+      await claim(updateAuthority);
+      token = await ctx.program.account.token.fetch(precedencePda);
+      expect(token.owner.toString()).to.equal(updateAuthority.publicKey.toString());
+      expect(token.claimPrecedence).to.equal(3);
+    });
+
+    it("mint authority cannot take the symbol back", async () => {
+      try {
+        await claim(mintAuthority);
+        expect.fail("Should have thrown an error");
+      } catch (err) {
+        expect(err.message).to.include("LowerClaimPrecedence");
+      }
+    });
+
+    it("voluntary transfer clears claim precedence", async () => {
+      await ctx.program.methods
+        .transferOwnership(mintAuthority.publicKey)
+        .accountsPartial({
+          owner: updateAuthority.publicKey,
+          config: ctx.configPda,
+          tokenAccount: precedencePda,
+        })
+        .signers([updateAuthority])
+        .rpc();
+
+      const token = await ctx.program.account.token.fetch(precedencePda);
+      expect(token.owner.toString()).to.equal(mintAuthority.publicKey.toString());
+      expect(token.claimPrecedence).to.equal(0);
+    });
+  });
+
   describe("majority holder claim", () => {
     const majoritySymbol = "MAJOR";
     let majorityMint: PublicKey;
@@ -375,7 +487,9 @@ describe("TNS - Claim Ownership", () => {
           tokenMetadata: majorityMetadata,
           feeCollector: ctx.feeCollectorPubkey,
           priceUpdate: ctx.priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -390,14 +504,129 @@ describe("TNS - Claim Ownership", () => {
         .rpc();
     });
 
-    it("majority holder can claim ownership", async () => {
+    function getMajorityClaimPda(claimant: PublicKey): PublicKey {
+      const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("majority_claim"), majorityPda.toBuffer(), claimant.toBuffer()],
+        ctx.program.programId
+      );
+      return pda;
+    }
+
+    function getEscrowPda(majorityClaim: PublicKey): PublicKey {
+      const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("majority_escrow"), majorityClaim.toBuffer()],
+        ctx.program.programId
+      );
+      return pda;
+    }
+
+    async function setHoldPeriod(seconds: number) {
+      await ctx.program.methods
+        .updateConfig(null, null, null, null, null, null, null, new BN(seconds), null, null, null)
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          config: ctx.configPda,
+        })
+        .rpc();
+    }
+
+    it("majority holder cannot claim directly via claim_ownership", async () => {
+      try {
+        await ctx.program.methods
+          .claimOwnership()
+          .accountsPartial({
+            claimant: majorityHolder.publicKey,
+            config: ctx.configPda,
+            tokenAccount: majorityPda,
+            tokenMint: majorityMint,
+            tokenMetadata: majorityMetadata,
+          })
+          .signers([majorityHolder])
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (err) {
+        expect(err.message).to.include("NotTokenAuthority");
+      }
+    });
+
+    it("majority claim cannot complete before the hold period", async () => {
       const { program, configPda } = ctx;
+      await setHoldPeriod(3600);
 
-      // Verify current owner is symbolOwner
-      const tokenBefore = await program.account.token.fetch(majorityPda);
-      expect(tokenBefore.owner.toString()).to.equal(symbolOwner.publicKey.toString());
+      const majorityClaimPda = getMajorityClaimPda(majorityHolder.publicKey);
+      const escrowPda = getEscrowPda(majorityClaimPda);
+      const holderAta = await getOrCreateAssociatedTokenAccount(
+        ctx.provider.connection,
+        mintAuthority,
+        majorityMint,
+        majorityHolder.publicKey
+      );
+
+      await program.methods
+        .startMajorityClaim(new BN(60_000_000_000))
+        .accountsPartial({
+          claimant: majorityHolder.publicKey,
+          config: configPda,
+          tokenAccount: majorityPda,
+          tokenMint: majorityMint,
+          claimantTokenAccount: holderAta.address,
+          majorityClaim: majorityClaimPda,
+          escrow: escrowPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([majorityHolder])
+        .rpc();
+
+      try {
+        await program.methods
+          .completeMajorityClaim()
+          .accountsPartial({
+            claimant: majorityHolder.publicKey,
+            config: configPda,
+            tokenAccount: majorityPda,
+            tokenMint: majorityMint,
+            claimantTokenAccount: holderAta.address,
+            majorityClaim: majorityClaimPda,
+            escrow: escrowPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([majorityHolder])
+          .rpc();
+
+        expect.fail("Should have thrown an error");
+      } catch (err) {
+        expect(err.message).to.include("HoldPeriodNotElapsed");
+      }
+
+      // Cancel returns the escrowed tokens
+      await program.methods
+        .cancelMajorityClaim()
+        .accountsPartial({
+          claimant: majorityHolder.publicKey,
+          tokenMint: majorityMint,
+          claimantTokenAccount: holderAta.address,
+          majorityClaim: majorityClaimPda,
+          escrow: escrowPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([majorityHolder])
+        .rpc();
+
+      const balance = await ctx.provider.connection.getTokenAccountBalance(holderAta.address);
+      expect(balance.value.amount).to.equal("60000000000");
+      expect(await ctx.provider.connection.getAccountInfo(majorityClaimPda)).to.be.null;
+
+      const token = await program.account.token.fetch(majorityPda);
+      expect(token.owner.toString()).to.equal(symbolOwner.publicKey.toString());
+    });
+
+    it("majority holder can claim ownership after the hold period", async () => {
+      const { program, configPda } = ctx;
+      await setHoldPeriod(1);
 
-      // Get majority holder's token account
+      const majorityClaimPda = getMajorityClaimPda(majorityHolder.publicKey);
+      const escrowPda = getEscrowPda(majorityClaimPda);
       const holderAta = await getOrCreateAssociatedTokenAccount(
         ctx.provider.connection,
         mintAuthority,
@@ -405,25 +634,50 @@ describe("TNS - Claim Ownership", () => {
         majorityHolder.publicKey
       );
 
-      // Majority holder (60% of supply) can claim
       await program.methods
-        .claimOwnership()
+        .startMajorityClaim(new BN(60_000_000_000))
         .accountsPartial({
           claimant: majorityHolder.publicKey,
           config: configPda,
           tokenAccount: majorityPda,
           tokenMint: majorityMint,
-          tokenMetadata: majorityMetadata,
           claimantTokenAccount: holderAta.address,
+          majorityClaim: majorityClaimPda,
+          escrow: escrowPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([majorityHolder])
+        .rpc();
+
+      const escrowBalance = await ctx.provider.connection.getTokenAccountBalance(escrowPda);
+      expect(escrowBalance.value.amount).to.equal("60000000000");
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      await program.methods
+        .completeMajorityClaim()
+        .accountsPartial({
+          claimant: majorityHolder.publicKey,
+          config: configPda,
+          tokenAccount: majorityPda,
+          tokenMint: majorityMint,
+          claimantTokenAccount: holderAta.address,
+          majorityClaim: majorityClaimPda,
+          escrow: escrowPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([majorityHolder])
         .rpc();
 
       const tokenAfter = await program.account.token.fetch(majorityPda);
       expect(tokenAfter.owner.toString()).to.equal(majorityHolder.publicKey.toString());
+
+      const balance = await ctx.provider.connection.getTokenAccountBalance(holderAta.address);
+      expect(balance.value.amount).to.equal("60000000000");
+      expect(await ctx.provider.connection.getAccountInfo(escrowPda)).to.be.null;
     });
 
-    it("non-majority holder cannot claim", async () => {
+    it("non-majority holder cannot start a claim", async () => {
       const { program, configPda } = ctx;
       const nonMajorityHolder = Keypair.generate();
       await fundAccounts(ctx.provider, nonMajorityHolder);
@@ -436,7 +690,6 @@ describe("TNS - Claim Ownership", () => {
         nonMajorityHolder.publicKey
       );
 
-      // Mint 1 token to them (less than 50%)
       await mintTo(
         ctx.provider.connection,
         mintAuthority,
@@ -446,48 +699,38 @@ describe("TNS - Claim Ownership", () => {
         1_000_000_000 // 1 token
       );
 
-      // Transfer ownership back to symbolOwner for this test
-      await program.methods
-        .transferOwnership(symbolOwner.publicKey)
-        .accountsPartial({
-          owner: majorityHolder.publicKey,
-          config: configPda,
-          tokenAccount: majorityPda,
-        })
-        .signers([majorityHolder])
-        .rpc();
+      const majorityClaimPda = getMajorityClaimPda(nonMajorityHolder.publicKey);
 
-      // Non-majority holder cannot claim
       try {
         await program.methods
-          .claimOwnership()
+          .startMajorityClaim(new BN(1_000_000_000))
           .accountsPartial({
             claimant: nonMajorityHolder.publicKey,
             config: configPda,
             tokenAccount: majorityPda,
             tokenMint: majorityMint,
-            tokenMetadata: majorityMetadata,
             claimantTokenAccount: nonMajorityAta.address,
+            majorityClaim: majorityClaimPda,
+            escrow: getEscrowPda(majorityClaimPda),
+            tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([nonMajorityHolder])
           .rpc();
 
         expect.fail("Should have thrown an error");
       } catch (err) {
-        expect(err.message).to.include("NotTokenAuthority");
+        expect(err.message).to.include("NotMajorityHolder");
       }
     });
 
-    it("exactly 50% holder cannot claim (requires >50%)", async () => {
+    it("exactly 50% of supply cannot start a claim (requires >50%)", async () => {
       const { program, configPda } = ctx;
       const fiftyPercentHolder = Keypair.generate();
       await fundAccounts(ctx.provider, fiftyPercentHolder);
 
-      // Get current total supply (should be 101 tokens from previous tests)
       const mintInfo = await ctx.provider.connection.getTokenSupply(majorityMint);
       const currentSupply = BigInt(mintInfo.value.amount);
 
-      // Create token account for 50% holder
       const fiftyAta = await getOrCreateAssociatedTokenAccount(
         ctx.provider.connection,
         mintAuthority,
@@ -495,9 +738,7 @@ describe("TNS - Claim Ownership", () => {
         fiftyPercentHolder.publicKey
       );
 
-      // Mint exactly the same amount as current supply to make them 50%
-      // After minting: currentSupply + currentSupply = 2*currentSupply
-      // fiftyPercentHolder has currentSupply = exactly 50%
+      // After minting: fiftyPercentHolder holds exactly half of the new supply
       await mintTo(
         ctx.provider.connection,
         mintAuthority,
@@ -507,24 +748,27 @@ describe("TNS - Claim Ownership", () => {
         currentSupply
       );
 
-      // Exactly 50% holder cannot claim
+      const majorityClaimPda = getMajorityClaimPda(fiftyPercentHolder.publicKey);
+
       try {
         await program.methods
-          .claimOwnership()
+          .startMajorityClaim(new BN(currentSupply.toString()))
           .accountsPartial({
             claimant: fiftyPercentHolder.publicKey,
             config: configPda,
             tokenAccount: majorityPda,
             tokenMint: majorityMint,
-            tokenMetadata: majorityMetadata,
             claimantTokenAccount: fiftyAta.address,
+            majorityClaim: majorityClaimPda,
+            escrow: getEscrowPda(majorityClaimPda),
+            tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([fiftyPercentHolder])
           .rpc();
 
         expect.fail("Should have thrown an error");
       } catch (err) {
-        expect(err.message).to.include("NotTokenAuthority");
+        expect(err.message).to.include("NotMajorityHolder");
       }
     });
   });
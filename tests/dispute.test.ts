@@ -0,0 +1,316 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  getBalance,
+  refreshConfigState,
+  ensureUnpaused,
+  createTokenWithMetadata,
+  getMetadataPda,
+  registerSymbolSol,
+} from "./helpers/setup";
+
+// Max slippage for tests (1 SOL)
+const MAX_SOL_COST = new BN(1_000_000_000);
+
+// Challenger bond used in tests (0.1 SOL, also set as the minimum)
+const BOND = new BN(LAMPORTS_PER_SOL / 10);
+
+function getDisputeConfigPda(programId: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("dispute_config")],
+    programId
+  );
+  return pda;
+}
+
+function getDisputePda(programId: PublicKey, tokenAccount: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("dispute"), tokenAccount.toBuffer()],
+    programId
+  );
+  return pda;
+}
+
+describe("TNS - Disputes", () => {
+  let ctx: TestContext;
+  let disputeConfigPda: PublicKey;
+  const arbiters = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+  const challenger = Keypair.generate();
+
+  async function openDispute(tokenPda: PublicKey): Promise<PublicKey> {
+    const disputePda = getDisputePda(ctx.program.programId, tokenPda);
+
+    await ctx.program.methods
+      .openDispute(BOND, "https://example.com/evidence.json")
+      .accountsPartial({
+        challenger: challenger.publicKey,
+        config: ctx.configPda,
+        disputeConfig: disputeConfigPda,
+        tokenAccount: tokenPda,
+        dispute: disputePda,
+      })
+      .signers([challenger])
+      .rpc();
+
+    return disputePda;
+  }
+
+  async function vote(disputePda: PublicKey, arbiter: Keypair, forChallenger: boolean) {
+    await ctx.program.methods
+      .castDisputeVote(forChallenger)
+      .accountsPartial({
+        arbiter: arbiter.publicKey,
+        disputeConfig: disputeConfigPda,
+        dispute: disputePda,
+      })
+      .signers([arbiter])
+      .rpc();
+  }
+
+  async function resolve(tokenPda: PublicKey, disputePda: PublicKey) {
+    await ctx.program.methods
+      .resolveDispute()
+      .accountsPartial({
+        resolver: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+        dispute: disputePda,
+        challenger: challenger.publicKey,
+        owner: ctx.admin.publicKey,
+        feeCollector: ctx.feeCollectorPubkey,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await fundAccounts(ctx.provider, challenger, ...arbiters);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+
+    disputeConfigPda = getDisputeConfigPda(ctx.program.programId);
+
+    const existing = await ctx.provider.connection.getAccountInfo(disputeConfigPda);
+    if (existing === null) {
+      await ctx.program.methods
+        .initializeDisputeConfig(arbiters.map((a) => a.publicKey), 2)
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          config: ctx.configPda,
+          disputeConfig: disputeConfigPda,
+        })
+        .rpc();
+    }
+
+    await ctx.program.methods
+      .updateDisputeConfig(arbiters.map((a) => a.publicKey), 2, BOND, null, null)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        disputeConfig: disputeConfigPda,
+      })
+      .rpc();
+  });
+
+  it("rejects a quorum that is not a strict majority", async () => {
+    try {
+      await ctx.program.methods
+        .updateDisputeConfig(null, 1, null, null, null)
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          config: ctx.configPda,
+          disputeConfig: disputeConfigPda,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidDisputeConfig");
+    }
+  });
+
+  it("rejects a zero minimum bond", async () => {
+    try {
+      await ctx.program.methods
+        .updateDisputeConfig(null, null, new BN(0), null, null)
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          config: ctx.configPda,
+          disputeConfig: disputeConfigPda,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidDisputeConfig");
+    }
+  });
+
+  it("opening a dispute freezes ownership changes", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "DSPA");
+    const disputePda = await openDispute(tokenPda);
+
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    expect(token.disputeOpen).to.be.true;
+
+    const dispute = await ctx.program.account.dispute.fetch(disputePda);
+    expect(dispute.challenger.toString()).to.equal(challenger.publicKey.toString());
+    expect(dispute.challengerBond.toNumber()).to.equal(BOND.toNumber());
+
+    try {
+      await ctx.program.methods
+        .transferOwnership(Keypair.generate().publicKey)
+        .accountsPartial({
+          owner: ctx.admin.publicKey,
+          config: ctx.configPda,
+          tokenAccount: tokenPda,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("DisputeOpen");
+    }
+  });
+
+  it("opening a dispute freezes mint updates", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "DSPF");
+    await openDispute(tokenPda);
+
+    const newMint = await createTokenWithMetadata(ctx.provider, ctx.admin, "DSPF");
+
+    try {
+      await ctx.program.methods
+        .updateMintSol(MAX_SOL_COST, 0)
+        .accountsPartial({
+          owner: ctx.admin.publicKey,
+          config: ctx.configPda,
+          tokenAccount: tokenPda,
+          feeCollector: ctx.feeCollectorPubkey,
+          priceUpdate: ctx.priceUpdate,
+          platform: null,
+          platformFeeAccount: null,
+          keeperVault: null,
+          revenueVault: null,
+          newMint,
+          newMintMetadata: getMetadataPda(newMint),
+        })
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("DisputeOpen");
+    }
+  });
+
+  it("rejects a bond below the minimum", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "DSPB");
+
+    try {
+      await ctx.program.methods
+        .openDispute(new BN(1), "")
+        .accountsPartial({
+          challenger: challenger.publicKey,
+          config: ctx.configPda,
+          disputeConfig: disputeConfigPda,
+          tokenAccount: tokenPda,
+          dispute: getDisputePda(ctx.program.programId, tokenPda),
+        })
+        .signers([challenger])
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("DisputeBondTooLow");
+    }
+  });
+
+  it("challenger prevails: symbol moves and owner bond is slashed", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "DSPC");
+    const disputePda = await openDispute(tokenPda);
+
+    await ctx.program.methods
+      .respondToDispute()
+      .accountsPartial({
+        owner: ctx.admin.publicKey,
+        tokenAccount: tokenPda,
+        dispute: disputePda,
+      })
+      .rpc();
+
+    await vote(disputePda, arbiters[0], true);
+    await vote(disputePda, arbiters[1], true);
+
+    const collectorBefore = await getBalance(ctx.provider, ctx.feeCollectorPubkey);
+    await resolve(tokenPda, disputePda);
+    const collectorAfter = await getBalance(ctx.provider, ctx.feeCollectorPubkey);
+
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    expect(token.owner.toString()).to.equal(challenger.publicKey.toString());
+    expect(token.disputeOpen).to.be.false;
+    expect(collectorAfter - collectorBefore).to.equal(BOND.toNumber());
+
+    const disputeInfo = await ctx.provider.connection.getAccountInfo(disputePda);
+    expect(disputeInfo).to.be.null;
+  });
+
+  it("owner prevails: challenger bond is slashed and ownership unchanged", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "DSPD");
+    const disputePda = await openDispute(tokenPda);
+
+    await ctx.program.methods
+      .respondToDispute()
+      .accountsPartial({
+        owner: ctx.admin.publicKey,
+        tokenAccount: tokenPda,
+        dispute: disputePda,
+      })
+      .rpc();
+
+    await vote(disputePda, arbiters[0], false);
+
+    try {
+      await vote(disputePda, arbiters[0], false);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("AlreadyVoted");
+    }
+
+    try {
+      await resolve(tokenPda, disputePda);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("DisputeNotResolvable");
+    }
+
+    await vote(disputePda, arbiters[2], false);
+
+    const collectorBefore = await getBalance(ctx.provider, ctx.feeCollectorPubkey);
+    await resolve(tokenPda, disputePda);
+    const collectorAfter = await getBalance(ctx.provider, ctx.feeCollectorPubkey);
+
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    expect(token.owner.toString()).to.equal(ctx.admin.publicKey.toString());
+    expect(token.disputeOpen).to.be.false;
+    expect(collectorAfter - collectorBefore).to.equal(BOND.toNumber());
+  });
+
+  it("non-arbiters cannot vote", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "DSPE");
+    const disputePda = await openDispute(tokenPda);
+
+    try {
+      await vote(disputePda, challenger, true);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("NotArbiter");
+    }
+  });
+});
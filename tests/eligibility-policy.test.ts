@@ -0,0 +1,98 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  refreshConfigState,
+  ensureUnpaused,
+  createTokenWithMetadata,
+  registerSymbolSol,
+} from "./helpers/setup";
+
+const OPEN_POLICY = {
+  requireImmutableMetadata: false,
+  requireMintAuthorityRevoked: false,
+  requireNoFreezeAuthority: false,
+  minSupply: new BN(0),
+  rejectDangerousExtensions: false,
+};
+
+describe("TNS - Eligibility Policy", () => {
+  let ctx: TestContext;
+
+  async function setPolicy(policy: typeof OPEN_POLICY) {
+    await ctx.program.methods
+      .updateConfig(null, null, null, null, null, null, null, null, null, null, policy)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await fundAccounts(ctx.provider, ctx.registrant, ctx.feeCollector);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+  });
+
+  after(async () => {
+    await setPolicy(OPEN_POLICY);
+  });
+
+  it("rejects mutable metadata when immutability is required", async () => {
+    await setPolicy({ ...OPEN_POLICY, requireImmutableMetadata: true });
+
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, "ELIGA", "ELIGA Token", false);
+
+    try {
+      await registerSymbolSol(ctx, "ELIGA", { mint });
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("MetadataMustBeImmutable");
+    }
+
+    // Immutable metadata passes the same policy
+    const immutableMint = await createTokenWithMetadata(ctx.provider, ctx.admin, "ELIGB");
+    await registerSymbolSol(ctx, "ELIGB", { mint: immutableMint });
+  });
+
+  it("rejects a mint whose authority is not revoked", async () => {
+    await setPolicy({ ...OPEN_POLICY, requireMintAuthorityRevoked: true });
+
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, "ELIGC");
+
+    try {
+      await registerSymbolSol(ctx, "ELIGC", { mint });
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("MintAuthorityNotRevoked");
+    }
+  });
+
+  it("rejects a mint below the minimum supply", async () => {
+    await setPolicy({ ...OPEN_POLICY, minSupply: new BN("1000000000000000000") });
+
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, "ELIGD");
+
+    try {
+      await registerSymbolSol(ctx, "ELIGD", { mint });
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("SupplyBelowMinimum");
+    }
+  });
+
+  it("exposes the active policy on the config account", async () => {
+    await setPolicy({ ...OPEN_POLICY, requireNoFreezeAuthority: true });
+
+    const config = await ctx.program.account.config.fetch(ctx.configPda);
+    expect(config.eligibilityPolicy.requireNoFreezeAuthority).to.be.true;
+    expect(config.eligibilityPolicy.requireImmutableMetadata).to.be.false;
+  });
+});
@@ -114,7 +114,9 @@ describe("TNS - Cancel Symbol", () => {
         tokenMetadata: testTokenMetadata,
         feeCollector: feeCollectorPubkey,
         priceUpdate: priceUpdate,
+        platform: null,
         platformFeeAccount: null,
+        revenueVault: null,
       })
       .rpc();
 
@@ -198,7 +200,9 @@ describe("TNS - Cancel Symbol", () => {
           tokenMetadata: tokenMetadata,
           feeCollector: ctx.feeCollectorPubkey,
           priceUpdate: ctx.priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -264,7 +268,9 @@ describe("TNS - Cancel Symbol", () => {
           tokenMetadata: newMetadata,
           feeCollector: ctx.feeCollectorPubkey,
           priceUpdate: ctx.priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -307,7 +313,9 @@ describe("TNS - Cancel Symbol", () => {
           tokenMetadata: originalMetadata,
           feeCollector: ctx.feeCollectorPubkey,
           priceUpdate: ctx.priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -385,7 +393,9 @@ describe("TNS - Cancel Symbol", () => {
           tokenMetadata: newMetadata,
           feeCollector: ctx.feeCollectorPubkey,
           priceUpdate: ctx.priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .signers([newOwner])
         .rpc();
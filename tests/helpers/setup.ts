@@ -12,6 +12,8 @@ import {
 } from "@solana/web3.js";
 import {
   createMint,
+  getOrCreateAssociatedTokenAccount,
+  TOKEN_PROGRAM_ID,
   TOKEN_2022_PROGRAM_ID,
   ExtensionType,
   getMintLen,
@@ -39,6 +41,10 @@ export const SOL_USD_PYTH_FEED = new PublicKey(
 export const SOL_USD_PRICE_UPDATE = new PublicKey(
   "7UVimffxr9ow1uXYxbK2aDRwZc7hRcy1fU7SEgHBJu6e"
 );
+// SOL/USD PriceUpdateV2 account published in 2024, always stale (mock fixture loaded in test validator)
+export const SOL_USD_PRICE_UPDATE_STALE = new PublicKey(
+  "5BSVskY2h6mSz1gwz881xurmwv9kwjXvF9LutpL6sYP5"
+);
 // SOL/USD feed ID (same on mainnet and devnet)
 export const SOL_USD_FEED_ID = Buffer.from(
   "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d",
@@ -54,6 +60,39 @@ export const USDT_MINT = new PublicKey(
   "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB"
 );
 
+// USDC/USD feed ID (Stablecoin price source for the USDC payment asset)
+export const USDC_USD_FEED_ID = Buffer.from(
+  "eaa020c61cc479712813461ce153894a96a6c00b21ed0cfc2798d1f9a9e9c94a",
+  "hex"
+);
+
+// USDC/USD and USDT/USD PriceUpdateV2 accounts at $1.00 (mock fixtures loaded in test validator)
+export const USDC_USD_PRICE_UPDATE = new PublicKey(
+  "2JbbKLjPJDdXp2zcL3j4kCXo7W2pCXeRvvgEJU6B85Nu"
+);
+export const USDT_USD_PRICE_UPDATE = new PublicKey(
+  "Bjh9sST68vJbu73J6xvNwebQQKc2xoGHmwfEJue3Poao"
+);
+// USDC/USD PriceUpdateV2 account depegged at $0.95 (mock fixture loaded in test validator)
+export const USDC_USD_PRICE_UPDATE_DEPEGGED = new PublicKey(
+  "8FVn9EKEjf4UifsYdhyVQ16ZNopBzxJhhv415Drp1UNy"
+);
+
+// Token account for "LGCY" in the layout used before price locks (mock fixture loaded in test validator)
+export const LEGACY_TOKEN_ACCOUNT = new PublicKey(
+  "JDSH5qbtvh5ypkckdy3q8G8UHTMfXRuhgF1RZveMtKLk"
+);
+export const LEGACY_TOKEN_ACCOUNT_LEN = 167;
+
+// TNS/SOL pool vaults (mock fixtures loaded in test validator)
+// 1B TNS against 50,000 SOL at $200 => $0.01 per TNS
+export const POOL_TNS_RESERVE = new PublicKey(
+  "8UGTQaaDjjWoye9YiG9YuGUc5RHcTb8sHcr877Eg5L73"
+);
+export const POOL_SOL_RESERVE = new PublicKey(
+  "B4PNGmGrdPbPjb2iu7MAKHBovQduv5gjLJdC9i5UNQaJ"
+);
+
 // Pyth magic number
 const PYTH_MAGIC = 0xa1b2c3d4;
 
@@ -62,6 +101,7 @@ export interface TestContext {
   program: Program<Tns>;
   admin: anchor.Wallet;
   configPda: PublicKey;
+  keeperVaultPda: PublicKey;
   feeCollector: Keypair;
   feeCollectorPubkey: PublicKey; // The actual fee collector in config (may differ from feeCollector.publicKey)
   registrant: Keypair;
@@ -80,6 +120,86 @@ export function getConfigPda(programId: PublicKey): PublicKey {
   return configPda;
 }
 
+export function getKeeperVaultPda(programId: PublicKey): PublicKey {
+  const [keeperVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("keeper_vault")],
+    programId
+  );
+  return keeperVaultPda;
+}
+
+export function getTnsPriceSourcesPda(programId: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("tns_price_sources")],
+    programId
+  );
+  return pda;
+}
+
+export function getTnsPriceObservationPda(programId: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("tns_price_observation")],
+    programId
+  );
+  return pda;
+}
+
+export function getPlatformPda(programId: PublicKey, authority: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform"), authority.toBuffer()],
+    programId
+  );
+  return pda;
+}
+
+export function getTreasuryPda(programId: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    programId
+  );
+  return pda;
+}
+
+export function getOracleConfigPda(programId: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("oracle_config")],
+    programId
+  );
+  return pda;
+}
+
+export function getPaymentAssetPda(programId: PublicKey, mint: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("payment_asset"), mint.toBuffer()],
+    programId
+  );
+  return pda;
+}
+
+export function getBondVaultPda(programId: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("bond_vault")],
+    programId
+  );
+  return pda;
+}
+
+export function getRevenueVaultPda(programId: PublicKey, mint: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("revenue_vault"), mint.toBuffer()],
+    programId
+  );
+  return pda;
+}
+
+export function getRevenueTokensPda(programId: PublicKey, mint: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("revenue_tokens"), mint.toBuffer()],
+    programId
+  );
+  return pda;
+}
+
 export function getTokenPda(programId: PublicKey, symbol: string): PublicKey {
   const [tokenPda] = PublicKey.findProgramAddressSync(
     [Buffer.from("token"), Buffer.from(symbol)],
@@ -253,6 +373,9 @@ export async function ensureConfigInitialized(ctx: TestContext): Promise<void> {
     ctx.feeCollectorPubkey = config.feeCollector;
     ctx.currentPhase = config.phase;
     ctx.solUsdPythFeed = config.solUsdPythFeed;
+    await ensureKeeperVaultInitialized(ctx);
+    await ensureOracleConfigInitialized(ctx);
+    await ensureBondVaultInitialized(ctx);
     return;
   }
 
@@ -286,6 +409,226 @@ export async function ensureConfigInitialized(ctx: TestContext): Promise<void> {
   // Set the initial values
   ctx.feeCollectorPubkey = feeCollector.publicKey;
   ctx.currentPhase = 1;
+
+  await ensureKeeperVaultInitialized(ctx);
+  await ensureOracleConfigInitialized(ctx);
+  await ensureBondVaultInitialized(ctx);
+}
+
+// Create the oracle config with default feeds if it doesn't exist yet (required by every priced instruction)
+export async function ensureOracleConfigInitialized(ctx: TestContext): Promise<PublicKey> {
+  const { program, admin, configPda, provider } = ctx;
+  const oracleConfigPda = getOracleConfigPda(program.programId);
+
+  const accountInfo = await provider.connection.getAccountInfo(oracleConfigPda);
+  if (accountInfo !== null) {
+    return oracleConfigPda;
+  }
+
+  await program.methods
+    .initializeOracleConfig()
+    .accountsPartial({
+      admin: admin.publicKey,
+      config: configPda,
+      oracleConfig: oracleConfigPda,
+    })
+    .rpc();
+
+  return oracleConfigPda;
+}
+
+// Create the keeper vault if it doesn't exist yet (required by register/cancel/verify)
+export async function ensureKeeperVaultInitialized(ctx: TestContext): Promise<void> {
+  const { program, admin, configPda, keeperVaultPda, provider } = ctx;
+
+  const accountInfo = await provider.connection.getAccountInfo(keeperVaultPda);
+  if (accountInfo !== null) {
+    return;
+  }
+
+  await program.methods
+    .initializeKeeperVault()
+    .accountsPartial({
+      admin: admin.publicKey,
+      config: configPda,
+      keeperVault: keeperVaultPda,
+    })
+    .rpc();
+}
+
+// Create the bond vault if it doesn't exist yet (required by SOL register/claim, release and closes)
+export async function ensureBondVaultInitialized(ctx: TestContext): Promise<PublicKey> {
+  const { program, admin, configPda, provider } = ctx;
+  const bondVaultPda = getBondVaultPda(program.programId);
+
+  const accountInfo = await provider.connection.getAccountInfo(bondVaultPda);
+  if (accountInfo !== null) {
+    return bondVaultPda;
+  }
+
+  await program.methods
+    .initializeBondVault()
+    .accountsPartial({
+      admin: admin.publicKey,
+      config: configPda,
+      bondVault: bondVaultPda,
+    })
+    .rpc();
+
+  return bondVaultPda;
+}
+
+// Create the TNS price sources with the fixture pool if they don't exist yet
+export async function ensureTnsPriceSourcesInitialized(ctx: TestContext): Promise<PublicKey> {
+  const { program, admin, configPda, provider } = ctx;
+  const priceSourcesPda = getTnsPriceSourcesPda(program.programId);
+
+  const accountInfo = await provider.connection.getAccountInfo(priceSourcesPda);
+  if (accountInfo !== null) {
+    return priceSourcesPda;
+  }
+
+  await program.methods
+    .initializeTnsPriceSources(
+      [{ kind: { vaultPair: {} }, tnsVault: POOL_TNS_RESERVE, solVault: POOL_SOL_RESERVE }],
+      1
+    )
+    .accountsPartial({
+      admin: admin.publicKey,
+      config: configPda,
+      tnsPriceSources: priceSourcesPda,
+    })
+    .rpc();
+
+  return priceSourcesPda;
+}
+
+// Register a fresh platform whose authority is also its (funded) payout wallet
+export async function registerPlatform(
+  ctx: TestContext,
+  maxFeeBps: number
+): Promise<{ authority: Keypair; platformPda: PublicKey }> {
+  const { program, admin, configPda, provider } = ctx;
+  const authority = Keypair.generate();
+  await fundAccounts(provider, authority);
+
+  const platformPda = getPlatformPda(program.programId, authority.publicKey);
+
+  await program.methods
+    .registerPlatform(authority.publicKey, authority.publicKey, maxFeeBps)
+    .accountsPartial({
+      admin: admin.publicKey,
+      config: configPda,
+      platform: platformPda,
+    })
+    .rpc();
+
+  return { authority, platformPda };
+}
+
+// Register a symbol with SOL (fresh metadata mint unless one is given)
+// Pays from the admin wallet by default; bondVault defaults to the bond vault PDA.
+export async function registerSymbolSol(
+  ctx: TestContext,
+  symbol: string,
+  options: {
+    payer?: Keypair;
+    mint?: PublicKey;
+    years?: number;
+    bondVault?: PublicKey | null;
+  } = {}
+): Promise<PublicKey> {
+  const { program, admin, provider } = ctx;
+  const mint = options.mint ?? (await createTokenWithMetadata(provider, admin, symbol));
+  const tokenPda = getTokenPda(program.programId, symbol);
+  const bondVault =
+    options.bondVault === undefined ? getBondVaultPda(program.programId) : options.bondVault;
+
+  const builder = program.methods
+    .registerSymbolSol(symbol, options.years ?? 1, new anchor.BN(1_000_000_000), 0)
+    .accountsPartial({
+      payer: options.payer?.publicKey ?? admin.publicKey,
+      config: ctx.configPda,
+      keeperVault: ctx.keeperVaultPda,
+      bondVault,
+      tokenAccount: tokenPda,
+      tokenMint: mint,
+      tokenMetadata: getMetadataPda(mint),
+      feeCollector: ctx.feeCollectorPubkey,
+      priceUpdate: ctx.priceUpdate,
+      platform: null,
+      platformFeeAccount: null,
+      revenueVault: null,
+    });
+
+  await (options.payer ? builder.signers([options.payer]) : builder).rpc();
+
+  return tokenPda;
+}
+
+// Create the treasury (allowlisting the fee collector) if it doesn't exist yet
+export async function ensureTreasuryInitialized(ctx: TestContext): Promise<PublicKey> {
+  const { program, admin, configPda, provider } = ctx;
+  const treasuryPda = getTreasuryPda(program.programId);
+
+  const accountInfo = await provider.connection.getAccountInfo(treasuryPda);
+  if (accountInfo !== null) {
+    return treasuryPda;
+  }
+
+  await program.methods
+    .initializeTreasury([ctx.feeCollectorPubkey])
+    .accountsPartial({
+      admin: admin.publicKey,
+      config: configPda,
+      treasury: treasuryPda,
+    })
+    .rpc();
+
+  return treasuryPda;
+}
+
+// Get (creating if needed) the treasury vault for a mint: the treasury PDA's associated token account
+export async function getTreasuryVault(
+  ctx: TestContext,
+  mint: PublicKey,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<PublicKey> {
+  const treasuryPda = await ensureTreasuryInitialized(ctx);
+
+  const vault = await getOrCreateAssociatedTokenAccount(
+    ctx.provider.connection,
+    ctx.admin.payer,
+    mint,
+    treasuryPda,
+    true,
+    undefined,
+    undefined,
+    tokenProgram
+  );
+  return vault.address;
+}
+
+// Create the TNS price observation buffer if it doesn't exist yet
+export async function ensureTnsPriceObservationInitialized(ctx: TestContext): Promise<PublicKey> {
+  const { program, admin, configPda, provider } = ctx;
+  const observationPda = getTnsPriceObservationPda(program.programId);
+
+  const accountInfo = await provider.connection.getAccountInfo(observationPda);
+  if (accountInfo !== null) {
+    return observationPda;
+  }
+
+  await program.methods
+    .initializeTnsPriceObservation()
+    .accountsPartial({
+      admin: admin.publicKey,
+      config: configPda,
+      tnsPriceObservation: observationPda,
+    })
+    .rpc();
+
+  return observationPda;
 }
 
 // Refresh config state from chain
@@ -327,6 +670,7 @@ export function setupTest(): TestContext {
   const program = anchor.workspace.Tns as Program<Tns>;
   const admin = provider.wallet as anchor.Wallet;
   const configPda = getConfigPda(program.programId);
+  const keeperVaultPda = getKeeperVaultPda(program.programId);
 
   const feeCollector = Keypair.generate();
   const registrant = Keypair.generate();
@@ -336,6 +680,7 @@ export function setupTest(): TestContext {
     program,
     admin,
     configPda,
+    keeperVaultPda,
     feeCollector,
     feeCollectorPubkey: feeCollector.publicKey, // Will be updated in ensureConfigInitialized
     registrant,
@@ -0,0 +1,169 @@
+import BN from "bn.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  getTokenPda,
+  getBalance,
+  refreshConfigState,
+  ensureUnpaused,
+  createTokenWithMetadata,
+  getMetadataPda,
+  registerSymbolSol,
+} from "./helpers/setup";
+
+const SECONDS_PER_YEAR = 31_557_600;
+
+describe("TNS - Keeper Vault", () => {
+  let ctx: TestContext;
+
+  async function reconcile(sweepSurplus: boolean) {
+    await ctx.program.methods
+      .reconcileKeeperVault(sweepSurplus)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        keeperVault: ctx.keeperVaultPda,
+        feeCollector: ctx.feeCollectorPubkey,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+  });
+
+  it("records each registration's deposit on the symbol and the vault", async () => {
+    const config = await ctx.program.account.config.fetch(ctx.configPda);
+    const vaultBefore = await ctx.program.account.keeperVault.fetch(ctx.keeperVaultPda);
+    const balanceBefore = await getBalance(ctx.provider, ctx.keeperVaultPda);
+
+    const tokenPda = await registerSymbolSol(ctx, "KVLTA");
+
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    const vaultAfter = await ctx.program.account.keeperVault.fetch(ctx.keeperVaultPda);
+    const balanceAfter = await getBalance(ctx.provider, ctx.keeperVaultPda);
+
+    const reward = config.keeperRewardLamports.toNumber();
+    expect(token.keeperDeposit.toNumber()).to.equal(reward);
+    expect(
+      vaultAfter.outstandingDeposits.sub(vaultBefore.outstandingDeposits).toNumber()
+    ).to.equal(reward);
+    expect(balanceAfter - balanceBefore).to.equal(reward);
+  });
+
+  it("reconcile sweeps only the surplus above outstanding deposits and the legacy reserve", async () => {
+    await registerSymbolSol(ctx, "KVLTB");
+
+    // Simulate legacy keeper rewards left in the Config PDA
+    const legacyLamports = 100_000_000;
+    await ctx.provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: ctx.admin.publicKey,
+          toPubkey: ctx.configPda,
+          lamports: legacyLamports,
+        })
+      )
+    );
+
+    const vaultBefore = await ctx.program.account.keeperVault.fetch(ctx.keeperVaultPda);
+    await reconcile(true);
+
+    const vault = await ctx.program.account.keeperVault.fetch(ctx.keeperVaultPda);
+    const info = await ctx.provider.connection.getAccountInfo(ctx.keeperVaultPda);
+    const rent = await ctx.provider.connection.getMinimumBalanceForRentExemption(
+      info.data.length
+    );
+
+    expect(vault.legacyReserve.sub(vaultBefore.legacyReserve).toNumber()).to.equal(legacyLamports);
+    expect(info.lamports).to.equal(
+      rent + vault.outstandingDeposits.toNumber() + vault.legacyReserve.toNumber()
+    );
+  });
+
+  it("pays keepers of symbols without a deposit from the legacy reserve", async () => {
+    const symbol = "KVLTD";
+    const keeper = Keypair.generate();
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, symbol);
+    const tokenPda = getTokenPda(ctx.program.programId, symbol);
+    await fundAccounts(ctx.provider, keeper);
+
+    await ctx.program.methods
+      .seedSymbol(symbol, 1, ctx.admin.publicKey)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+        tokenMint: mint,
+        tokenMetadata: getMetadataPda(mint),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const threeYearsAgo = Math.floor(Date.now() / 1000) - 3 * SECONDS_PER_YEAR;
+    await ctx.program.methods
+      .adminUpdateSymbol(null, null, new BN(threeYearsAgo))
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+      })
+      .rpc();
+
+    const config = await ctx.program.account.config.fetch(ctx.configPda);
+    const vaultBefore = await ctx.program.account.keeperVault.fetch(ctx.keeperVaultPda);
+    const rent = await getBalance(ctx.provider, tokenPda);
+    const keeperBefore = await getBalance(ctx.provider, keeper.publicKey);
+
+    await ctx.program.methods
+      .cancelSymbol()
+      .accountsPartial({
+        keeper: keeper.publicKey,
+        config: ctx.configPda,
+        keeperVault: ctx.keeperVaultPda,
+        tokenAccount: tokenPda,
+      })
+      .signers([keeper])
+      .rpc();
+
+    const vaultAfter = await ctx.program.account.keeperVault.fetch(ctx.keeperVaultPda);
+    const keeperAfter = await getBalance(ctx.provider, keeper.publicKey);
+    const expected = Math.min(
+      config.keeperRewardLamports.toNumber(),
+      vaultBefore.legacyReserve.toNumber()
+    );
+
+    expect(expected).to.be.greaterThan(0);
+    expect(vaultBefore.legacyReserve.sub(vaultAfter.legacyReserve).toNumber()).to.equal(expected);
+    // Keeper pays the tx fee (5000 lamports) out of the payout
+    expect(keeperAfter - keeperBefore).to.equal(rent + expected - 5000);
+  });
+
+  it("admin close releases the symbol's deposit to surplus", async () => {
+    const tokenPda = await registerSymbolSol(ctx, "KVLTC");
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    const vaultBefore = await ctx.program.account.keeperVault.fetch(ctx.keeperVaultPda);
+
+    await ctx.program.methods
+      .adminCloseSymbol()
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        keeperVault: ctx.keeperVaultPda,
+        tokenAccount: tokenPda,
+      })
+      .rpc();
+
+    const vaultAfter = await ctx.program.account.keeperVault.fetch(ctx.keeperVaultPda);
+    expect(
+      vaultBefore.outstandingDeposits.sub(vaultAfter.outstandingDeposits).toNumber()
+    ).to.equal(token.keeperDeposit.toNumber());
+  });
+});
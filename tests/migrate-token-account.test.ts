@@ -0,0 +1,84 @@
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  LEGACY_TOKEN_ACCOUNT,
+  LEGACY_TOKEN_ACCOUNT_LEN,
+} from "./helpers/setup";
+import {
+  findLegacyTokenAccounts,
+  migrateTokenAccounts,
+  TOKEN_ACCOUNT_LEN,
+} from "../scripts/migrate-token-accounts";
+
+describe("TNS - Token Account Migration", () => {
+  let ctx: TestContext;
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+  });
+
+  it("the migration script grows legacy Token accounts before anything else loads them", async () => {
+    const before = await ctx.provider.connection.getAccountInfo(LEGACY_TOKEN_ACCOUNT);
+    expect(before.data.length).to.equal(LEGACY_TOKEN_ACCOUNT_LEN);
+
+    const legacy = await findLegacyTokenAccounts(ctx.program);
+    expect(legacy.map(({ pubkey }) => pubkey.toString())).to.include(LEGACY_TOKEN_ACCOUNT.toString());
+
+    await migrateTokenAccounts(ctx.program, legacy.map(({ pubkey }) => pubkey));
+
+    const after = await ctx.provider.connection.getAccountInfo(LEGACY_TOKEN_ACCOUNT);
+    expect(after.data.length).to.equal(TOKEN_ACCOUNT_LEN);
+    expect(after.lamports).to.equal(
+      await ctx.provider.connection.getMinimumBalanceForRentExemption(after.data.length)
+    );
+    expect(await findLegacyTokenAccounts(ctx.program)).to.be.empty;
+
+    const token = await ctx.program.account.token.fetch(LEGACY_TOKEN_ACCOUNT);
+    expect(token.symbol).to.equal("LGCY");
+    expect(token.priceLock.yearlyPriceUsdMicro.toNumber()).to.equal(0);
+    expect(token.bondLamports.toNumber()).to.equal(0);
+
+    // Instructions taking Account<Token> now load it
+    await ctx.program.methods
+      .adminUpdateSymbol(null, null, token.expiresAt)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: LEGACY_TOKEN_ACCOUNT,
+      })
+      .rpc();
+  });
+
+  it("rejects migrating an account that is already up to date", async () => {
+    try {
+      await ctx.program.methods
+        .migrateTokenAccount()
+        .accountsPartial({
+          payer: ctx.admin.publicKey,
+          tokenAccount: LEGACY_TOKEN_ACCOUNT,
+        })
+        .rpc();
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("TokenAccountUpToDate");
+    }
+  });
+
+  it("rejects migrating an account that is not a Token", async () => {
+    try {
+      await ctx.program.methods
+        .migrateTokenAccount()
+        .accountsPartial({
+          payer: ctx.admin.publicKey,
+          tokenAccount: ctx.configPda,
+        })
+        .rpc();
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidTokenAccount");
+    }
+  });
+});
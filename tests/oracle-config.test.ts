@@ -0,0 +1,363 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  getTokenPda,
+  getOracleConfigPda,
+  refreshConfigState,
+  ensureUnpaused,
+  createTokenWithMetadata,
+  getMetadataPda,
+  USDC_USD_PRICE_UPDATE,
+  SOL_USD_PRICE_UPDATE_STALE,
+} from "./helpers/setup";
+
+// Max slippage for tests (1 SOL)
+const MAX_SOL_COST = new BN(1_000_000_000);
+
+const PYTH_RECEIVER_PROGRAM_ID = new PublicKey("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+type OracleUpdate = {
+  maxStalenessSeconds?: BN;
+  priceUpdateOwner?: PublicKey;
+  minSolPriceUsdMicro?: BN;
+  maxSolPriceUsdMicro?: BN;
+  maxDeviationBps?: number;
+  autoPause?: boolean;
+  tripAfterViolations?: number;
+  maxLastGoodAgeSeconds?: BN;
+};
+
+describe("TNS - Oracle Config", () => {
+  let ctx: TestContext;
+  let oracleConfigPda: PublicKey;
+
+  async function updateOracleConfig(update: OracleUpdate, admin?: Keypair) {
+    const builder = ctx.program.methods
+      .updateOracleConfig(
+        null,
+        update.maxStalenessSeconds ?? null,
+        null,
+        update.priceUpdateOwner ?? null,
+        null,
+        update.minSolPriceUsdMicro ?? null,
+        update.maxSolPriceUsdMicro ?? null,
+        update.maxDeviationBps ?? null,
+        update.autoPause ?? null,
+        update.tripAfterViolations ?? null,
+        update.maxLastGoodAgeSeconds ?? null
+      )
+      .accountsPartial({
+        admin: admin ? admin.publicKey : ctx.admin.publicKey,
+        config: ctx.configPda,
+        oracleConfig: oracleConfigPda,
+      });
+
+    await (admin ? builder.signers([admin]) : builder).rpc();
+  }
+
+  async function registerSol(symbol: string, priceUpdate: PublicKey | null = ctx.priceUpdate) {
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, symbol);
+
+    await ctx.program.methods
+      .registerSymbolSol(symbol, 1, MAX_SOL_COST, 0)
+      .accountsPartial({
+        payer: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: getTokenPda(ctx.program.programId, symbol),
+        tokenMint: mint,
+        tokenMetadata: getMetadataPda(mint),
+        feeCollector: ctx.feeCollectorPubkey,
+        priceUpdate,
+        oracleConfig: oracleConfigPda,
+        platform: null,
+        platformFeeAccount: null,
+        revenueVault: null,
+      })
+      .rpc();
+  }
+
+  async function recordSolPrice() {
+    await ctx.program.methods
+      .recordSolPrice()
+      .accountsPartial({
+        cranker: ctx.admin.publicKey,
+        priceUpdate: ctx.priceUpdate,
+        oracleConfig: oracleConfigPda,
+      })
+      .rpc();
+  }
+
+  async function resetSolCircuit(lastGoodPriceUsdMicro: number) {
+    await ctx.program.methods
+      .resetSolCircuit(new BN(lastGoodPriceUsdMicro))
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        oracleConfig: oracleConfigPda,
+      })
+      .rpc();
+  }
+
+  async function postFallbackSolPrice(priceUsdMicro: number, ttlSeconds: number, admin?: Keypair) {
+    const builder = ctx.program.methods
+      .postFallbackSolPrice(new BN(priceUsdMicro), new BN(ttlSeconds))
+      .accountsPartial({
+        admin: admin ? admin.publicKey : ctx.admin.publicKey,
+        config: ctx.configPda,
+        oracleConfig: oracleConfigPda,
+      });
+
+    await (admin ? builder.signers([admin]) : builder).rpc();
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await fundAccounts(ctx.provider, ctx.feeCollector);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+
+    oracleConfigPda = getOracleConfigPda(ctx.program.programId);
+  });
+
+  it("initializes with the default feeds and verification rules", async () => {
+    const oracle = await ctx.program.account.oracleConfig.fetch(oracleConfigPda);
+    expect(oracle.maxStalenessSeconds.toNumber()).to.equal(60);
+    expect(oracle.minVerification).to.deep.equal({ partial: { numSignatures: 3 } });
+    expect(oracle.priceUpdateOwner.toString()).to.equal(PYTH_RECEIVER_PROGRAM_ID.toString());
+  });
+
+  it("admin changes the maximum staleness", async () => {
+    await updateOracleConfig({ maxStalenessSeconds: new BN(120) });
+
+    let oracle = await ctx.program.account.oracleConfig.fetch(oracleConfigPda);
+    expect(oracle.maxStalenessSeconds.toNumber()).to.equal(120);
+
+    await updateOracleConfig({ maxStalenessSeconds: new BN(60) });
+    oracle = await ctx.program.account.oracleConfig.fetch(oracleConfigPda);
+    expect(oracle.maxStalenessSeconds.toNumber()).to.equal(60);
+  });
+
+  it("rejects a staleness above one hour", async () => {
+    try {
+      await updateOracleConfig({ maxStalenessSeconds: new BN(3601) });
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidOracleConfig");
+    }
+  });
+
+  it("rejects price updates not owned by the configured program", async () => {
+    await updateOracleConfig({ priceUpdateOwner: Keypair.generate().publicKey });
+
+    try {
+      await registerSol("ORCA");
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidPriceUpdateOwner");
+    } finally {
+      await updateOracleConfig({ priceUpdateOwner: PYTH_RECEIVER_PROGRAM_ID });
+    }
+  });
+
+  it("rejects a SOL/USD price outside the configured bounds", async () => {
+    await updateOracleConfig({ maxSolPriceUsdMicro: new BN(1) });
+
+    try {
+      await registerSol("ORCB");
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("SolPriceOutOfBounds");
+    } finally {
+      await updateOracleConfig({ maxSolPriceUsdMicro: new BN(0) });
+    }
+  });
+
+  it("rejects a SOL/USD price too far from the last good price", async () => {
+    await updateOracleConfig({ maxDeviationBps: 1000 });
+    await resetSolCircuit(1_000_000);
+
+    try {
+      await registerSol("ORCB");
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("SolPriceDeviationTooLarge");
+    } finally {
+      await resetSolCircuit(0);
+      await updateOracleConfig({ maxDeviationBps: 0 });
+    }
+  });
+
+  it("skips the deviation guard once the last good price is older than the max age", async () => {
+    await updateOracleConfig({ maxDeviationBps: 1000, maxLastGoodAgeSeconds: new BN(1) });
+    await resetSolCircuit(1_000_000);
+
+    try {
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await registerSol("ORCE");
+    } finally {
+      await resetSolCircuit(0);
+      await updateOracleConfig({ maxDeviationBps: 0, maxLastGoodAgeSeconds: new BN(0) });
+    }
+  });
+
+  it("records a SOL/USD price passing every guard as the last good price", async () => {
+    await recordSolPrice();
+
+    const oracle = await ctx.program.account.oracleConfig.fetch(oracleConfigPda);
+    expect(oracle.lastGoodSolPriceMicro.toNumber()).to.be.greaterThan(0);
+    expect(oracle.solCircuitTripped).to.be.false;
+  });
+
+  it("trips the circuit breaker on a violation with auto-pause and halts SOL payments", async () => {
+    await updateOracleConfig({ maxSolPriceUsdMicro: new BN(1), autoPause: true, tripAfterViolations: 1 });
+
+    try {
+      await recordSolPrice();
+
+      const oracle = await ctx.program.account.oracleConfig.fetch(oracleConfigPda);
+      expect(oracle.solCircuitTripped).to.be.true;
+      expect(oracle.solViolationCount).to.equal(1);
+
+      // Payments stay halted even once the guard itself is lifted
+      await updateOracleConfig({ maxSolPriceUsdMicro: new BN(0) });
+      try {
+        await registerSol("ORCC");
+        expect.fail("Should have thrown an error");
+      } catch (err) {
+        expect(err.message).to.include("SolPriceCircuitTripped");
+      }
+
+      await resetSolCircuit(0);
+      await registerSol("ORCC");
+    } finally {
+      await updateOracleConfig({ maxSolPriceUsdMicro: new BN(0), autoPause: false, tripAfterViolations: 0 });
+    }
+  });
+
+  it("counts each violating update once toward tripping the circuit", async () => {
+    await updateOracleConfig({ maxSolPriceUsdMicro: new BN(1), autoPause: true });
+
+    try {
+      // The same update already counted above, so replaying it fails instead of counting again
+      try {
+        await recordSolPrice();
+        expect.fail("Should have thrown an error");
+      } catch (err) {
+        expect(err.message).to.include("SolPriceOutOfBounds");
+      }
+
+      const oracle = await ctx.program.account.oracleConfig.fetch(oracleConfigPda);
+      expect(oracle.solCircuitTripped).to.be.false;
+      expect(oracle.solViolationCount).to.equal(0);
+    } finally {
+      await updateOracleConfig({ maxSolPriceUsdMicro: new BN(0), autoPause: false });
+    }
+  });
+
+  it("rejects SOL payments with a stale price update when no fallback is live", async () => {
+    try {
+      await registerSol("ORCD", SOL_USD_PRICE_UPDATE_STALE);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("StalePriceFeed");
+    }
+  });
+
+  it("prices SOL payments from a live fallback price when Pyth is stale", async () => {
+    await postFallbackSolPrice(150_000_000, 300);
+
+    try {
+      const oracle = await ctx.program.account.oracleConfig.fetch(oracleConfigPda);
+      expect(oracle.fallbackSolPriceUsdMicro.toNumber()).to.equal(150_000_000);
+      expect(oracle.fallbackSolPriceExpiresAt.toNumber()).to.be.greaterThan(0);
+
+      await registerSol("ORCD", SOL_USD_PRICE_UPDATE_STALE);
+      const token = await ctx.program.account.token.fetch(getTokenPda(ctx.program.programId, "ORCD"));
+      expect(token.symbol).to.equal("ORCD");
+    } finally {
+      await postFallbackSolPrice(0, 0);
+    }
+
+    const oracle = await ctx.program.account.oracleConfig.fetch(oracleConfigPda);
+    expect(oracle.fallbackSolPriceUsdMicro.toNumber()).to.equal(0);
+  });
+
+  it("requires the price update even while a fallback price is live", async () => {
+    await postFallbackSolPrice(150_000_000, 300);
+
+    try {
+      await registerSol("ORCG", null);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("priceUpdate");
+    } finally {
+      await postFallbackSolPrice(0, 0);
+    }
+  });
+
+  it("does not fall back when the price update is for another feed", async () => {
+    await postFallbackSolPrice(150_000_000, 300);
+
+    try {
+      await registerSol("ORCF", USDC_USD_PRICE_UPDATE);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("PriceFeedMismatch");
+    } finally {
+      await postFallbackSolPrice(0, 0);
+    }
+  });
+
+  it("rejects a fallback price living longer than 15 minutes", async () => {
+    try {
+      await postFallbackSolPrice(150_000_000, 901);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidFallbackPrice");
+    }
+  });
+
+  it("rejects a fallback price outside the configured bounds", async () => {
+    await updateOracleConfig({ maxSolPriceUsdMicro: new BN(100_000_000) });
+
+    try {
+      await postFallbackSolPrice(150_000_000, 300);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidFallbackPrice");
+    } finally {
+      await updateOracleConfig({ maxSolPriceUsdMicro: new BN(0) });
+    }
+  });
+
+  it("non-admin cannot post a fallback price", async () => {
+    const attacker = Keypair.generate();
+    await fundAccounts(ctx.provider, attacker);
+
+    try {
+      await postFallbackSolPrice(150_000_000, 300, attacker);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("UnauthorizedAdmin");
+    }
+  });
+
+  it("non-admin cannot update the oracle config", async () => {
+    const attacker = Keypair.generate();
+    await fundAccounts(ctx.provider, attacker);
+
+    try {
+      await updateOracleConfig({ maxStalenessSeconds: new BN(3600) }, attacker);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("UnauthorizedAdmin");
+    }
+  });
+});
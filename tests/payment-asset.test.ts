@@ -0,0 +1,313 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  createMint,
+  mintTo,
+  getOrCreateAssociatedTokenAccount,
+  getAccount,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  getTokenPda,
+  refreshConfigState,
+  ensureUnpaused,
+  createTokenWithMetadata,
+  getMetadataPda,
+  getTreasuryVault,
+  getPaymentAssetPda,
+} from "./helpers/setup";
+
+// $2 per whole token, 10% discount, 9 decimals
+const PEG_USD_MICRO = new BN(2_000_000);
+const DISCOUNT_BPS = 1000;
+const DECIMALS = 9;
+
+// Max slippage for tests (1,000 whole tokens)
+const MAX_TOKEN_AMOUNT = new BN(1_000).mul(new BN(10).pow(new BN(DECIMALS)));
+
+describe("TNS - Payment Assets", () => {
+  let ctx: TestContext;
+  let paymentMint: PublicKey;
+  let paymentAssetPda: PublicKey;
+  let payerAta: PublicKey;
+  let treasuryVault: PublicKey;
+
+  async function setEnabled(enabled: boolean) {
+    await ctx.program.methods
+      .updatePaymentAsset(null, null, enabled)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        paymentAsset: paymentAssetPda,
+      })
+      .rpc();
+  }
+
+  async function registerWithAsset(
+    symbol: string,
+    maxTokenAmount: BN = MAX_TOKEN_AMOUNT
+  ): Promise<PublicKey> {
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, symbol);
+    const tokenPda = getTokenPda(ctx.program.programId, symbol);
+
+    await ctx.program.methods
+      .registerSymbolAsset(symbol, 1, maxTokenAmount, 0)
+      .accountsPartial({
+        payer: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+        tokenMint: mint,
+        tokenMetadata: getMetadataPda(mint),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        paymentAsset: paymentAssetPda,
+        paymentMint,
+        payerTokenAccount: payerAta,
+        treasuryVault,
+        platform: null,
+        platformFeeAccount: null,
+        revenueVault: null,
+        revenueTokenAccount: null,
+        priceUpdate: null,
+        tnsPriceObservation: null,
+        tnsPriceSources: null,
+        tnsUsdPriceUpdate: null,
+      })
+      .rpc();
+
+    return tokenPda;
+  }
+
+  async function setBondMode(enabled: boolean) {
+    await ctx.program.methods
+      .updateConfig(
+        null, null, null, null, null, null, null, null, null, null, null, null, null, null, null,
+        { enabled, keeperShareBps: 0 }
+      )
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        newAdmin: null,
+      })
+      .rpc();
+  }
+
+  async function treasuryBalance(): Promise<bigint> {
+    return (await getAccount(ctx.provider.connection, treasuryVault)).amount;
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await fundAccounts(ctx.provider, ctx.feeCollector);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+
+    paymentMint = await createMint(
+      ctx.provider.connection,
+      ctx.admin.payer,
+      ctx.admin.publicKey,
+      null,
+      DECIMALS
+    );
+    paymentAssetPda = getPaymentAssetPda(ctx.program.programId, paymentMint);
+
+    payerAta = (
+      await getOrCreateAssociatedTokenAccount(
+        ctx.provider.connection,
+        ctx.admin.payer,
+        paymentMint,
+        ctx.admin.publicKey
+      )
+    ).address;
+    treasuryVault = await getTreasuryVault(ctx, paymentMint);
+
+    await mintTo(
+      ctx.provider.connection,
+      ctx.admin.payer,
+      paymentMint,
+      payerAta,
+      ctx.admin.payer,
+      1_000 * 10 ** DECIMALS
+    );
+  });
+
+  it("admin adds a payment asset with decimals read from the mint", async () => {
+    await ctx.program.methods
+      .addPaymentAsset({ fixedPeg: { usdMicro: PEG_USD_MICRO } }, DISCOUNT_BPS, true)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        mint: paymentMint,
+        paymentAsset: paymentAssetPda,
+      })
+      .rpc();
+
+    const asset = await ctx.program.account.paymentAsset.fetch(paymentAssetPda);
+    expect(asset.mint.toString()).to.equal(paymentMint.toString());
+    expect(asset.decimals).to.equal(DECIMALS);
+    expect(asset.discountBps).to.equal(DISCOUNT_BPS);
+    expect(asset.enabled).to.be.true;
+  });
+
+  it("registers a symbol paying with the asset at its peg and discount", async () => {
+    const config = await ctx.program.account.config.fetch(ctx.configPda);
+    const feeUsdMicro = BigInt(config.basePriceUsdMicro.toString());
+    const expected =
+      (feeUsdMicro * BigInt(10000 - DISCOUNT_BPS) / 10000n) *
+      10n ** BigInt(DECIMALS) /
+      BigInt(PEG_USD_MICRO.toString());
+
+    const before = await treasuryBalance();
+    const tokenPda = await registerWithAsset("PAYA");
+    const after = await treasuryBalance();
+
+    expect(after - before).to.equal(expected);
+
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    expect(token.owner.toString()).to.equal(ctx.admin.publicKey.toString());
+  });
+
+  it("renews a symbol paying with the asset", async () => {
+    const tokenPda = await registerWithAsset("PAYB");
+    const tokenBefore = await ctx.program.account.token.fetch(tokenPda);
+
+    await ctx.program.methods
+      .renewSymbolAsset(1, MAX_TOKEN_AMOUNT, 0)
+      .accountsPartial({
+        payer: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        paymentAsset: paymentAssetPda,
+        paymentMint,
+        payerTokenAccount: payerAta,
+        treasuryVault,
+        platform: null,
+        platformFeeAccount: null,
+        revenueVault: null,
+        revenueTokenAccount: null,
+        priceUpdate: null,
+        tnsPriceObservation: null,
+        tnsPriceSources: null,
+        tnsUsdPriceUpdate: null,
+      })
+      .rpc();
+
+    const tokenAfter = await ctx.program.account.token.fetch(tokenPda);
+    expect(tokenAfter.expiresAt.toNumber()).to.equal(tokenBefore.expiresAt.toNumber() + 31_557_600);
+  });
+
+  it("rejects a payment above the payer's max token amount", async () => {
+    const config = await ctx.program.account.config.fetch(ctx.configPda);
+    const feeUsdMicro = BigInt(config.basePriceUsdMicro.toString());
+    const expected =
+      (feeUsdMicro * BigInt(10000 - DISCOUNT_BPS) / 10000n) *
+      10n ** BigInt(DECIMALS) /
+      BigInt(PEG_USD_MICRO.toString());
+
+    const before = await treasuryBalance();
+
+    try {
+      await registerWithAsset("PAYS", new BN((expected - 1n).toString()));
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("SlippageExceeded");
+    }
+
+    expect(await treasuryBalance()).to.equal(before);
+
+    // Exactly the quoted amount is accepted
+    await registerWithAsset("PAYS", new BN(expected.toString()));
+    expect((await treasuryBalance()) - before).to.equal(expected);
+  });
+
+  it("rejects payments in a disabled asset", async () => {
+    await setEnabled(false);
+
+    try {
+      await registerWithAsset("PAYC");
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("PaymentAssetDisabled");
+    } finally {
+      await setEnabled(true);
+    }
+  });
+
+  it("rejects payments in any asset while bond mode is enabled (bonds are SOL-only)", async () => {
+    await setBondMode(true);
+
+    try {
+      await registerWithAsset("PAYG");
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("BondModeUnsupported");
+    } finally {
+      await setBondMode(false);
+    }
+  });
+
+  it("rejects a discount of 100% or more", async () => {
+    try {
+      await ctx.program.methods
+        .updatePaymentAsset(null, 10000, null)
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          config: ctx.configPda,
+          paymentAsset: paymentAssetPda,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidPaymentAsset");
+    }
+  });
+
+  it("rejects the TNS TWAP price source for any mint but TNS", async () => {
+    try {
+      await ctx.program.methods
+        .updatePaymentAsset({ tnsTwap: {} }, null, null)
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          config: ctx.configPda,
+          paymentAsset: paymentAssetPda,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidPaymentAsset");
+    }
+  });
+
+  it("non-admin cannot add a payment asset", async () => {
+    const attacker = Keypair.generate();
+    await fundAccounts(ctx.provider, attacker);
+
+    const otherMint = await createMint(ctx.provider.connection, ctx.admin.payer, ctx.admin.publicKey, null, 6);
+
+    try {
+      await ctx.program.methods
+        .addPaymentAsset({ fixedPeg: { usdMicro: new BN(1_000_000) } }, 0, true)
+        .accountsPartial({
+          admin: attacker.publicKey,
+          config: ctx.configPda,
+          mint: otherMint,
+          paymentAsset: getPaymentAssetPda(ctx.program.programId, otherMint),
+        })
+        .signers([attacker])
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("UnauthorizedAdmin");
+    }
+  });
+});
@@ -0,0 +1,185 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  getTokenPda,
+  getBalance,
+  getPlatformPda,
+  refreshConfigState,
+  ensureUnpaused,
+  createTokenWithMetadata,
+  getMetadataPda,
+  registerPlatform,
+} from "./helpers/setup";
+
+// Max slippage for tests (1 SOL)
+const MAX_SOL_COST = new BN(1_000_000_000);
+
+const PLATFORM_FEE_BPS = 1000;
+
+describe("TNS - Platform Registry", () => {
+  let ctx: TestContext;
+  let authority: Keypair;
+  let platformPda: PublicKey;
+
+  async function registerWithPlatform(
+    symbol: string,
+    platform: PublicKey | null,
+    platformFeeAccount: PublicKey | null,
+    platformFeeBps: number
+  ) {
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, symbol);
+
+    await ctx.program.methods
+      .registerSymbolSol(symbol, 1, MAX_SOL_COST, platformFeeBps)
+      .accountsPartial({
+        payer: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: getTokenPda(ctx.program.programId, symbol),
+        tokenMint: mint,
+        tokenMetadata: getMetadataPda(mint),
+        feeCollector: ctx.feeCollectorPubkey,
+        priceUpdate: ctx.priceUpdate,
+        platform,
+        platformFeeAccount,
+        revenueVault: null,
+      })
+      .rpc();
+  }
+
+  async function setActive(active: boolean) {
+    await ctx.program.methods
+      .updatePlatform(null, null, active)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        platform: platformPda,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await fundAccounts(ctx.provider, ctx.feeCollector);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+
+    ({ authority, platformPda } = await registerPlatform(ctx, PLATFORM_FEE_BPS));
+  });
+
+  it("admin registers an active platform paying out to its wallet", async () => {
+    const platform = await ctx.program.account.platform.fetch(platformPda);
+    expect(platform.authority.toString()).to.equal(authority.publicKey.toString());
+    expect(platform.payoutWallet.toString()).to.equal(authority.publicKey.toString());
+    expect(platform.maxFeeBps).to.equal(PLATFORM_FEE_BPS);
+    expect(platform.active).to.be.true;
+    expect(platform.operationCount.toNumber()).to.equal(0);
+  });
+
+  it("rejects a platform maximum above 50%", async () => {
+    const other = Keypair.generate();
+
+    try {
+      await ctx.program.methods
+        .registerPlatform(other.publicKey, other.publicKey, 5001)
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          config: ctx.configPda,
+          platform: getPlatformPda(ctx.program.programId, other.publicKey),
+        })
+        .rpc();
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("PlatformFeeExceedsMax");
+    }
+  });
+
+  it("pays the split to the payout wallet and updates the counters", async () => {
+    const payoutBefore = await getBalance(ctx.provider, authority.publicKey);
+
+    await registerWithPlatform("PLTA", platformPda, authority.publicKey, PLATFORM_FEE_BPS);
+
+    expect(await getBalance(ctx.provider, authority.publicKey)).to.be.greaterThan(payoutBefore);
+
+    const platform = await ctx.program.account.platform.fetch(platformPda);
+    const volume = platform.volumeUsdMicro.toNumber();
+    expect(platform.operationCount.toNumber()).to.equal(1);
+    expect(volume).to.be.greaterThan(0);
+    expect(platform.feesUsdMicro.toNumber()).to.equal(Math.floor((volume * PLATFORM_FEE_BPS) / 10000));
+  });
+
+  it("rejects a fee split without a registered platform", async () => {
+    const wallet = Keypair.generate();
+
+    try {
+      await registerWithPlatform("PLTB", null, wallet.publicKey, PLATFORM_FEE_BPS);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("PlatformRequired");
+    }
+  });
+
+  it("rejects a payout account other than the platform's wallet", async () => {
+    const wallet = Keypair.generate();
+    await fundAccounts(ctx.provider, wallet);
+
+    try {
+      await registerWithPlatform("PLTB", platformPda, wallet.publicKey, PLATFORM_FEE_BPS);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidPlatformPayout");
+    }
+  });
+
+  it("rejects an inactive platform", async () => {
+    await setActive(false);
+
+    try {
+      await registerWithPlatform("PLTB", platformPda, authority.publicKey, PLATFORM_FEE_BPS);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("PlatformInactive");
+    } finally {
+      await setActive(true);
+    }
+  });
+
+  it("platform authority changes its payout wallet", async () => {
+    const wallet = Keypair.generate();
+
+    await ctx.program.methods
+      .updatePlatformPayout(wallet.publicKey)
+      .accountsPartial({
+        authority: authority.publicKey,
+        platform: platformPda,
+      })
+      .signers([authority])
+      .rpc();
+
+    const platform = await ctx.program.account.platform.fetch(platformPda);
+    expect(platform.payoutWallet.toString()).to.equal(wallet.publicKey.toString());
+  });
+
+  it("non-admin cannot update a platform", async () => {
+    try {
+      await ctx.program.methods
+        .updatePlatform(null, 5000, null)
+        .accountsPartial({
+          admin: authority.publicKey,
+          config: ctx.configPda,
+          platform: platformPda,
+        })
+        .signers([authority])
+        .rpc();
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("UnauthorizedAdmin");
+    }
+  });
+});
@@ -0,0 +1,116 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  getBalance,
+  refreshConfigState,
+  ensureUnpaused,
+  registerSymbolSol,
+} from "./helpers/setup";
+
+// Max slippage for tests (1 SOL)
+const MAX_SOL_COST = new BN(1_000_000_000);
+
+const DISABLED_POLICY = { enabled: false, maxEscalationBps: 0 };
+
+describe("TNS - Price Lock", () => {
+  let ctx: TestContext;
+  let originalBasePrice: BN;
+
+  async function updateConfig(
+    basePriceUsdMicro: BN | null,
+    priceLockPolicy: typeof DISABLED_POLICY | null
+  ) {
+    await ctx.program.methods
+      .updateConfig(null, null, null, null, null, basePriceUsdMicro, null, null, null, null, null, priceLockPolicy)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+      })
+      .rpc();
+  }
+
+  // Returns the fee collected for a 1-year SOL renewal
+  async function renew(tokenPda: PublicKey): Promise<number> {
+    const before = await getBalance(ctx.provider, ctx.feeCollectorPubkey);
+
+    await ctx.program.methods
+      .renewSymbolSol(1, MAX_SOL_COST, 0)
+      .accountsPartial({
+        payer: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+        feeCollector: ctx.feeCollectorPubkey,
+        priceUpdate: ctx.priceUpdate,
+        platform: null,
+        platformFeeAccount: null,
+        keeperVault: null,
+        revenueVault: null,
+      })
+      .rpc();
+
+    const after = await getBalance(ctx.provider, ctx.feeCollectorPubkey);
+    return after - before;
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await fundAccounts(ctx.provider, ctx.feeCollector);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+
+    const config = await ctx.program.account.config.fetch(ctx.configPda);
+    originalBasePrice = config.basePriceUsdMicro;
+  });
+
+  after(async () => {
+    await updateConfig(originalBasePrice, DISABLED_POLICY);
+  });
+
+  it("does not record a lock while the policy is disabled", async () => {
+    await updateConfig(null, DISABLED_POLICY);
+
+    const tokenPda = await registerSymbolSol(ctx, "PLKA");
+
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    expect(token.priceLock.yearlyPriceUsdMicro.toNumber()).to.equal(0);
+  });
+
+  it("renews at the locked price after the base price rises", async () => {
+    await updateConfig(null, { enabled: true, maxEscalationBps: 500 });
+
+    const tokenPda = await registerSymbolSol(ctx, "PLKB");
+
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    expect(token.priceLock.yearlyPriceUsdMicro.toNumber()).to.be.greaterThan(0);
+    expect(token.priceLock.lockedAt.toNumber()).to.be.greaterThan(0);
+
+    const lockedFee = await renew(tokenPda);
+
+    await updateConfig(originalBasePrice.muln(2), null);
+    const feeAfterIncrease = await renew(tokenPda);
+    expect(feeAfterIncrease).to.equal(lockedFee);
+
+    // Disabling the policy falls back to the current price
+    await updateConfig(null, DISABLED_POLICY);
+    const unlockedFee = await renew(tokenPda);
+    expect(unlockedFee).to.be.greaterThan(lockedFee);
+
+    await updateConfig(originalBasePrice, null);
+  });
+
+  it("rejects escalation above 10000 basis points", async () => {
+    try {
+      await updateConfig(null, { enabled: true, maxEscalationBps: 10001 });
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidPriceLockPolicy");
+    }
+  });
+});
@@ -1,6 +1,5 @@
 import * as anchor from "@coral-xyz/anchor";
 import BN from "bn.js";
-import { Keypair } from "@solana/web3.js";
 import { expect } from "chai";
 import {
   setupTest,
@@ -13,6 +12,7 @@ import {
   ensureUnpaused,
   createTokenWithMetadata,
   getMetadataPda,
+  registerPlatform,
 } from "./helpers/setup";
 
 // Max slippage for tests (1 SOL)
@@ -75,7 +75,9 @@ describe("TNS - Register Symbol", () => {
         tokenMetadata: tokenMetadata,
         feeCollector: feeCollectorPubkey,
         priceUpdate: priceUpdate,
+        platform: null,
         platformFeeAccount: null,
+        revenueVault: null,
       })
       .rpc();
 
@@ -118,7 +120,9 @@ describe("TNS - Register Symbol", () => {
         tokenMetadata: tokenMetadata,
         feeCollector: feeCollectorPubkey,
         priceUpdate: priceUpdate,
+        platform: null,
         platformFeeAccount: null,
+        revenueVault: null,
       })
       .rpc();
 
@@ -152,7 +156,9 @@ describe("TNS - Register Symbol", () => {
           tokenMint: tokenMint,
           tokenMetadata: tokenMetadata,
           tokenAccount: tokenPda,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -182,7 +188,9 @@ describe("TNS - Register Symbol", () => {
           tokenMint: tokenMint,
           tokenMetadata: tokenMetadata,
           tokenAccount: tokenPda,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -211,7 +219,9 @@ describe("TNS - Register Symbol", () => {
           tokenMint: tokenMint,
           tokenMetadata: tokenMetadata,
           tokenAccount: tokenPda,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -241,7 +251,9 @@ describe("TNS - Register Symbol", () => {
           tokenMint: tokenMint,
           tokenMetadata: tokenMetadata,
           tokenAccount: tokenPda,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -271,7 +283,9 @@ describe("TNS - Register Symbol", () => {
           tokenMint: tokenMint,
           tokenMetadata: tokenMetadata,
           tokenAccount: tokenPda,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -299,7 +313,9 @@ describe("TNS - Register Symbol", () => {
           tokenMetadata: tokenMetadata,
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -326,7 +342,9 @@ describe("TNS - Register Symbol", () => {
             tokenMetadata: tokenMetadata,
             feeCollector: feeCollectorPubkey,
             priceUpdate: priceUpdate,
+            platform: null,
             platformFeeAccount: null,
+            revenueVault: null,
           })
           .rpc();
 
@@ -345,8 +363,7 @@ describe("TNS - Register Symbol", () => {
       const tokenMint = await getOrCreateTokenMint(symbol);
       const tokenMetadata = getMetadataPda(tokenMint);
 
-      const platformAccount = Keypair.generate();
-      await fundAccounts(ctx.provider, platformAccount);
+      const { authority: platformAccount, platformPda } = await registerPlatform(ctx, 1000);
 
       const feeCollectorBefore = await getBalance(ctx.provider, feeCollectorPubkey);
       const platformBefore = await getBalance(ctx.provider, platformAccount.publicKey);
@@ -362,7 +379,9 @@ describe("TNS - Register Symbol", () => {
           tokenMetadata: tokenMetadata,
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
+          platform: platformPda,
           platformFeeAccount: platformAccount.publicKey,
+          revenueVault: null,
         })
         .rpc();
 
@@ -383,14 +402,13 @@ describe("TNS - Register Symbol", () => {
       expect(platformPercent).to.be.closeTo(10, 1);
     });
 
-    it("rejects platform fee exceeding 50% (5000 bps)", async () => {
+    it("rejects platform fee above the platform's maximum", async () => {
       const { program, admin, configPda, feeCollectorPubkey, priceUpdate } = ctx;
       const symbol = "PLATHI";
       const tokenMint = await getOrCreateTokenMint(symbol);
       const tokenMetadata = getMetadataPda(tokenMint);
 
-      const platformAccount = Keypair.generate();
-      await fundAccounts(ctx.provider, platformAccount);
+      const { authority: platformAccount, platformPda } = await registerPlatform(ctx, 5000);
 
       try {
         await program.methods
@@ -403,12 +421,14 @@ describe("TNS - Register Symbol", () => {
             tokenMetadata: tokenMetadata,
             feeCollector: feeCollectorPubkey,
             priceUpdate: priceUpdate,
+            platform: platformPda,
             platformFeeAccount: platformAccount.publicKey,
+            revenueVault: null,
           })
           .rpc();
-        expect.fail("Should have rejected platform fee > 50%");
+        expect.fail("Should have rejected platform fee above its maximum");
       } catch (err: any) {
-        expect(err.toString()).to.include("PlatformFeeExceedsMax");
+        expect(err.toString()).to.include("PlatformFeeAboveLimit");
       }
     });
 
@@ -418,8 +438,7 @@ describe("TNS - Register Symbol", () => {
       const tokenMint = await getOrCreateTokenMint(symbol);
       const tokenMetadata = getMetadataPda(tokenMint);
 
-      const platformAccount = Keypair.generate();
-      await fundAccounts(ctx.provider, platformAccount);
+      const { authority: platformAccount, platformPda } = await registerPlatform(ctx, 5000);
 
       const feeCollectorBefore = await getBalance(ctx.provider, feeCollectorPubkey);
       const platformBefore = await getBalance(ctx.provider, platformAccount.publicKey);
@@ -435,7 +454,9 @@ describe("TNS - Register Symbol", () => {
           tokenMetadata: tokenMetadata,
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
+          platform: platformPda,
           platformFeeAccount: platformAccount.publicKey,
+          revenueVault: null,
         })
         .rpc();
 
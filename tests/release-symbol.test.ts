@@ -0,0 +1,144 @@
+import BN from "bn.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  getBalance,
+  refreshConfigState,
+  ensureUnpaused,
+  createTokenWithMetadata,
+  registerSymbolSol,
+} from "./helpers/setup";
+
+const SECONDS_PER_DAY = 86_400;
+const SECONDS_PER_YEAR = 31_557_600;
+
+describe("TNS - Release Symbol", () => {
+  let ctx: TestContext;
+  const owner = Keypair.generate();
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await fundAccounts(ctx.provider, owner);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+  });
+
+  it("owner gets rent and keeper deposit back, symbol is registrable again", async () => {
+    const symbol = "RLSA";
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, symbol);
+    const tokenPda = await registerSymbolSol(ctx, symbol, { payer: owner, mint });
+
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    const rent = await getBalance(ctx.provider, tokenPda);
+    const vaultBefore = await ctx.program.account.keeperVault.fetch(ctx.keeperVaultPda);
+    const ownerBefore = await getBalance(ctx.provider, owner.publicKey);
+
+    await ctx.program.methods
+      .releaseSymbol()
+      .accountsPartial({
+        owner: owner.publicKey,
+        config: ctx.configPda,
+        keeperVault: ctx.keeperVaultPda,
+        // No bond posted, so the bond vault can be omitted
+        bondVault: null,
+        tokenAccount: tokenPda,
+      })
+      .signers([owner])
+      .rpc();
+
+    const ownerAfter = await getBalance(ctx.provider, owner.publicKey);
+    const vaultAfter = await ctx.program.account.keeperVault.fetch(ctx.keeperVaultPda);
+
+    // Owner pays the tx fee (5000 lamports) out of the refund
+    expect(ownerAfter - ownerBefore).to.equal(
+      rent + token.keeperDeposit.toNumber() - 5000
+    );
+    expect(
+      vaultBefore.outstandingDeposits.sub(vaultAfter.outstandingDeposits).toNumber()
+    ).to.equal(token.keeperDeposit.toNumber());
+    expect(await ctx.provider.connection.getAccountInfo(tokenPda)).to.be.null;
+
+    // Immediately registrable again
+    await registerSymbolSol(ctx, symbol, { payer: owner, mint });
+    const reRegistered = await ctx.program.account.token.fetch(tokenPda);
+    expect(reRegistered.owner.toString()).to.equal(owner.publicKey.toString());
+  });
+
+  it("only the owner can release", async () => {
+    const symbol = "RLSB";
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, symbol);
+    const tokenPda = await registerSymbolSol(ctx, symbol, { payer: owner, mint });
+
+    try {
+      await ctx.program.methods
+        .releaseSymbol()
+        .accountsPartial({
+          owner: ctx.admin.publicKey,
+          config: ctx.configPda,
+          keeperVault: ctx.keeperVaultPda,
+          tokenAccount: tokenPda,
+        })
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("UnauthorizedOwner");
+    }
+  });
+
+  async function setExpiresAt(tokenPda: PublicKey, expiresAt: number) {
+    await ctx.program.methods
+      .adminUpdateSymbol(null, null, new BN(expiresAt))
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+      })
+      .rpc();
+  }
+
+  async function expectReleaseRejected(tokenPda: PublicKey, error: string) {
+    try {
+      await ctx.program.methods
+        .releaseSymbol()
+        .accountsPartial({
+          owner: owner.publicKey,
+          config: ctx.configPda,
+          keeperVault: ctx.keeperVaultPda,
+          tokenAccount: tokenPda,
+        })
+        .signers([owner])
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include(error);
+    }
+  }
+
+  it("rejects release during the grace period", async () => {
+    const symbol = "RLSC";
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, symbol);
+    const tokenPda = await registerSymbolSol(ctx, symbol, { payer: owner, mint });
+
+    await setExpiresAt(tokenPda, Math.floor(Date.now() / 1000) - SECONDS_PER_DAY);
+    await expectReleaseRejected(tokenPda, "SymbolNotActive");
+  });
+
+  it("rejects release once expired, leaving the deposit to the keeper", async () => {
+    const symbol = "RLSD";
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, symbol);
+    const tokenPda = await registerSymbolSol(ctx, symbol, { payer: owner, mint });
+
+    await setExpiresAt(tokenPda, Math.floor(Date.now() / 1000) - 3 * SECONDS_PER_YEAR);
+    await expectReleaseRejected(tokenPda, "SymbolNotActive");
+
+    const token = await ctx.program.account.token.fetch(tokenPda);
+    expect(token.keeperDeposit.toNumber()).to.be.greaterThan(0);
+  });
+});
@@ -58,7 +58,9 @@ describe("TNS - Renew Symbol", () => {
         tokenMetadata: testTokenMetadata,
         feeCollector: ctx.feeCollectorPubkey,
         priceUpdate: ctx.priceUpdate,
+        platform: null,
         platformFeeAccount: null,
+        revenueVault: null,
       })
       .rpc();
   });
@@ -82,7 +84,10 @@ describe("TNS - Renew Symbol", () => {
         tokenAccount: tokenPda,
         feeCollector: feeCollectorPubkey,
         priceUpdate: priceUpdate,
+        platform: null,
         platformFeeAccount: null,
+        keeperVault: null,
+        revenueVault: null,
       })
       .rpc();
 
@@ -126,7 +131,10 @@ describe("TNS - Renew Symbol", () => {
         tokenAccount: tokenPda,
         feeCollector: feeCollectorPubkey,
         priceUpdate: priceUpdate,
+        platform: null,
         platformFeeAccount: null,
+        keeperVault: null,
+        revenueVault: null,
       })
       .signers([registrant])
       .rpc();
@@ -153,7 +161,10 @@ describe("TNS - Renew Symbol", () => {
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
           tokenAccount: tokenPda,
+          platform: null,
           platformFeeAccount: null,
+          keeperVault: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -176,7 +187,10 @@ describe("TNS - Renew Symbol", () => {
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
           tokenAccount: tokenPda,
+          platform: null,
           platformFeeAccount: null,
+          keeperVault: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -213,7 +227,9 @@ describe("TNS - Renew Symbol", () => {
           tokenMetadata: graceTokenMetadata,
           feeCollector: ctx.feeCollectorPubkey,
           priceUpdate: ctx.priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
     });
@@ -247,7 +263,10 @@ describe("TNS - Renew Symbol", () => {
           tokenAccount: graceTokenPda,
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          keeperVault: null,
+          revenueVault: null,
         })
         .rpc();
 
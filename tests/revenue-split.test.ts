@@ -0,0 +1,172 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { NATIVE_MINT, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  getTokenPda,
+  getRevenueVaultPda,
+  getRevenueTokensPda,
+  refreshConfigState,
+  ensureUnpaused,
+  createTokenWithMetadata,
+  getMetadataPda,
+} from "./helpers/setup";
+
+// Max slippage for tests (1 SOL)
+const MAX_SOL_COST = new BN(1_000_000_000);
+
+const TREASURY_ONLY = { treasuryBps: 10000, keeperBps: 0, burnBps: 0, stakerBps: 0 };
+const SPLIT = { treasuryBps: 7000, keeperBps: 1000, burnBps: 1500, stakerBps: 500 };
+
+describe("TNS - Revenue Split", () => {
+  let ctx: TestContext;
+  let solVaultPda: PublicKey;
+
+  async function setRevenueSplit(split: typeof SPLIT) {
+    await ctx.program.methods
+      .updateConfig(null, null, null, null, null, null, null, null, null, null, null, null, null, null, split)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+      })
+      .rpc();
+  }
+
+  async function registerSol(symbol: string, revenueVault: PublicKey | null) {
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, symbol);
+
+    await ctx.program.methods
+      .registerSymbolSol(symbol, 1, MAX_SOL_COST, 0)
+      .accountsPartial({
+        payer: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: getTokenPda(ctx.program.programId, symbol),
+        tokenMint: mint,
+        tokenMetadata: getMetadataPda(mint),
+        feeCollector: ctx.feeCollectorPubkey,
+        priceUpdate: ctx.priceUpdate,
+        platform: null,
+        platformFeeAccount: null,
+        revenueVault,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await fundAccounts(ctx.provider, ctx.feeCollector);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+
+    solVaultPda = getRevenueVaultPda(ctx.program.programId, NATIVE_MINT);
+
+    const existing = await ctx.provider.connection.getAccountInfo(solVaultPda);
+    if (existing === null) {
+      await ctx.program.methods
+        .initializeRevenueVault(PublicKey.default, 500)
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          config: ctx.configPda,
+          mint: NATIVE_MINT,
+          revenueVault: solVaultPda,
+          revenueTokenAccount: getRevenueTokensPda(ctx.program.programId, NATIVE_MINT),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+    }
+  });
+
+  after(async () => {
+    await setRevenueSplit(TREASURY_ONLY);
+  });
+
+  it("defaults to 100% treasury", async () => {
+    const config = await ctx.program.account.config.fetch(ctx.configPda);
+    expect(config.revenueSplit).to.deep.equal(TREASURY_ONLY);
+  });
+
+  it("creates the SOL revenue vault with buybacks disabled", async () => {
+    const vault = await ctx.program.account.revenueVault.fetch(solVaultPda);
+    expect(vault.mint.toString()).to.equal(NATIVE_MINT.toString());
+    expect(vault.buybackPool.toString()).to.equal(PublicKey.default.toString());
+  });
+
+  it("rejects a split that does not sum to 100%", async () => {
+    try {
+      await setRevenueSplit({ ...SPLIT, stakerBps: 600 });
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidRevenueSplit");
+    }
+  });
+
+  it("requires the revenue vault once burn and staker shares are configured", async () => {
+    await setRevenueSplit(SPLIT);
+
+    try {
+      await registerSol("REVA", null);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("MissingRevenueAccount");
+    }
+  });
+
+  it("routes burn and staker shares of a SOL fee into the revenue vault", async () => {
+    await setRevenueSplit(SPLIT);
+    const before = await ctx.program.account.revenueVault.fetch(solVaultPda);
+    const lamportsBefore = await ctx.provider.connection.getBalance(solVaultPda);
+
+    await registerSol("REVB", solVaultPda);
+
+    const after = await ctx.program.account.revenueVault.fetch(solVaultPda);
+    const burned = after.burnBalance.sub(before.burnBalance).toNumber();
+    const staked = after.stakerBalance.sub(before.stakerBalance).toNumber();
+
+    expect(burned).to.be.greaterThan(0);
+    expect(staked).to.be.greaterThan(0);
+    expect(after.keeperBalance.toNumber()).to.equal(before.keeperBalance.toNumber());
+    expect(await ctx.provider.connection.getBalance(solVaultPda)).to.equal(lamportsBefore + burned + staked);
+  });
+
+  it("rejects a buyback slippage limit above 20%", async () => {
+    try {
+      await ctx.program.methods
+        .updateRevenueVault(null, 2001)
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          config: ctx.configPda,
+          revenueVault: solVaultPda,
+        })
+        .rpc();
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidBuybackSettings");
+    }
+  });
+
+  it("non-admin cannot update a revenue vault", async () => {
+    const attacker = Keypair.generate();
+    await fundAccounts(ctx.provider, attacker);
+
+    try {
+      await ctx.program.methods
+        .updateRevenueVault(attacker.publicKey, null)
+        .accountsPartial({
+          admin: attacker.publicKey,
+          config: ctx.configPda,
+          revenueVault: solVaultPda,
+        })
+        .signers([attacker])
+        .rpc();
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("UnauthorizedAdmin");
+    }
+  });
+});
@@ -129,7 +129,9 @@ describe("TNS - Security Tests", () => {
           tokenMetadata: pauseTestMetadata,
           feeCollector: ctx.feeCollectorPubkey,
           priceUpdate: ctx.priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
     });
@@ -163,7 +165,9 @@ describe("TNS - Security Tests", () => {
             tokenMetadata: tokenMetadata,
             feeCollector: feeCollectorPubkey,
             priceUpdate: priceUpdate,
+            platform: null,
             platformFeeAccount: null,
+            revenueVault: null,
           })
           .rpc();
 
@@ -204,7 +208,10 @@ describe("TNS - Security Tests", () => {
             tokenAccount: tokenForRenewal,
             feeCollector: feeCollectorPubkey,
             priceUpdate: priceUpdate,
+            platform: null,
             platformFeeAccount: null,
+            keeperVault: null,
+            revenueVault: null,
           })
           .rpc();
 
@@ -249,7 +256,10 @@ describe("TNS - Security Tests", () => {
             tokenAccount: tokenForRenewal,
             feeCollector: feeCollectorPubkey,
             priceUpdate: priceUpdate,
+            platform: null,
             platformFeeAccount: null,
+            keeperVault: null,
+            revenueVault: null,
             newMint: newMint,
             newMintMetadata: newMintMetadata,
           })
@@ -311,7 +321,9 @@ describe("TNS - Security Tests", () => {
           tokenMint: tokenMint,
           tokenMetadata: tokenMetadata,
           tokenAccount: tokenPda,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -351,7 +363,9 @@ describe("TNS - Security Tests", () => {
             tokenMetadata: tokenMetadata,
             feeCollector: feeCollectorPubkey,
             priceUpdate: priceUpdate,
+            platform: null,
             platformFeeAccount: null,
+            revenueVault: null,
           })
           .signers([registrant])
           .rpc();
@@ -391,7 +405,9 @@ describe("TNS - Security Tests", () => {
           tokenMetadata: tokenMetadata,
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -421,7 +437,9 @@ describe("TNS - Security Tests", () => {
             tokenMetadata: tokenMetadata,
             feeCollector: feeCollectorPubkey,
             priceUpdate: priceUpdate,
+            platform: null,
             platformFeeAccount: null,
+            revenueVault: null,
           })
           .rpc();
 
@@ -476,7 +494,9 @@ describe("TNS - Security Tests", () => {
             tokenMint: tokenMint,
             tokenMetadata: tokenMetadata,
             tokenAccount: tokenPda,
+            platform: null,
             platformFeeAccount: null,
+            revenueVault: null,
           })
           .rpc();
 
@@ -566,7 +586,9 @@ describe("TNS - Security Tests", () => {
           tokenMetadata: tokenMetadata,
           feeCollector: ctx.feeCollectorPubkey,
           priceUpdate: ctx.priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .signers([registrant])
         .rpc();
@@ -0,0 +1,148 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { getOrCreateAssociatedTokenAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  getTokenPda,
+  refreshConfigState,
+  ensureUnpaused,
+  createTokenWithMetadata,
+  getMetadataPda,
+  getTreasuryVault,
+  getPaymentAssetPda,
+  USDC_MINT,
+  USDC_USD_FEED_ID,
+  USDC_USD_PRICE_UPDATE_DEPEGGED,
+  SOL_USD_PRICE_UPDATE,
+} from "./helpers/setup";
+
+// Max slippage for tests (1,000 USDC)
+const MAX_TOKEN_AMOUNT = new BN(1_000_000_000);
+
+describe("TNS - Stablecoin Depeg Protection", () => {
+  let ctx: TestContext;
+  let payerUsdcAccount: PublicKey;
+  let treasuryUsdcVault: PublicKey;
+  let usdcPaymentAsset: PublicKey;
+
+  async function setBand(bandBps: number) {
+    await ctx.program.methods
+      .updateConfig(null, null, null, null, null, null, null, null, null, null, null, null, bandBps)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+      })
+      .rpc();
+  }
+
+  async function registerUsdc(symbol: string, priceUpdate: PublicKey) {
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, symbol);
+
+    await ctx.program.methods
+      .registerSymbolAsset(symbol, 1, MAX_TOKEN_AMOUNT, 0)
+      .accountsPartial({
+        payer: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: getTokenPda(ctx.program.programId, symbol),
+        tokenMint: mint,
+        tokenMetadata: getMetadataPda(mint),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        paymentAsset: usdcPaymentAsset,
+        paymentMint: USDC_MINT,
+        payerTokenAccount: payerUsdcAccount,
+        treasuryVault: treasuryUsdcVault,
+        platform: null,
+        platformFeeAccount: null,
+        revenueVault: null,
+        revenueTokenAccount: null,
+        priceUpdate,
+        tnsPriceObservation: null,
+        tnsPriceSources: null,
+        tnsUsdPriceUpdate: null,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await fundAccounts(ctx.provider, ctx.feeCollector);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+
+    // Empty USDC accounts - the mainnet mint authority isn't available on localnet
+    payerUsdcAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        ctx.provider.connection,
+        ctx.admin.payer,
+        USDC_MINT,
+        ctx.admin.publicKey
+      )
+    ).address;
+    treasuryUsdcVault = await getTreasuryVault(ctx, USDC_MINT);
+
+    // USDC is accepted as a PaymentAsset priced from its Pyth feed with the depeg check
+    usdcPaymentAsset = getPaymentAssetPda(ctx.program.programId, USDC_MINT);
+    if ((await ctx.provider.connection.getAccountInfo(usdcPaymentAsset)) === null) {
+      await ctx.program.methods
+        .addPaymentAsset({ stablecoin: { feedId: Array.from(USDC_USD_FEED_ID) } }, 0, true)
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          config: ctx.configPda,
+          mint: USDC_MINT,
+          paymentAsset: usdcPaymentAsset,
+        })
+        .rpc();
+    }
+  });
+
+  after(async () => {
+    await setBand(200);
+  });
+
+  it("rejects a depeg band of zero", async () => {
+    try {
+      await setBand(0);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidDepegBand");
+    }
+  });
+
+  it("rejects USDC payments while USDC trades outside the band", async () => {
+    await setBand(200);
+
+    try {
+      await registerUsdc("DPGA", USDC_USD_PRICE_UPDATE_DEPEGGED);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("StablecoinDepegged");
+    }
+  });
+
+  it("accepts the same price once the band is widened", async () => {
+    await setBand(600);
+
+    // Passes the depeg check and fails only on the empty USDC balance
+    try {
+      await registerUsdc("DPGB", USDC_USD_PRICE_UPDATE_DEPEGGED);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.not.include("StablecoinDepegged");
+    }
+  });
+
+  it("rejects a price update for a different feed", async () => {
+    try {
+      await registerUsdc("DPGC", SOL_USD_PRICE_UPDATE);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("PriceFeedMismatch");
+    }
+  });
+});
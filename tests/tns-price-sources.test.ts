@@ -0,0 +1,141 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  refreshConfigState,
+  ensureTnsPriceSourcesInitialized,
+  ensureTnsPriceObservationInitialized,
+  POOL_TNS_RESERVE,
+  POOL_SOL_RESERVE,
+} from "./helpers/setup";
+
+const FIXTURE_POOL = { kind: { vaultPair: {} }, tnsVault: POOL_TNS_RESERVE, solVault: POOL_SOL_RESERVE };
+
+describe("TNS - Price Sources", () => {
+  let ctx: TestContext;
+  let priceSourcesPda: PublicKey;
+  let observationPda: PublicKey;
+
+  async function updateSources(pools: (typeof FIXTURE_POOL)[] | null, minSources: number | null) {
+    await ctx.program.methods
+      .updateTnsPriceSources(pools, minSources)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tnsPriceSources: priceSourcesPda,
+      })
+      .rpc();
+  }
+
+  async function setTnsUsdPythFeed(feed: PublicKey) {
+    await ctx.program.methods
+      .updateConfig(null, null, null, feed)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+      })
+      .rpc();
+  }
+
+  async function crank(remainingAccounts: PublicKey[]) {
+    await ctx.program.methods
+      .updateTnsPriceObservation()
+      .accountsPartial({
+        cranker: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tnsPriceObservation: observationPda,
+        tnsPriceSources: priceSourcesPda,
+        priceUpdate: ctx.priceUpdate,
+        tnsUsdPriceUpdate: null,
+      })
+      .remainingAccounts(
+        remainingAccounts.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }))
+      )
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await refreshConfigState(ctx);
+
+    priceSourcesPda = await ensureTnsPriceSourcesInitialized(ctx);
+    observationPda = await ensureTnsPriceObservationInitialized(ctx);
+  });
+
+  it("stores the configured pools on-chain", async () => {
+    const sources = await ctx.program.account.tnsPriceSources.fetch(priceSourcesPda);
+    expect(sources.pools.length).to.equal(1);
+    expect(sources.pools[0].tnsVault.toString()).to.equal(POOL_TNS_RESERVE.toString());
+    expect(sources.minSources).to.equal(1);
+  });
+
+  it("rejects a minimum above the number of sources", async () => {
+    try {
+      await updateSources(null, 3);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidPriceSources");
+    }
+  });
+
+  it("rejects the same pool listed twice", async () => {
+    try {
+      await updateSources([FIXTURE_POOL, FIXTURE_POOL], 1);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidPriceSources");
+    }
+  });
+
+  it("rejects pool accounts that don't match the configured vaults", async () => {
+    try {
+      await crank([POOL_SOL_RESERVE, POOL_TNS_RESERVE]);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidPoolReserve");
+    }
+  });
+
+  it("requires the Pyth TNS/USD account once the feed is configured", async () => {
+    await setTnsUsdPythFeed(Keypair.generate().publicKey);
+
+    try {
+      await crank([POOL_TNS_RESERVE, POOL_SOL_RESERVE]);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("MissingPriceAccount");
+    } finally {
+      // The default pubkey clears the feed
+      await setTnsUsdPythFeed(PublicKey.default);
+    }
+
+    const config = await ctx.program.account.config.fetch(ctx.configPda);
+    expect(config.tnsUsdPythFeed).to.be.null;
+  });
+
+  it("non-admin cannot update the price sources", async () => {
+    const attacker = Keypair.generate();
+    await fundAccounts(ctx.provider, attacker);
+
+    try {
+      await ctx.program.methods
+        .updateTnsPriceSources(null, 1)
+        .accountsPartial({
+          admin: attacker.publicKey,
+          config: ctx.configPda,
+          tnsPriceSources: priceSourcesPda,
+        })
+        .signers([attacker])
+        .rpc();
+
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("UnauthorizedAdmin");
+    }
+  });
+});
@@ -0,0 +1,129 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  refreshConfigState,
+  ensureTnsPriceSourcesInitialized,
+  ensureTnsPriceObservationInitialized,
+  POOL_TNS_RESERVE,
+  POOL_SOL_RESERVE,
+} from "./helpers/setup";
+
+const EXPECTED_TNS_PRICE_MICRO = 10_000;
+
+const DEFAULT_POLICY = { windowSeconds: 30 * 60, maxDeviationBps: 500 };
+
+describe("TNS - TWAP Pricing", () => {
+  let ctx: TestContext;
+  let observationPda: PublicKey;
+  let priceSourcesPda: PublicKey;
+  let cranker: Keypair;
+
+  async function updateTwapPolicy(policy: typeof DEFAULT_POLICY) {
+    await ctx.program.methods
+      .updateConfig(null, null, null, null, null, null, null, null, null, null, null, null, null, policy)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+      })
+      .rpc();
+  }
+
+  async function crank() {
+    await ctx.program.methods
+      .updateTnsPriceObservation()
+      .accountsPartial({
+        cranker: cranker.publicKey,
+        config: ctx.configPda,
+        tnsPriceObservation: observationPda,
+        tnsPriceSources: priceSourcesPda,
+        priceUpdate: ctx.priceUpdate,
+        tnsUsdPriceUpdate: null,
+      })
+      .remainingAccounts([
+        { pubkey: POOL_TNS_RESERVE, isWritable: false, isSigner: false },
+        { pubkey: POOL_SOL_RESERVE, isWritable: false, isSigner: false },
+      ])
+      .signers([cranker])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await refreshConfigState(ctx);
+
+    priceSourcesPda = await ensureTnsPriceSourcesInitialized(ctx);
+    observationPda = await ensureTnsPriceObservationInitialized(ctx);
+    cranker = Keypair.generate();
+    await fundAccounts(ctx.provider, cranker);
+  });
+
+  after(async () => {
+    await updateTwapPolicy(DEFAULT_POLICY);
+  });
+
+  it("the observation buffer starts empty", async () => {
+    const observation = await ctx.program.account.tnsPriceObservation.fetch(observationPda);
+    expect(observation.count).to.equal(0);
+  });
+
+  it("anyone can record a sample at the pool spot price", async () => {
+    await crank();
+
+    const observation = await ctx.program.account.tnsPriceObservation.fetch(observationPda);
+    expect(observation.count).to.equal(1);
+
+    const latest = observation.observations[observation.head];
+    expect(latest.priceUsdMicro.toNumber()).to.equal(EXPECTED_TNS_PRICE_MICRO);
+    expect(latest.cumulativePrice.toNumber()).to.equal(0);
+  });
+
+  it("rejects a second sample within the minimum interval", async () => {
+    try {
+      await crank();
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("ObservationTooSoon");
+    }
+  });
+
+  it("admin updates the TWAP policy", async () => {
+    await updateTwapPolicy({ windowSeconds: 2400, maxDeviationBps: 1000 });
+
+    const config = await ctx.program.account.config.fetch(ctx.configPda);
+    expect(config.tnsTwapPolicy.windowSeconds).to.equal(2400);
+    expect(config.tnsTwapPolicy.maxDeviationBps).to.equal(1000);
+  });
+
+  it("rejects a window shorter than the sample interval", async () => {
+    try {
+      await updateTwapPolicy({ windowSeconds: 30, maxDeviationBps: 500 });
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidTwapPolicy");
+    }
+  });
+
+  it("rejects a window longer than the observation buffer can cover", async () => {
+    try {
+      await updateTwapPolicy({ windowSeconds: 24 * 60 * 60, maxDeviationBps: 500 });
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidTwapPolicy");
+    }
+  });
+
+  it("rejects a zero deviation band", async () => {
+    try {
+      await updateTwapPolicy({ windowSeconds: 1800, maxDeviationBps: 0 });
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidTwapPolicy");
+    }
+  });
+});
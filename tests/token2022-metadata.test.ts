@@ -2,6 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import BN from "bn.js";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
+import { AuthorityType, setAuthority } from "@solana/spl-token";
 import {
   setupTest,
   TestContext,
@@ -59,7 +60,9 @@ describe("TNS - Token-2022 Metadata Support", () => {
           tokenMetadata: tokenMint, // Pass mint as metadata for Token-2022
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -95,7 +98,9 @@ describe("TNS - Token-2022 Metadata Support", () => {
             tokenMetadata: metaplexMetadataPda, // Wrong! Should be mint for Token-2022
             feeCollector: feeCollectorPubkey,
             priceUpdate: priceUpdate,
+            platform: null,
             platformFeeAccount: null,
+            revenueVault: null,
           })
           .rpc();
 
@@ -138,7 +143,9 @@ describe("TNS - Token-2022 Metadata Support", () => {
           tokenMetadata: tokenMetadata, // Metaplex PDA for classic SPL
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -172,7 +179,9 @@ describe("TNS - Token-2022 Metadata Support", () => {
             tokenMetadata: tokenMint, // Wrong! Should be Metaplex PDA for classic SPL
             feeCollector: feeCollectorPubkey,
             priceUpdate: priceUpdate,
+            platform: null,
             platformFeeAccount: null,
+            revenueVault: null,
           })
           .rpc();
 
@@ -210,7 +219,9 @@ describe("TNS - Token-2022 Metadata Support", () => {
             tokenMetadata: tokenMint,
             feeCollector: feeCollectorPubkey,
             priceUpdate: priceUpdate,
+            platform: null,
             platformFeeAccount: null,
+            revenueVault: null,
           })
           .rpc();
 
@@ -249,7 +260,9 @@ describe("TNS - Token-2022 Metadata Support", () => {
             tokenMetadata: tokenMetadata,
             feeCollector: feeCollectorPubkey,
             priceUpdate: priceUpdate,
+            platform: null,
             platformFeeAccount: null,
+            revenueVault: null,
           })
           .rpc();
 
@@ -292,4 +305,97 @@ describe("TNS - Token-2022 Metadata Support", () => {
       expect(tokenAccount.owner.toString()).to.equal(owner.toString());
     });
   });
+
+  describe("Claim ownership with Token-2022", () => {
+    it("update authority in the metadata extension can claim ownership", async () => {
+      const { program, admin, configPda, feeCollectorPubkey, priceUpdate } = ctx;
+      const symbol = "CLM22";
+      const tokenPda = getTokenPda(program.programId, symbol);
+      const otherOwner = Keypair.generate();
+
+      const tokenMint = await createToken2022WithMetadata(
+        ctx.provider,
+        ctx.admin,
+        symbol,
+        `${symbol} Token`
+      );
+
+      // Revoke mint authority so only the update authority path can succeed
+      await setAuthority(
+        ctx.provider.connection,
+        admin.payer,
+        tokenMint,
+        admin.publicKey,
+        AuthorityType.MintTokens,
+        null,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await program.methods
+        .registerSymbolSol(symbol, 1, MAX_SOL_COST, 0)
+        .accountsPartial({
+          payer: admin.publicKey,
+          config: configPda,
+          tokenAccount: tokenPda,
+          tokenMint: tokenMint,
+          tokenMetadata: tokenMint,
+          feeCollector: feeCollectorPubkey,
+          priceUpdate: priceUpdate,
+          platform: null,
+          platformFeeAccount: null,
+          revenueVault: null,
+        })
+        .rpc();
+
+      await program.methods
+        .transferOwnership(otherOwner.publicKey)
+        .accountsPartial({
+          owner: admin.publicKey,
+          config: configPda,
+          tokenAccount: tokenPda,
+        })
+        .rpc();
+
+      await program.methods
+        .claimOwnership()
+        .accountsPartial({
+          claimant: admin.publicKey,
+          config: configPda,
+          tokenAccount: tokenPda,
+          tokenMint: tokenMint,
+          tokenMetadata: tokenMint, // Pass mint as metadata for Token-2022
+        })
+        .rpc();
+
+      const tokenAccount = await program.account.token.fetch(tokenPda);
+      expect(tokenAccount.owner.toString()).to.equal(admin.publicKey.toString());
+    });
+
+    it("rejects a metadata account the mint's pointer does not target", async () => {
+      const { program, configPda, registrant } = ctx;
+      const symbol = "CLM22";
+      const tokenPda = getTokenPda(program.programId, symbol);
+      const tokenAccount = await program.account.token.fetch(tokenPda);
+
+      try {
+        await program.methods
+          .claimOwnership()
+          .accountsPartial({
+            claimant: registrant.publicKey,
+            config: configPda,
+            tokenAccount: tokenPda,
+            tokenMint: tokenAccount.mint,
+            tokenMetadata: getMetadataPda(tokenAccount.mint),
+          })
+          .signers([registrant])
+          .rpc();
+
+        expect.fail("Should have rejected metadata account");
+      } catch (err: any) {
+        expect(err.error?.errorCode?.code || err.message).to.include("InvalidMetadata");
+      }
+    });
+  });
 });
@@ -0,0 +1,253 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import { Keypair, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  ExtensionType,
+  TOKEN_2022_PROGRAM_ID,
+  createInitializeMintCloseAuthorityInstruction,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  getAccount,
+  getMintLen,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  getTokenPda,
+  refreshConfigState,
+  ensureUnpaused,
+  createTokenWithMetadata,
+  getMetadataPda,
+  getTreasuryVault,
+  getPaymentAssetPda,
+} from "./helpers/setup";
+
+// $1 per whole token, 6 decimals, 1% transfer fee
+const PEG_USD_MICRO = new BN(1_000_000);
+const DECIMALS = 6;
+const TRANSFER_FEE_BPS = 100;
+const MAX_TRANSFER_FEE = BigInt(1_000_000_000_000);
+
+// Max slippage for tests (1,000 whole tokens)
+const MAX_TOKEN_AMOUNT = new BN(1_000).mul(new BN(10).pow(new BN(DECIMALS)));
+
+// Create a Token-2022 mint with the given extensions initialised
+async function createToken2022Mint(
+  ctx: TestContext,
+  extensions: ExtensionType[]
+): Promise<PublicKey> {
+  const mintKeypair = Keypair.generate();
+  const mintLen = getMintLen(extensions);
+  const lamports = await ctx.provider.connection.getMinimumBalanceForRentExemption(mintLen);
+
+  const tx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: ctx.admin.publicKey,
+      newAccountPubkey: mintKeypair.publicKey,
+      space: mintLen,
+      lamports,
+      programId: TOKEN_2022_PROGRAM_ID,
+    })
+  );
+
+  if (extensions.includes(ExtensionType.TransferFeeConfig)) {
+    tx.add(
+      createInitializeTransferFeeConfigInstruction(
+        mintKeypair.publicKey,
+        ctx.admin.publicKey,
+        ctx.admin.publicKey,
+        TRANSFER_FEE_BPS,
+        MAX_TRANSFER_FEE,
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+  }
+  if (extensions.includes(ExtensionType.MintCloseAuthority)) {
+    tx.add(
+      createInitializeMintCloseAuthorityInstruction(
+        mintKeypair.publicKey,
+        ctx.admin.publicKey,
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+  }
+  tx.add(
+    createInitializeMintInstruction(
+      mintKeypair.publicKey,
+      DECIMALS,
+      ctx.admin.publicKey,
+      null,
+      TOKEN_2022_PROGRAM_ID
+    )
+  );
+
+  await ctx.provider.sendAndConfirm(tx, [mintKeypair]);
+  return mintKeypair.publicKey;
+}
+
+describe("TNS - Token-2022 Transfer Fees", () => {
+  let ctx: TestContext;
+  let paymentMint: PublicKey;
+  let paymentAssetPda: PublicKey;
+  let payerAta: PublicKey;
+  let treasuryVault: PublicKey;
+
+  async function addPaymentAsset(mint: PublicKey) {
+    await ctx.program.methods
+      .addPaymentAsset({ fixedPeg: { usdMicro: PEG_USD_MICRO } }, 0, true)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: ctx.configPda,
+        mint,
+        paymentAsset: getPaymentAssetPda(ctx.program.programId, mint),
+      })
+      .rpc();
+  }
+
+  async function registerWithAsset(
+    symbol: string,
+    maxTokenAmount: BN = MAX_TOKEN_AMOUNT
+  ): Promise<PublicKey> {
+    const mint = await createTokenWithMetadata(ctx.provider, ctx.admin, symbol);
+    const tokenPda = getTokenPda(ctx.program.programId, symbol);
+
+    await ctx.program.methods
+      .registerSymbolAsset(symbol, 1, maxTokenAmount, 0)
+      .accountsPartial({
+        payer: ctx.admin.publicKey,
+        config: ctx.configPda,
+        tokenAccount: tokenPda,
+        tokenMint: mint,
+        tokenMetadata: getMetadataPda(mint),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        paymentAsset: paymentAssetPda,
+        paymentMint,
+        payerTokenAccount: payerAta,
+        treasuryVault,
+        platform: null,
+        platformFeeAccount: null,
+        revenueVault: null,
+        revenueTokenAccount: null,
+        priceUpdate: null,
+        tnsPriceObservation: null,
+        tnsPriceSources: null,
+        tnsUsdPriceUpdate: null,
+      })
+      .rpc();
+
+    return tokenPda;
+  }
+
+  async function balance(tokenAccount: PublicKey): Promise<bigint> {
+    return (
+      await getAccount(ctx.provider.connection, tokenAccount, undefined, TOKEN_2022_PROGRAM_ID)
+    ).amount;
+  }
+
+  async function quotedAmount(): Promise<bigint> {
+    const config = await ctx.program.account.config.fetch(ctx.configPda);
+    return (
+      BigInt(config.basePriceUsdMicro.toString()) *
+      10n ** BigInt(DECIMALS) /
+      BigInt(PEG_USD_MICRO.toString())
+    );
+  }
+
+  // Smallest amount that leaves `net` after the 1% transfer fee
+  function grossUp(net: bigint): bigint {
+    return (net * 10000n + BigInt(10000 - TRANSFER_FEE_BPS) - 1n) / BigInt(10000 - TRANSFER_FEE_BPS);
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await fundAccounts(ctx.provider, ctx.feeCollector);
+    await refreshConfigState(ctx);
+    await ensureUnpaused(ctx);
+
+    paymentMint = await createToken2022Mint(ctx, [ExtensionType.TransferFeeConfig]);
+    paymentAssetPda = getPaymentAssetPda(ctx.program.programId, paymentMint);
+
+    payerAta = (
+      await getOrCreateAssociatedTokenAccount(
+        ctx.provider.connection,
+        ctx.admin.payer,
+        paymentMint,
+        ctx.admin.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      )
+    ).address;
+    treasuryVault = await getTreasuryVault(ctx, paymentMint, TOKEN_2022_PROGRAM_ID);
+
+    await mintTo(
+      ctx.provider.connection,
+      ctx.admin.payer,
+      paymentMint,
+      payerAta,
+      ctx.admin.payer,
+      10_000 * 10 ** DECIMALS,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    await addPaymentAsset(paymentMint);
+  });
+
+  it("grosses up the payment so the treasury receives the full quoted fee", async () => {
+    const quoted = await quotedAmount();
+
+    const treasuryBefore = await balance(treasuryVault);
+    const payerBefore = await balance(payerAta);
+    await registerWithAsset("XFEA");
+
+    expect((await balance(treasuryVault)) - treasuryBefore).to.equal(quoted);
+    expect(payerBefore - (await balance(payerAta))).to.equal(grossUp(quoted));
+  });
+
+  it("applies slippage protection to the grossed-up amount", async () => {
+    const quoted = await quotedAmount();
+    const treasuryBefore = await balance(treasuryVault);
+
+    try {
+      await registerWithAsset("XFEB", new BN(quoted.toString()));
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("SlippageExceeded");
+    }
+
+    expect(await balance(treasuryVault)).to.equal(treasuryBefore);
+
+    await registerWithAsset("XFEB", new BN(grossUp(quoted).toString()));
+    expect((await balance(treasuryVault)) - treasuryBefore).to.equal(quoted);
+  });
+
+  it("rejects payment mints with unsupported extensions", async () => {
+    const closableMint = await createToken2022Mint(ctx, [ExtensionType.MintCloseAuthority]);
+
+    try {
+      await addPaymentAsset(closableMint);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("UnsupportedPaymentMintExtension");
+    }
+  });
+
+  it("accepts Token-2022 payment mints without extensions", async () => {
+    const splMint = await createToken2022Mint(ctx, []);
+    await addPaymentAsset(splMint);
+
+    const asset = await ctx.program.account.paymentAsset.fetch(
+      getPaymentAssetPda(ctx.program.programId, splMint)
+    );
+    expect(asset.decimals).to.equal(DECIMALS);
+  });
+});
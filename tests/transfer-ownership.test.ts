@@ -58,7 +58,9 @@ describe("TNS - Transfer Ownership", () => {
         tokenMetadata: testTokenMetadata,
         feeCollector: ctx.feeCollectorPubkey,
         priceUpdate: ctx.priceUpdate,
+        platform: null,
         platformFeeAccount: null,
+        revenueVault: null,
       })
       .rpc();
   });
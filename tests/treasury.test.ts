@@ -0,0 +1,135 @@
+import * as anchor from "@coral-xyz/anchor";
+import BN from "bn.js";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  createMint,
+  mintTo,
+  getOrCreateAssociatedTokenAccount,
+  getAccount,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  setupTest,
+  TestContext,
+  ensureConfigInitialized,
+  fundAccounts,
+  refreshConfigState,
+  ensureTreasuryInitialized,
+  getTreasuryVault,
+} from "./helpers/setup";
+
+const DECIMALS = 6;
+
+describe("TNS - Treasury", () => {
+  let ctx: TestContext;
+  let treasuryPda: PublicKey;
+  let mint: PublicKey;
+  let vault: PublicKey;
+  let collectorAccount: PublicKey;
+
+  async function withdraw(destination: PublicKey, amount: number, admin?: Keypair) {
+    const builder = ctx.program.methods
+      .withdrawTreasury(new BN(amount))
+      .accountsPartial({
+        admin: admin ? admin.publicKey : ctx.admin.publicKey,
+        config: ctx.configPda,
+        treasury: treasuryPda,
+        mint,
+        treasuryVault: vault,
+        destination,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      });
+
+    await (admin ? builder.signers([admin]) : builder).rpc();
+  }
+
+  async function balance(account: PublicKey): Promise<bigint> {
+    return (await getAccount(ctx.provider.connection, account)).amount;
+  }
+
+  before(async () => {
+    ctx = setupTest();
+    await ensureConfigInitialized(ctx);
+    await fundAccounts(ctx.provider, ctx.feeCollector);
+    await refreshConfigState(ctx);
+
+    treasuryPda = await ensureTreasuryInitialized(ctx);
+
+    mint = await createMint(ctx.provider.connection, ctx.admin.payer, ctx.admin.publicKey, null, DECIMALS);
+    vault = await getTreasuryVault(ctx, mint);
+    collectorAccount = (
+      await getOrCreateAssociatedTokenAccount(ctx.provider.connection, ctx.admin.payer, mint, ctx.feeCollectorPubkey)
+    ).address;
+
+    await mintTo(ctx.provider.connection, ctx.admin.payer, mint, vault, ctx.admin.payer, 1_000 * 10 ** DECIMALS);
+  });
+
+  it("allowlists the fee collector on creation", async () => {
+    const treasury = await ctx.program.account.treasury.fetch(treasuryPda);
+    expect(treasury.allowlist.map((k) => k.toString())).to.include(ctx.feeCollectorPubkey.toString());
+  });
+
+  it("withdraws to an allowlisted wallet's token account", async () => {
+    const vaultBefore = await balance(vault);
+    const collectorBefore = await balance(collectorAccount);
+
+    await withdraw(collectorAccount, 250 * 10 ** DECIMALS);
+
+    expect(vaultBefore - (await balance(vault))).to.equal(BigInt(250 * 10 ** DECIMALS));
+    expect((await balance(collectorAccount)) - collectorBefore).to.equal(BigInt(250 * 10 ** DECIMALS));
+  });
+
+  it("rejects a destination outside the allowlist", async () => {
+    const stranger = Keypair.generate();
+    const strangerAccount = (
+      await getOrCreateAssociatedTokenAccount(ctx.provider.connection, ctx.admin.payer, mint, stranger.publicKey)
+    ).address;
+
+    try {
+      await withdraw(strangerAccount, 1);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("DestinationNotAllowed");
+    }
+  });
+
+  it("rejects a withdrawal above the vault balance", async () => {
+    const available = Number(await balance(vault));
+
+    try {
+      await withdraw(collectorAccount, available + 1);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InsufficientTreasuryBalance");
+    }
+  });
+
+  it("rejects an allowlist with duplicates", async () => {
+    try {
+      await ctx.program.methods
+        .updateTreasuryAllowlist([ctx.feeCollectorPubkey, ctx.feeCollectorPubkey])
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          config: ctx.configPda,
+          treasury: treasuryPda,
+        })
+        .rpc();
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("InvalidTreasuryAllowlist");
+    }
+  });
+
+  it("non-admin cannot withdraw", async () => {
+    const attacker = Keypair.generate();
+    await fundAccounts(ctx.provider, attacker);
+
+    try {
+      await withdraw(collectorAccount, 1, attacker);
+      expect.fail("Should have thrown an error");
+    } catch (err) {
+      expect(err.message).to.include("UnauthorizedAdmin");
+    }
+  });
+});
@@ -71,7 +71,9 @@ describe("TNS - Update Mint", () => {
         tokenMetadata: testTokenMetadata,
         feeCollector: ctx.feeCollectorPubkey,
         priceUpdate: ctx.priceUpdate,
+        platform: null,
         platformFeeAccount: null,
+        revenueVault: null,
       })
       .rpc();
   });
@@ -93,7 +95,10 @@ describe("TNS - Update Mint", () => {
         tokenAccount: tokenPda,
         feeCollector: feeCollectorPubkey,
         priceUpdate: priceUpdate,
+        platform: null,
         platformFeeAccount: null,
+        keeperVault: null,
+        revenueVault: null,
         newMint: newMintForUpdate,
         newMintMetadata: newMintMetadata,
       })
@@ -132,7 +137,10 @@ describe("TNS - Update Mint", () => {
           tokenAccount: tokenPda,
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          keeperVault: null,
+          revenueVault: null,
           newMint: testTokenMint, // Valid mint, but wrong owner
           newMintMetadata: testTokenMetadata,
         })
@@ -164,7 +172,10 @@ describe("TNS - Update Mint", () => {
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
           tokenAccount: tokenPda,
+          platform: null,
           platformFeeAccount: null,
+          keeperVault: null,
+          revenueVault: null,
           newMint: currentMint,
           newMintMetadata: currentMintMetadata,
         })
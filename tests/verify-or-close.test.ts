@@ -174,7 +174,9 @@ describe("TNS - Verify Or Close", () => {
           tokenMetadata: tokenMetadata,
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -225,7 +227,9 @@ describe("TNS - Verify Or Close", () => {
           tokenMetadata: tokenMetadata,
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -277,7 +281,9 @@ describe("TNS - Verify Or Close", () => {
           tokenMetadata: tokenMetadata,
           feeCollector: feeCollectorPubkey,
           priceUpdate: priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
@@ -303,14 +309,9 @@ describe("TNS - Verify Or Close", () => {
       }
     });
 
-    it("successfully closes symbol when metadata drift is detected", async () => {
+    async function registerDriftableSymbol(symbol: string, mintAuthority: Keypair) {
       const { program, configPda } = ctx;
 
-      const symbol = "DRIFT";
-      const mintAuthority = Keypair.generate();
-      const keeper = Keypair.generate();
-      await fundAccounts(ctx.provider, mintAuthority, keeper);
-
       // Create mint with controlled authority (mutable metadata)
       const tokenMint = await createMint(
         ctx.provider.connection,
@@ -350,29 +351,144 @@ describe("TNS - Verify Or Close", () => {
           tokenMetadata: tokenMetadata,
           feeCollector: ctx.feeCollectorPubkey,
           priceUpdate: ctx.priceUpdate,
+          platform: null,
           platformFeeAccount: null,
+          revenueVault: null,
         })
         .rpc();
 
-      // Update metadata to create drift (change symbol)
-      const updateMetadataIx = updateMetadataV2Ix(
-        tokenMetadata,
-        mintAuthority.publicKey,
-        `${symbol} Token`,
-        "DRIFTED", // Changed!
-        ""
+      return { tokenMint, tokenMetadata, tokenPda };
+    }
+
+    async function setMetadataSymbol(tokenMetadata: PublicKey, mintAuthority: Keypair, newSymbol: string) {
+      const updateTx = new anchor.web3.Transaction().add(
+        updateMetadataV2Ix(tokenMetadata, mintAuthority.publicKey, `${newSymbol} Token`, newSymbol, "")
       );
+      await anchor.web3.sendAndConfirmTransaction(ctx.provider.connection, updateTx, [mintAuthority]);
+    }
+
+    async function setDriftGracePeriod(seconds: number) {
+      await ctx.program.methods
+        .updateConfig(null, null, null, null, null, null, null, null, null, new BN(seconds), null)
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          config: ctx.configPda,
+        })
+        .rpc();
+    }
+
+    it("first call on drift flags the symbol instead of closing it", async () => {
+      const { program, configPda } = ctx;
+
+      const symbol = "DRIFTA";
+      const mintAuthority = Keypair.generate();
+      const keeper = Keypair.generate();
+      await fundAccounts(ctx.provider, mintAuthority, keeper);
+      await setDriftGracePeriod(3600);
+
+      const { tokenMint, tokenMetadata, tokenPda } = await registerDriftableSymbol(symbol, mintAuthority);
+      await setMetadataSymbol(tokenMetadata, mintAuthority, "OOPS");
+
+      const accounts = {
+        keeper: keeper.publicKey,
+        config: configPda,
+        tokenAccount: tokenPda,
+        tokenMint: tokenMint,
+        tokenMetadata: tokenMetadata,
+      };
 
-      const updateTx = new anchor.web3.Transaction().add(updateMetadataIx);
+      await program.methods.verifyOrClose().accountsPartial(accounts).signers([keeper]).rpc();
+
+      const flagged = await program.account.token.fetch(tokenPda);
+      expect(flagged.driftFlaggedAt.toNumber()).to.be.greaterThan(0);
+
+      // Second call within the window is rejected
+      try {
+        await program.methods.verifyOrClose().accountsPartial(accounts).signers([keeper]).rpc();
+        expect.fail("Should have thrown an error");
+      } catch (err: any) {
+        expect(err.message).to.include("DriftGracePeriodActive");
+      }
+
+      // Owner restores metadata; the next call clears the flag
+      await setMetadataSymbol(tokenMetadata, mintAuthority, symbol);
+      await program.methods.verifyOrClose().accountsPartial(accounts).signers([keeper]).rpc();
+
+      const cleared = await program.account.token.fetch(tokenPda);
+      expect(cleared.driftFlaggedAt.toNumber()).to.equal(0);
+    });
+
+    it("flags a name/URI change and the owner can re-commit", async () => {
+      const { program, configPda } = ctx;
+
+      const symbol = "DRIFTB";
+      const mintAuthority = Keypair.generate();
+      const keeper = Keypair.generate();
+      await fundAccounts(ctx.provider, mintAuthority, keeper);
+      await setDriftGracePeriod(3600);
+
+      const { tokenMint, tokenMetadata, tokenPda } = await registerDriftableSymbol(symbol, mintAuthority);
+
+      // Keep the ticker, swap the name and URI
+      const updateTx = new anchor.web3.Transaction().add(
+        updateMetadataV2Ix(tokenMetadata, mintAuthority.publicKey, "Other Brand", symbol, "https://example.com/other.json")
+      );
       await anchor.web3.sendAndConfirmTransaction(ctx.provider.connection, updateTx, [mintAuthority]);
 
-      // Verify token exists before
-      const tokenBefore = await program.account.token.fetch(tokenPda);
-      expect(tokenBefore.symbol).to.equal(symbol);
+      const accounts = {
+        keeper: keeper.publicKey,
+        config: configPda,
+        tokenAccount: tokenPda,
+        tokenMint: tokenMint,
+        tokenMetadata: tokenMetadata,
+      };
 
-      const keeperBalanceBefore = await getBalance(ctx.provider, keeper.publicKey);
+      await program.methods.verifyOrClose().accountsPartial(accounts).signers([keeper]).rpc();
+
+      const flagged = await program.account.token.fetch(tokenPda);
+      expect(flagged.driftFlaggedAt.toNumber()).to.be.greaterThan(0);
+
+      // Owner (admin, who registered) accepts the new metadata by paying the update fee
+      await program.methods
+        .recommitMetadataSol(MAX_SOL_COST, 0)
+        .accountsPartial({
+          owner: ctx.admin.publicKey,
+          config: configPda,
+          tokenAccount: tokenPda,
+          feeCollector: ctx.feeCollectorPubkey,
+          priceUpdate: ctx.priceUpdate,
+          platform: null,
+          platformFeeAccount: null,
+          keeperVault: null,
+          revenueVault: null,
+          tokenMint: tokenMint,
+          tokenMetadata: tokenMetadata,
+        })
+        .rpc();
+
+      const recommitted = await program.account.token.fetch(tokenPda);
+      expect(recommitted.driftFlaggedAt.toNumber()).to.equal(0);
+
+      try {
+        await program.methods.verifyOrClose().accountsPartial(accounts).signers([keeper]).rpc();
+        expect.fail("Should have thrown an error");
+      } catch (err: any) {
+        expect(err.message).to.include("NoDriftDetected");
+      }
+    });
+
+    it("owner can't release a drift-flagged symbol out from under the keeper", async () => {
+      const { program, configPda } = ctx;
+
+      const symbol = "DRIFTR";
+      const mintAuthority = Keypair.generate();
+      const keeper = Keypair.generate();
+      await fundAccounts(ctx.provider, mintAuthority, keeper);
+      await setDriftGracePeriod(3600);
+
+      const { tokenMint, tokenMetadata, tokenPda } = await registerDriftableSymbol(symbol, mintAuthority);
+      await setMetadataSymbol(tokenMetadata, mintAuthority, "OOPS");
 
-      // Now verify_or_close should succeed because drift detected
       await program.methods
         .verifyOrClose()
         .accountsPartial({
@@ -385,6 +501,59 @@ describe("TNS - Verify Or Close", () => {
         .signers([keeper])
         .rpc();
 
+      try {
+        await program.methods
+          .releaseSymbol()
+          .accountsPartial({
+            owner: ctx.admin.publicKey,
+            config: configPda,
+            keeperVault: ctx.keeperVaultPda,
+            tokenAccount: tokenPda,
+          })
+          .rpc();
+        expect.fail("Should have thrown an error");
+      } catch (err: any) {
+        expect(err.message).to.include("SymbolDriftFlagged");
+      }
+
+      const token = await program.account.token.fetch(tokenPda);
+      expect(token.driftFlaggedAt.toNumber()).to.be.greaterThan(0);
+    });
+
+    it("closes symbol when drift persists past the remediation window", async () => {
+      const { program, configPda } = ctx;
+
+      const symbol = "DRIFT";
+      const mintAuthority = Keypair.generate();
+      const keeper = Keypair.generate();
+      await fundAccounts(ctx.provider, mintAuthority, keeper);
+      await setDriftGracePeriod(1);
+
+      const { tokenMint, tokenMetadata, tokenPda } = await registerDriftableSymbol(symbol, mintAuthority);
+
+      // Update metadata to create drift (change symbol)
+      await setMetadataSymbol(tokenMetadata, mintAuthority, "DRIFTED");
+
+      const accounts = {
+        keeper: keeper.publicKey,
+        config: configPda,
+        tokenAccount: tokenPda,
+        tokenMint: tokenMint,
+        tokenMetadata: tokenMetadata,
+      };
+
+      // Phase 1: flag
+      await program.methods.verifyOrClose().accountsPartial(accounts).signers([keeper]).rpc();
+      const tokenFlagged = await program.account.token.fetch(tokenPda);
+      expect(tokenFlagged.symbol).to.equal(symbol);
+
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      const keeperBalanceBefore = await getBalance(ctx.provider, keeper.publicKey);
+
+      // Phase 2: close after the window
+      await program.methods.verifyOrClose().accountsPartial(accounts).signers([keeper]).rpc();
+
       // Verify token account is closed
       const tokenAccountInfo = await ctx.provider.connection.getAccountInfo(tokenPda);
       expect(tokenAccountInfo).to.be.null;